@@ -14,8 +14,6 @@ use dsyrs::{
     ServoState,
 };
 use std::time::Duration;
-use tokio_modbus::prelude::*;
-use tokio_serial::SerialStream;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -32,28 +30,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         port_name, baud_rate, slave_id
     );
 
-    // Open serial port with timeout
-    let builder = tokio_serial::new(port_name, baud_rate).timeout(Duration::from_millis(100));
-    let port = SerialStream::open(&builder)?;
-
-    // Create Modbus RTU context
-    let ctx = rtu::attach_slave(port, Slave::from(slave_id));
-
     // Create servo configuration
     // - Position control mode
     // - CW forward rotation direction
     // - Max speed 3000 rpm
     // - Rated current 3.0 A
+    // - 100 ms response timeout per request
     // Note: rated_current, encoder_type, encoder_resolution, and motor_model_code are optional.
     // If not specified, they will be read from the servo during init().
     let config = ServoConfig::new(slave_id)
         .with_control_mode(ControlMode::Position)
         .with_direction(Direction::CwForward)
         .with_max_speed(3000)
-        .with_rated_current(3.0);
+        .with_rated_current(3.0)
+        .with_timeout(Duration::from_millis(100));
 
-    // Create and initialize client
-    let mut servo = DsyrsClient::new(ctx, config);
+    // Open the serial port, then initialize the client
+    let mut servo = DsyrsClient::connect(port_name, baud_rate, config)?;
     println!("Initializing servo drive...");
     servo.init().await?;
 
@@ -63,15 +56,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("State: {:?}", status.state);
     println!("Speed: {} rpm", status.speed);
     println!("Position: {} pulses", status.position);
-    println!("Torque: {}% of rated", status.torque as f32 * 0.1);
-    println!("Current: {} A", status.current as f32 * 0.01);
-    println!("Bus Voltage: {} V", status.bus_voltage as f32 * 0.1);
+    println!("Torque: {}% of rated", status.torque_percent());
+    println!("Current: {} A", status.current_amps());
+    println!("Bus Voltage: {} V", status.bus_voltage_volts());
 
     // Read firmware version
-    let sw_version = servo.get_software_version().await?;
-    let fpga_version = servo.get_fpga_version().await?;
-    println!("\nFirmware Version: {}", sw_version);
-    println!("FPGA Version: {}", fpga_version);
+    let firmware = servo.get_firmware_info().await?;
+    println!("\nFirmware: {}", firmware);
 
     // Example: Configure homing
     println!("\n--- Homing Configuration ---");
@@ -89,6 +80,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         accel_limit: 200,        // Acceleration: 200 ms
         timeout: 30000,          // Timeout: 30 seconds
         offset: 0,               // No offset after homing
+        enable_mode: None,       // Already set above
     };
     servo.apply_homing_config(&homing_config).await?;
     println!(
@@ -0,0 +1,220 @@
+//! Example: Exercising the clients against a simulated servo (no hardware)
+//!
+//! This example plugs the in-memory `MockTransport` (`test-util` feature)
+//! into the clients, so the full API can be used without a drive or an RS485
+//! adapter. The same mock drives the tests in `tests/`.
+//!
+//! Key concepts:
+//! - Setting and scripting the values returned by registers (status, speed, ...)
+//! - Inspecting the writes the client makes
+//! - Simulating a fault-and-recover cycle
+//! - Homing with the one-call run_homing()
+//! - Checking how scattered parameter reads are batched
+//! - Cloning parameters between drives
+//! - Retrying transient communication errors
+//! - Driving the synchronous client with the same mock
+//! - Relative moves through the multi-segment engine
+//! - Sampling the status as a stream
+
+use dsyrs::mock::{simulated_client, MockTransport};
+use dsyrs::{registers, ControlMode, DsyrsSyncClient, HomingConfig, RetryPolicy, ServoConfig};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+/// Example 1: Show the writes made by init()
+async fn example_init_writes() -> Result<(), Box<dyn std::error::Error>> {
+    println!("=== init() writes ===\n");
+
+    let config = ServoConfig::new(1)
+        .with_control_mode(ControlMode::Speed)
+        .with_max_speed(2000);
+    let (mut servo, mock) = simulated_client(config);
+    servo.init().await?;
+
+    for (addr, value) in mock.writes() {
+        println!("  wrote {:#06X} = {}", addr, value);
+    }
+    Ok(())
+}
+
+/// Example 2: Script status responses
+async fn example_scripted_status() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Scripted status ===\n");
+
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [0, 500, 1000]);
+
+    // Once the script is exhausted, reads fall back to the register map
+    for _ in 0..4 {
+        println!("  speed: {} rpm", servo.get_speed().await?);
+    }
+    Ok(())
+}
+
+/// Example 3: Fault and recover
+async fn example_fault_recovery() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Fault and recover ===\n");
+
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+
+    // Error state in the low nibble, fault code above it, until the reset
+    mock.set_register(registers::P18_SERVO_STATUS, (0x410 << 4) | 2);
+    let fault = servo.get_fault_code().await?;
+    println!("  fault: {:?}", fault.map(|f| f.to_string()));
+
+    servo.reset_fault().await?;
+    // The drive leaves the error state once the fault is reset
+    mock.set_register(registers::P18_SERVO_STATUS, 0);
+    println!(
+        "  state after reset_fault(): {:?}",
+        servo.get_servo_state().await?
+    );
+    Ok(())
+}
+
+/// Example 4: Homing in one call
+async fn example_homing() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Homing ===\n");
+
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let config = HomingConfig::default().with_timeout(200);
+    // The motor runs to the home switch, then stops on the home position
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [0, 300, 0]);
+    mock.script_reads(registers::P18_ABSOLUTE_POSITION + 1, [0, 900, 0]);
+    servo
+        .run_homing(&config, Duration::from_millis(500))
+        .await?;
+    println!("  run_homing complete");
+    Ok(())
+}
+
+/// Example 5: Batched reads of scattered parameters
+async fn example_batched_reads() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Batched parameter reads ===\n");
+
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P05_ACCEL_TIME, 100);
+    mock.set_register(registers::P05_DECEL_TIME, 200);
+    mock.set_register(registers::P00_MAX_SPEED, 3000);
+
+    // Unsorted input, values come back in the requested order
+    let values = servo
+        .read_params(&[
+            registers::P05_DECEL_TIME,
+            registers::P00_MAX_SPEED,
+            registers::P05_ACCEL_TIME,
+        ])
+        .await?;
+    println!("  values: {:?}", values);
+
+    let reads = mock.reads();
+    println!("  {} requests: {:04X?}", reads.len(), reads);
+    Ok(())
+}
+
+/// Example 6: Clone parameters from one drive to another
+async fn example_parameter_clone() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Parameter dump and restore ===\n");
+
+    let (mut source, source_mock) = simulated_client(ServoConfig::new(1));
+    source_mock.set_register(registers::P00_MAX_SPEED, 3000);
+    let backup = source.dump_parameters().await?;
+    println!("  dumped {} registers", backup.len());
+
+    let (mut target, target_mock) = simulated_client(ServoConfig::new(2));
+    target.restore_parameters(&backup).await?;
+    println!("  restored with {} writes", target_mock.writes().len());
+    Ok(())
+}
+
+/// Example 7: Retry transient errors
+async fn example_retry() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Retry with backoff ===\n");
+
+    let config =
+        ServoConfig::new(1).with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1)));
+    let (mut servo, mock) = simulated_client(config);
+    mock.set_register(registers::P00_MAX_SPEED, 3000);
+
+    // Two timeouts, then the third attempt succeeds
+    mock.fail_next(2);
+    let value = servo.read_register(registers::P00_MAX_SPEED).await?;
+    println!("  read {} after 2 timeouts", value);
+
+    // A permanent failure gives up after max_retries
+    mock.fail_next(4);
+    if let Err(e) = servo.write_register(registers::P00_MAX_SPEED, 2000).await {
+        println!("  {}", e);
+    }
+    Ok(())
+}
+
+/// Example 8: The same mock drives the synchronous client
+fn example_sync_client() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Synchronous client ===\n");
+
+    let mock = MockTransport::new();
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 1500);
+    let mut servo = DsyrsSyncClient::new(mock.clone(), ServoConfig::new(3));
+    servo.init()?;
+    println!("  speed: {} rpm", servo.get_speed()?);
+    Ok(())
+}
+
+/// Example 9: Relative positioning
+async fn example_positioning() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Positioning ===\n");
+
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P04_POSITIONING_RANGE, 10);
+
+    // Starts at 1000, moves, then settles at 1495
+    mock.script_reads(registers::P18_ABSOLUTE_POSITION + 1, [1000, 1300]);
+    mock.set_register(registers::P18_ABSOLUTE_POSITION + 1, 1495);
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [200, 0]);
+    servo
+        .move_relative(500, 300, Duration::from_millis(500))
+        .await?;
+    println!("  moved +500 to {}", servo.get_position().await?);
+    Ok(())
+}
+
+/// Example 10: Monitoring the status as a stream
+async fn example_status_stream() -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n=== Status stream ===\n");
+
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+    // One failed read is reported and sampling carries on
+    mock.fail_next(1);
+    let mut stream = servo.status_stream(Duration::from_millis(10)).take(5);
+    while let Some(sample) = stream.next().await {
+        match sample {
+            Ok(status) => println!("  {:?}, {} rpm", status.state, status.speed),
+            Err(e) => println!("  read failed: {}", e),
+        }
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("DSY-RS Simulated Servo Examples");
+    println!("===============================\n");
+
+    example_init_writes().await?;
+    example_scripted_status().await?;
+    example_fault_recovery().await?;
+    example_homing().await?;
+    example_batched_reads().await?;
+    example_parameter_clone().await?;
+    example_retry().await?;
+    example_sync_client()?;
+    example_positioning().await?;
+    example_status_stream().await?;
+
+    println!("\n===============================");
+    println!("Examples completed!");
+
+    Ok(())
+}
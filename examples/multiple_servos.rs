@@ -3,6 +3,7 @@
 //! This example demonstrates:
 //! - Controlling multiple servos on the same RS-485 bus
 //! - Coordinated motion between servos
+//! - Stopping all servos at once with a broadcast write
 //! - Using async for concurrent operations
 //!
 //! Run with: cargo run --example multiple_servos
@@ -80,8 +81,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("  State: {:?}", status.state);
         println!("  Speed: {} rpm", status.speed);
         println!("  Position: {} pulses", status.position);
-        println!("  Torque: {:.1}%", status.torque as f32 * 0.1);
-        println!("  Bus Voltage: {:.1} V", status.bus_voltage as f32 * 0.1);
+        println!("  Torque: {:.1}%", status.torque_percent());
+        println!("  Bus Voltage: {:.1} V", status.bus_voltage_volts());
 
         ctx = servo.into_context();
     }
@@ -125,19 +126,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!();
     }
 
-    // Synchronized stop
+    // Synchronized stop: a single broadcast write reaches every drive at once
     println!("\n--- Stopping All Servos ---");
-    for &slave_id in &SERVO_IDS {
-        ctx.set_slave(Slave::from(slave_id));
-
-        let config = ServoConfig::new(slave_id);
-        let mut servo = DsyrsClient::new(ctx, config);
-
-        servo.set_speed_command(0).await?;
-        println!("Servo {}: Stopped", slave_id);
-
-        ctx = servo.into_context();
-    }
+    let mut all = DsyrsClient::broadcast(ctx);
+    all.set_speed_command(0).await?;
+    println!("All servos stopped (broadcast)");
+    ctx = all.into_context();
 
     // Wait for deceleration
     tokio::time::sleep(Duration::from_millis(1000)).await;
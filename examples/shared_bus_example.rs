@@ -0,0 +1,94 @@
+//! Example: Servos and another Modbus device on one RS485 bus through `SharedBus`
+//!
+//! Same setup as sync_interop_example.rs, without the manual context
+//! juggling: `SharedBus` owns the tokio-modbus sync context and lends it to
+//! one client at a time, selecting the slave before each call and taking the
+//! context back after it.
+//!
+//! Key concepts:
+//! - Borrowing a servo client for one closure with `with_servo()`
+//! - Using a full `ServoConfig` with `with_servo_config()`
+//! - Lending the raw context to another device with `with_device()`
+//! - Running the pattern against a simulated bus (no hardware)
+
+use dsyrs::bus::SharedBus;
+use dsyrs::mock::MockTransport;
+use dsyrs::{registers, ControlMode, ServoConfig, Slave};
+use tokio_modbus::prelude::{client, SyncReader};
+
+// Used by the hardware example below (commented out in main)
+#[allow(dead_code)]
+const SERIAL_PORT: &str = "/dev/ttyUSB0";
+#[allow(dead_code)]
+const BAUD_RATE: u32 = 115200;
+
+/// Example 1: Servo on slave 1 and another device on slave 2 of a real bus
+#[allow(dead_code)]
+fn example_shared_bus() -> Result<(), Box<dyn std::error::Error>> {
+    println!("=== Shared Bus Example ===\n");
+
+    let builder = tokio_serial::new(SERIAL_PORT, BAUD_RATE);
+    let ctx = client::sync::rtu::connect_slave(&builder, Slave::from(1))?;
+    let mut bus = SharedBus::new(ctx);
+
+    // Servo on slave 1
+    let servo_config = ServoConfig::new(1)
+        .with_control_mode(ControlMode::Position)
+        .with_max_speed(3000);
+    bus.with_servo_config(servo_config, |servo| servo.init())?;
+    let speed = bus.with_servo(1, |servo| servo.get_speed())?;
+    println!("Servo speed: {} rpm", speed);
+
+    // Device on slave 2 (e.g. a stepper driver), through the raw context
+    let words = bus.with_device(
+        2,
+        |ctx| ctx,
+        |ctx| ctx,
+        |ctx| ctx.read_holding_registers(0x0000, 1),
+    )??;
+    println!("Slave 2 register 0x0000: {:?}", words);
+
+    // Back to the servo: the slave is switched automatically
+    let status = bus.with_servo(1, |servo| servo.get_status())?;
+    println!("Servo state: {:?}", status.state);
+
+    Ok(())
+}
+
+/// Example 2: The same pattern on a simulated bus
+/// (This example doesn't require hardware)
+fn example_simulated_bus() -> Result<(), Box<dyn std::error::Error>> {
+    println!("=== Simulated Shared Bus ===\n");
+
+    let mock = MockTransport::new();
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 1500);
+    let mut bus = SharedBus::new(mock.clone());
+
+    let speed = bus.with_servo(1, |servo| servo.get_speed())?;
+    println!("Servo 1 speed: {} rpm", speed);
+
+    // Another device: the raw context, addressed to slave 2
+    let slave = bus.with_device(2, |ctx| ctx, |ctx| ctx, |ctx| ctx.slave());
+    println!("Device call addressed slave {:?}", slave);
+
+    bus.with_servo(1, |servo| servo.set_speed_command(500))?;
+    println!("Servo 1 addressed again: slave {:?}", mock.slave());
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("DSY-RS Shared Bus Examples");
+    println!("==========================\n");
+
+    // This example doesn't require hardware:
+    example_simulated_bus()?;
+
+    // Uncomment to run with actual hardware:
+    // example_shared_bus()?;
+
+    println!("\n==========================");
+    println!("Examples completed!");
+
+    Ok(())
+}
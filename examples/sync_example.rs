@@ -10,11 +10,10 @@
 
 use dsyrs::{
     ControlMode, Direction, DsyrsSyncClient, JogConfig, MultiSegOperationMode,
-    MultiSegPositionMode, SegmentConfig, ServoConfig, ServoState, Slave,
+    MultiSegPositionMode, SegmentConfig, ServoConfig, ServoState,
 };
 use std::thread;
 use std::time::Duration;
-use tokio_modbus::prelude::client;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("DSY-RS Synchronous Example");
@@ -30,12 +29,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         port_name, baud_rate, slave_id
     );
 
-    // Create serial port builder
-    let builder = tokio_serial::new(port_name, baud_rate);
-
-    // Connect to slave via tokio-modbus sync API
-    let ctx = client::sync::rtu::connect_slave(&builder, Slave::from(slave_id))?;
-
     // Create servo configuration for position control
     // Note: rated_current, encoder_type, encoder_resolution, and motor_model_code are optional.
     // If not specified, they will be read from the servo during init().
@@ -43,10 +36,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_control_mode(ControlMode::Position)
         .with_direction(Direction::CwForward)
         .with_max_speed(3000)
-        .with_rated_current(3.0);
+        .with_rated_current(3.0)
+        .with_timeout(Duration::from_millis(100));
 
-    // Create synchronous client with context
-    let mut servo = DsyrsSyncClient::new(ctx, config);
+    // Open the serial port (the timeout bounds each response)
+    let mut servo = DsyrsSyncClient::connect(port_name, baud_rate, config)?;
 
     // Initialize servo with configuration
     println!("Initializing servo drive...");
@@ -58,12 +52,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Read version information
     println!("\n--- Version Information ---");
-    let sw_ver = servo.get_software_version()?;
-    let fpga_ver = servo.get_fpga_version()?;
-    let product_code = servo.get_product_code()?;
-    println!("Software Version: {}", sw_ver);
-    println!("FPGA Version: {}", fpga_ver);
-    println!("Product Code: {}", product_code);
+    let firmware = servo.get_firmware_info()?;
+    println!("Firmware: {}", firmware);
 
     // Example: Configure jog parameters
     println!("\n--- Jog Configuration ---");
@@ -94,6 +84,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         speed: 1000,
         accel_decel_time: 100,
         wait_time: 500, // Wait 500 ms after completion
+        position_mode_override: None,
     };
     servo.configure_segment(&segment1)?;
     println!(
@@ -108,6 +99,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         speed: 500,
         accel_decel_time: 150,
         wait_time: 500,
+        position_mode_override: None,
     };
     servo.configure_segment(&segment2)?;
     println!(
@@ -122,6 +114,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         speed: 800,
         accel_decel_time: 100,
         wait_time: 0,
+        position_mode_override: None,
     };
     servo.configure_segment(&segment3)?;
     println!(
@@ -191,12 +184,12 @@ fn display_status(servo: &mut DsyrsSyncClient) -> Result<(), Box<dyn std::error:
     println!("State: {:?}", status.state);
     println!("Speed: {} rpm", status.speed);
     println!("Position: {} pulses", status.position);
-    println!("Torque: {:.1}% of rated", status.torque as f32 * 0.1);
-    println!("Current: {:.2} A", status.current as f32 * 0.01);
-    println!("Bus Voltage: {:.1} V", status.bus_voltage as f32 * 0.1);
+    println!("Torque: {:.1}% of rated", status.torque_percent());
+    println!("Current: {:.2} A", status.current_amps());
+    println!("Bus Voltage: {:.1} V", status.bus_voltage_volts());
     println!(
         "Electrical Angle: {:.1}°",
-        status.electrical_angle as f32 * 0.1
+        status.electrical_angle_degrees()
     );
     Ok(())
 }
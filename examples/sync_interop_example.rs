@@ -12,10 +12,14 @@
 use dsyrs::{ControlMode, DsyrsSyncClient, ServoConfig, Slave};
 use tokio_modbus::prelude::{client, SlaveContext};
 
+// Used by the hardware examples below (commented out in main)
+#[allow(dead_code)]
 const SERIAL_PORT: &str = "/dev/ttyUSB0";
+#[allow(dead_code)]
 const BAUD_RATE: u32 = 115200;
 
 /// Example 1: Basic sync servo connection
+#[allow(dead_code)]
 fn example_sync_servo() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== Sync Servo Example ===\n");
 
@@ -42,13 +46,11 @@ fn example_sync_servo() -> Result<(), Box<dyn std::error::Error>> {
     println!("  State: {:?}", status.state);
     println!("  Speed: {} rpm", status.speed);
     println!("  Position: {}", status.position);
-    println!("  Load: {}%", status.load_rate as f32 * 0.1);
+    println!("  Load: {}%", status.load_rate_percent());
 
     // Read versions
-    let sw_version = servo.get_software_version()?;
-    let fpga_version = servo.get_fpga_version()?;
-    println!("  SW Version: {:#06X}", sw_version);
-    println!("  FPGA Version: {:#06X}", fpga_version);
+    let firmware = servo.get_firmware_info()?;
+    println!("  Firmware: {}", firmware);
 
     Ok(())
 }
@@ -62,6 +64,7 @@ fn example_sync_servo() -> Result<(), Box<dyn std::error::Error>> {
 /// 4. Extract context with into_context()
 /// 5. Switch slave with ctx.set_slave()
 /// 6. Create em2rs client with the same context
+#[allow(dead_code)]
 fn example_multi_device_sync() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n=== Multi-Device Sync Example ===\n");
 
@@ -0,0 +1,103 @@
+//! One RS485 bus shared between dsyrs servos and other Modbus devices
+//!
+//! Clients of one bus, such as the servos and a stepper driver library built
+//! on tokio-modbus, use the same sync context and only switch the slave
+//! address. [`SharedBus`] owns that context and lends it to one client at a
+//! time: each `with_*()` call selects the slave, builds the client, runs the
+//! closure and takes the context back, so the `into_context()` / `set_slave()`
+//! shuffle is never written by hand.
+//!
+//! The clients are short-lived: state cached by a client (such as the motor
+//! maximum speed) does not survive from one call to the next.
+//!
+//! # Example
+//! ```
+//! use dsyrs::bus::SharedBus;
+//! use dsyrs::mock::MockTransport;
+//! use dsyrs::{DsyrsSyncClient, ServoConfig};
+//!
+//! let mock = MockTransport::new();
+//! let mut bus = SharedBus::new(mock.clone());
+//!
+//! let speed = bus.with_servo(1, |servo| servo.get_speed())?;
+//! bus.with_servo_config(ServoConfig::new(3).with_max_speed(3000), |servo| servo.init())?;
+//!
+//! // Any client that owns the context, built and taken apart by the caller
+//! bus.with_device(
+//!     2,
+//!     |ctx| DsyrsSyncClient::new(ctx, ServoConfig::new(2)),
+//!     DsyrsSyncClient::into_context,
+//!     |client| client.get_speed(),
+//! )?;
+//! assert_eq!(mock.slave(), Some(2));
+//! # let _ = speed;
+//! # Ok::<(), dsyrs::DsyrsError>(())
+//! ```
+
+use crate::sync::DsyrsSyncClient;
+use crate::transport::SyncModbusTransport;
+use crate::types::ServoConfig;
+use tokio_modbus::prelude::*;
+
+/// Sync Modbus context shared by the devices of one bus
+pub struct SharedBus<C = client::sync::Context> {
+    /// `None` only if a closure panicked while holding the context
+    ctx: Option<C>,
+}
+
+impl<C: SyncModbusTransport> SharedBus<C> {
+    /// Take ownership of the bus context
+    pub fn new(ctx: C) -> Self {
+        Self { ctx: Some(ctx) }
+    }
+
+    /// Give the context back, addressed to the last slave used
+    pub fn into_context(mut self) -> C {
+        self.take_context()
+    }
+
+    /// Run `f` on a servo client for slave `id`, with the default configuration
+    pub fn with_servo<R>(&mut self, id: u8, f: impl FnOnce(&mut DsyrsSyncClient<C>) -> R) -> R {
+        self.with_servo_config(ServoConfig::new(id), f)
+    }
+
+    /// Run `f` on a servo client built from `config` (slave `config.slave_id`)
+    pub fn with_servo_config<R>(
+        &mut self,
+        config: ServoConfig,
+        f: impl FnOnce(&mut DsyrsSyncClient<C>) -> R,
+    ) -> R {
+        self.with_device(
+            config.slave_id,
+            |ctx| DsyrsSyncClient::new(ctx, config),
+            DsyrsSyncClient::into_context,
+            f,
+        )
+    }
+
+    /// Run `f` on any client of the bus, such as a stepper driver, at slave `id`
+    ///
+    /// `attach` builds the client from the context, already addressed to `id`,
+    /// and `detach` gives it back (usually the client's `into_context()`).
+    /// Pass the identity for both to use the raw context.
+    pub fn with_device<D, R>(
+        &mut self,
+        id: u8,
+        attach: impl FnOnce(C) -> D,
+        detach: impl FnOnce(D) -> C,
+        f: impl FnOnce(&mut D) -> R,
+    ) -> R {
+        let mut ctx = self.take_context();
+        ctx.set_slave(Slave(id));
+        let mut device = attach(ctx);
+        let result = f(&mut device);
+        self.ctx = Some(detach(device));
+        result
+    }
+
+    fn take_context(&mut self) -> C {
+        self.ctx
+            .take()
+            .expect("SharedBus context lost: a previous client closure panicked")
+    }
+}
@@ -0,0 +1,77 @@
+//! Object-safe servo drive trait
+//!
+//! The client methods are inherent `async fn`s, which cannot be called through
+//! a trait object. [`ServoDrive`] exposes the common operations with
+//! `async-trait`, so drives of different kinds (e.g. DSY-RS servos next to
+//! steppers implementing the same trait) can be stored as
+//! `Box<dyn ServoDrive>` and driven polymorphically. The concrete client
+//! methods are unchanged and remain the full API.
+//!
+//! Available with the `drive-trait` feature, which pulls in the `async-trait`
+//! dependency.
+//!
+//! # Example
+//! ```
+//! use dsyrs::drive::ServoDrive;
+//! use dsyrs::{ControlMode, DsyrsClient, MockTransport, ServoConfig};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> dsyrs::Result<()> {
+//! let mut fleet: Vec<Box<dyn ServoDrive>> = vec![
+//!     Box::new(DsyrsClient::new(MockTransport::new(), ServoConfig::new(1))),
+//!     Box::new(DsyrsClient::new(MockTransport::new(), ServoConfig::new(2))),
+//! ];
+//! for drive in fleet.iter_mut() {
+//!     drive.set_control_mode(ControlMode::Speed).await?;
+//!     drive.set_speed_command(500).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::DsyrsClient;
+use crate::transport::ModbusTransport;
+use crate::types::{ControlMode, Result, ServoStatus};
+use async_trait::async_trait;
+
+/// Common drive operations, callable through `dyn ServoDrive`
+#[async_trait]
+pub trait ServoDrive: Send {
+    /// Read the main status registers
+    async fn get_status(&mut self) -> Result<ServoStatus>;
+
+    /// Set the speed command (rpm)
+    async fn set_speed_command(&mut self, rpm: i16) -> Result<()>;
+
+    /// Set the control mode
+    async fn set_control_mode(&mut self, mode: ControlMode) -> Result<()>;
+
+    /// Reset the current fault
+    async fn reset_fault(&mut self) -> Result<()>;
+
+    /// Read the absolute position (command units)
+    async fn get_position(&mut self) -> Result<i32>;
+}
+
+#[async_trait]
+impl<T: ModbusTransport> ServoDrive for DsyrsClient<T> {
+    async fn get_status(&mut self) -> Result<ServoStatus> {
+        DsyrsClient::get_status(self).await
+    }
+
+    async fn set_speed_command(&mut self, rpm: i16) -> Result<()> {
+        DsyrsClient::set_speed_command(self, rpm).await
+    }
+
+    async fn set_control_mode(&mut self, mode: ControlMode) -> Result<()> {
+        DsyrsClient::set_control_mode(self, mode).await
+    }
+
+    async fn reset_fault(&mut self) -> Result<()> {
+        DsyrsClient::reset_fault(self).await
+    }
+
+    async fn get_position(&mut self) -> Result<i32> {
+        DsyrsClient::get_position(self).await
+    }
+}
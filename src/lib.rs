@@ -7,6 +7,7 @@
 //! # Features
 //! - Async API using tokio-modbus
 //! - Synchronous wrapper for blocking contexts  
+//! - Sync client reopening the serial port after an adapter drop (`ReconnectingClient`)
 //! - Support for multiple servo instances on the same bus
 //! - **Interoperability with em2rs library** for mixed servo/stepper systems
 //! - Complete parameter access based on official documentation
@@ -15,6 +16,15 @@
 //! - Homing routines
 //! - Digital I/O configuration
 //! - Real-time status monitoring
+//! - Parameter import from vendor parameter files
+//! - Offline configuration model with validation
+//! - Physical-unit newtypes for status values
+//! - Pluggable Modbus transport, with an in-memory mock for tests (`test-util` feature)
+//! - Serialize/Deserialize for configuration types (`serde` feature)
+//! - Object-safe `ServoDrive` trait for mixed fleets (`drive-trait` feature)
+//! - `SharedBus` lending one sync context to the servos and other devices of a bus
+//! - Diagnostics through `log` by default, or `tracing` with per-transaction
+//!   bus events carrying slave, direction, address and value fields (`tracing` feature)
 //!
 //! # Register Addressing
 //! Parameters are addressed as PXX.YY where:
@@ -166,15 +176,37 @@
 //! }
 //! ```
 
+#[macro_use]
+mod logging;
+
+pub mod bus;
 pub mod client;
+#[cfg(feature = "drive-trait")]
+pub mod drive;
+#[cfg(feature = "test-util")]
+pub mod mock;
+pub mod model;
+pub mod params;
+pub mod reconnect;
 pub mod registers;
 pub mod sync;
+pub mod transport;
 pub mod types;
+pub mod units;
 
 // Re-export main types
-pub use client::DsyrsClient;
-pub use sync::DsyrsSyncClient;
+pub use client::{DsyrsClient, ForcedIoGuard};
+#[cfg(feature = "drive-trait")]
+pub use drive::ServoDrive;
+#[cfg(feature = "test-util")]
+pub use mock::MockTransport;
+pub use model::ServoConfigModel;
+pub use params::ParameterSet;
+pub use reconnect::ReconnectingClient;
+pub use sync::{DsyrsSyncClient, SyncForcedIoGuard};
+pub use transport::{ModbusTransport, SyncModbusTransport};
 pub use types::*;
+pub use units::{Amps, Rpm, TorquePercent, Volts};
 
 // Re-export tokio_modbus prelude for convenience
-pub use tokio_modbus::prelude::{client as modbus_client, rtu, Slave, SlaveContext};
+pub use tokio_modbus::prelude::{client as modbus_client, rtu, ExceptionCode, Slave, SlaveContext};
@@ -0,0 +1,95 @@
+//! Diagnostics routed to `log` (default) or `tracing` (`tracing` feature)
+//!
+//! The clients report through the macros below instead of calling a logging
+//! crate directly. By default they expand to `log` records. With the
+//! `tracing` feature they emit `tracing` events instead, and the events that
+//! carry data do so as fields:
+//!
+//! - one debug event per Modbus read or write (target `dsyrs::bus`), with
+//!   `slave`, `direction` (`"read"` or `"write"`), `addr`, `param` (e.g.
+//!   `P13.08`) and `count` or `value`, enough to capture a full bus trace
+//!   with a subscriber;
+//! - one warning per `init()` mismatch, with `parameter`, `expected` and
+//!   `read`.
+
+/// Warning without structured fields
+macro_rules! log_warn {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::warn!($($arg)+);
+    }};
+}
+
+/// Debug message without structured fields
+macro_rules! log_debug {
+    ($($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        log::debug!($($arg)+);
+    }};
+}
+
+/// Configured value that differs from the one read from the drive in `init()`
+macro_rules! init_mismatch {
+    ($parameter:expr, $expected:expr, $read:expr) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            parameter = $parameter,
+            expected = ?$expected,
+            read = ?$read,
+            "init mismatch"
+        );
+        #[cfg(not(feature = "tracing"))]
+        log::warn!(
+            "{} mismatch: expected {:?}, read {:?}",
+            $parameter,
+            $expected,
+            $read
+        );
+    }};
+}
+
+/// One Modbus transaction: `read` with a register count, or `write` with the value(s)
+macro_rules! bus_event {
+    (read, $slave:expr, $addr:expr, $count:expr) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: "dsyrs::bus",
+            slave = $slave,
+            direction = "read",
+            addr = $addr,
+            param = %crate::params::format_param_code($addr),
+            count = $count,
+        );
+        #[cfg(not(feature = "tracing"))]
+        log::debug!(
+            target: "dsyrs::bus",
+            "slave {} read {} x{}",
+            $slave,
+            crate::params::format_param_code($addr),
+            $count
+        );
+    }};
+    (write, $slave:expr, $addr:expr, $value:expr) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: "dsyrs::bus",
+            slave = $slave,
+            direction = "write",
+            addr = $addr,
+            param = %crate::params::format_param_code($addr),
+            value = ?$value,
+        );
+        #[cfg(not(feature = "tracing"))]
+        log::debug!(
+            target: "dsyrs::bus",
+            "slave {} write {} = {:?}",
+            $slave,
+            crate::params::format_param_code($addr),
+            $value
+        );
+    }};
+}
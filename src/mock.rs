@@ -0,0 +1,355 @@
+//! In-memory Modbus transport for testing client logic without hardware
+//!
+//! [`MockTransport`] answers register requests from a register map, records
+//! every read and write, and can script the values returned by successive
+//! reads, inject transient failures, truncate replies or answer with a
+//! Modbus exception. It
+//! implements both [`ModbusTransport`] and [`SyncModbusTransport`], so it
+//! drives either client.
+//!
+//! The transport is a cheap handle around shared state: keep a clone to
+//! script and inspect the simulated drive after handing it to a client.
+//!
+//! Like real drives, the mock does not answer broadcast writes (slave 0): the
+//! write is applied, then the async request never completes and the blocking
+//! one times out. Requests stalled with
+//! [`stall_next`](MockTransport::stall_next) behave the same way, without
+//! reaching the register map.
+//!
+//! Available with the `test-util` feature.
+//!
+//! # Example
+//! ```
+//! use dsyrs::mock::MockTransport;
+//! use dsyrs::{registers, ControlMode, DsyrsClient, Direction, ServoConfig};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> dsyrs::Result<()> {
+//! let mock = MockTransport::new();
+//! let config = ServoConfig::new(1)
+//!     .with_control_mode(ControlMode::Position)
+//!     .with_direction(Direction::CwForward)
+//!     .with_max_speed(3000);
+//! let mut servo = DsyrsClient::new(mock.clone(), config);
+//! servo.init().await?;
+//!
+//! assert_eq!(
+//!     mock.writes(),
+//!     vec![
+//!         (registers::P00_CONTROL_MODE, 0),
+//!         (registers::P00_DIRECTION, 1),
+//!         (registers::P00_MAX_SPEED, 3000),
+//!     ]
+//! );
+//! assert_eq!(mock.slave(), Some(1));
+//!
+//! // Scripted reads, then the register map
+//! mock.script_reads(registers::P18_SPEED_FEEDBACK, [500, 1000]);
+//! mock.set_register(registers::P18_SPEED_FEEDBACK, 0);
+//! assert_eq!(servo.get_speed().await?, 500);
+//! assert_eq!(servo.get_speed().await?, 1000);
+//! assert_eq!(servo.get_speed().await?, 0);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::transport::{ModbusTransport, SyncModbusTransport};
+use crate::{registers, DsyrsClient, ServoConfig};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio_modbus::prelude::*;
+
+/// Simulated drive shared by the clones of a [`MockTransport`]
+#[derive(Debug, Default)]
+struct MockState {
+    /// Register map (unset registers read as 0)
+    registers: HashMap<u16, u16>,
+    /// Scripted values returned by successive reads of a register
+    scripted: HashMap<u16, VecDeque<u16>>,
+    /// Registers answering with a Modbus exception
+    exceptions: HashMap<u16, ExceptionCode>,
+    /// Every read request as (start, count), in order
+    reads: Vec<(u16, u16)>,
+    /// Every register write, in order
+    writes: Vec<(u16, u16)>,
+    /// Slave selected by the client
+    slave: Option<u8>,
+    /// Number of upcoming requests that time out
+    fail_next: u32,
+    /// Number of upcoming requests that never get a response
+    stall_next: u32,
+    /// Number of upcoming reads answered with no registers
+    short_read_next: u32,
+}
+
+impl MockState {
+    /// Fail the request if a transient failure is pending
+    fn check_transport(&mut self) -> Result<(), tokio_modbus::Error> {
+        if self.fail_next > 0 {
+            self.fail_next -= 1;
+            return Err(tokio_modbus::Error::Transport(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "simulated timeout",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Drop the request if a stall is pending
+    fn take_stall(&mut self) -> bool {
+        if self.stall_next > 0 {
+            self.stall_next -= 1;
+            return true;
+        }
+        false
+    }
+
+    /// Exception configured for any register in `addr..addr + count`
+    fn exception(&self, addr: u16, count: u16) -> Option<ExceptionCode> {
+        (0..count).find_map(|i| self.exceptions.get(&addr.wrapping_add(i)).copied())
+    }
+
+    fn read(&mut self, addr: u16, count: u16) -> tokio_modbus::Result<Vec<u16>> {
+        self.check_transport()?;
+        self.reads.push((addr, count));
+        if let Some(code) = self.exception(addr, count) {
+            return Ok(Err(code));
+        }
+        if self.short_read_next > 0 {
+            self.short_read_next -= 1;
+            return Ok(Ok(Vec::new()));
+        }
+        let values = (0..count)
+            .map(|i| {
+                let addr = addr.wrapping_add(i);
+                self.scripted
+                    .get_mut(&addr)
+                    .and_then(VecDeque::pop_front)
+                    .unwrap_or_else(|| self.registers.get(&addr).copied().unwrap_or(0))
+            })
+            .collect();
+        Ok(Ok(values))
+    }
+
+    /// Whether the selected slave is the broadcast address
+    fn is_broadcast(&self) -> bool {
+        self.slave == Some(0)
+    }
+
+    fn write(&mut self, addr: u16, values: &[u16]) -> tokio_modbus::Result<()> {
+        self.check_transport()?;
+        if let Some(code) = self.exception(addr, values.len() as u16) {
+            return Ok(Err(code));
+        }
+        for (i, &value) in values.iter().enumerate() {
+            let addr = addr.wrapping_add(i as u16);
+            self.writes.push((addr, value));
+            self.registers.insert(addr, value);
+        }
+        Ok(Ok(()))
+    }
+}
+
+/// In-memory transport answering from a simulated register map
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// Create a transport with an empty register map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Set the value of a register
+    pub fn set_register(&self, addr: u16, value: u16) {
+        self.state().registers.insert(addr, value);
+    }
+
+    /// Set consecutive registers starting at `addr`
+    pub fn set_registers(&self, addr: u16, values: &[u16]) {
+        let mut state = self.state();
+        for (i, &value) in values.iter().enumerate() {
+            state.registers.insert(addr.wrapping_add(i as u16), value);
+        }
+    }
+
+    /// Current value of a register (0 if never set)
+    pub fn register(&self, addr: u16) -> u16 {
+        self.state().registers.get(&addr).copied().unwrap_or(0)
+    }
+
+    /// Queue values returned by the next reads of a register
+    ///
+    /// Once the queue is exhausted, reads fall back to the register map.
+    pub fn script_reads(&self, addr: u16, values: impl IntoIterator<Item = u16>) {
+        self.state()
+            .scripted
+            .entry(addr)
+            .or_default()
+            .extend(values);
+    }
+
+    /// Answer every request touching `addr` with a Modbus exception
+    pub fn set_exception(&self, addr: u16, code: ExceptionCode) {
+        self.state().exceptions.insert(addr, code);
+    }
+
+    /// Make the next `count` requests fail with a timeout
+    pub fn fail_next(&self, count: u32) {
+        self.state().fail_next = count;
+    }
+
+    /// Make the next `count` requests go unanswered
+    ///
+    /// The requests are dropped: an async request never completes and a
+    /// blocking one fails with a timeout, as with a wedged bus.
+    pub fn stall_next(&self, count: u32) {
+        self.state().stall_next = count;
+    }
+
+    /// Answer the next `count` reads with no registers, as a truncated reply
+    pub fn short_read_next(&self, count: u32) {
+        self.state().short_read_next = count;
+    }
+
+    /// Every register written so far, as (address, value), in order
+    pub fn writes(&self) -> Vec<(u16, u16)> {
+        self.state().writes.clone()
+    }
+
+    /// Every read request so far, as (start, count), in order
+    pub fn reads(&self) -> Vec<(u16, u16)> {
+        self.state().reads.clone()
+    }
+
+    /// Forget the recorded reads and writes
+    pub fn clear_history(&self) {
+        let mut state = self.state();
+        state.reads.clear();
+        state.writes.clear();
+    }
+
+    /// Slave last selected by the client
+    pub fn slave(&self) -> Option<u8> {
+        self.state().slave
+    }
+
+    /// Apply a write; broadcast writes never get a response
+    fn async_write(
+        &self,
+        addr: u16,
+        values: &[u16],
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send + 'static {
+        let mut state = self.state();
+        if state.take_stall() {
+            return Self::no_response(Ok(Ok(())), true);
+        }
+        let result = state.write(addr, values);
+        Self::no_response(result, state.is_broadcast())
+    }
+
+    /// Apply a write; broadcast writes time out waiting for a response
+    fn sync_write(&self, addr: u16, values: &[u16]) -> tokio_modbus::Result<()> {
+        let mut state = self.state();
+        if state.take_stall() {
+            return Err(Self::response_timeout("stalled request"));
+        }
+        let result = state.write(addr, values);
+        if state.is_broadcast() {
+            return Err(Self::response_timeout("no response to broadcast"));
+        }
+        result
+    }
+
+    /// Response future, never ready when `silent`
+    async fn no_response<R>(
+        result: tokio_modbus::Result<R>,
+        silent: bool,
+    ) -> tokio_modbus::Result<R> {
+        if silent {
+            std::future::pending::<()>().await;
+        }
+        result
+    }
+
+    /// Timeout reported by a blocking request that got no response
+    fn response_timeout(reason: &str) -> tokio_modbus::Error {
+        tokio_modbus::Error::Transport(io::Error::new(io::ErrorKind::TimedOut, reason))
+    }
+}
+
+/// Build a client talking to a fresh simulated drive
+///
+/// The drive reports the motor nameplate values read by `init()`: a rated
+/// current of 2.50 A (P01.04) and a maximum speed of 3000 rpm (P01.08).
+/// The returned transport is a clone for scripting and inspecting the drive.
+pub fn simulated_client(config: ServoConfig) -> (DsyrsClient<MockTransport>, MockTransport) {
+    let mock = MockTransport::new();
+    mock.set_register(registers::P01_RATED_CURRENT, 250);
+    mock.set_register(registers::P01_MAX_SPEED, 3000);
+    (DsyrsClient::new(mock.clone(), config), mock)
+}
+
+impl ModbusTransport for MockTransport {
+    fn set_slave(&mut self, slave: Slave) {
+        self.state().slave = Some(slave.0);
+    }
+
+    fn read_holding_registers(
+        &mut self,
+        addr: u16,
+        count: u16,
+    ) -> impl Future<Output = tokio_modbus::Result<Vec<u16>>> + Send {
+        let mut state = self.state();
+        if state.take_stall() {
+            return Self::no_response(Ok(Ok(Vec::new())), true);
+        }
+        Self::no_response(state.read(addr, count), false)
+    }
+
+    fn write_single_register(
+        &mut self,
+        addr: u16,
+        value: u16,
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send {
+        self.async_write(addr, &[value])
+    }
+
+    fn write_multiple_registers<'a>(
+        &'a mut self,
+        addr: u16,
+        values: &'a [u16],
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send + 'a {
+        self.async_write(addr, values)
+    }
+}
+
+impl SyncModbusTransport for MockTransport {
+    fn set_slave(&mut self, slave: Slave) {
+        self.state().slave = Some(slave.0);
+    }
+
+    fn read_holding_registers(&mut self, addr: u16, count: u16) -> tokio_modbus::Result<Vec<u16>> {
+        let mut state = self.state();
+        if state.take_stall() {
+            return Err(Self::response_timeout("stalled request"));
+        }
+        state.read(addr, count)
+    }
+
+    fn write_single_register(&mut self, addr: u16, value: u16) -> tokio_modbus::Result<()> {
+        self.sync_write(addr, &[value])
+    }
+
+    fn write_multiple_registers(&mut self, addr: u16, values: &[u16]) -> tokio_modbus::Result<()> {
+        self.sync_write(addr, values)
+    }
+}
@@ -0,0 +1,433 @@
+//! Offline configuration model for DSY-RS servo drives
+//!
+//! A [`ServoConfigModel`] gathers the typed configuration of one drive (basic
+//! settings, gains, homing, I/O map, communication and multi-segment program)
+//! so it can be built, validated and shared as data without any hardware:
+//! - `validate()` checks every value and the cross-parameter constraints
+//! - `to_parameter_set()` computes the register addresses and raw values
+//! - `apply_model()` on either client writes the model to a drive
+//!
+//! # Example
+//! ```
+//! use dsyrs::model::ServoConfigModel;
+//! use dsyrs::{
+//!     registers, ControlMode, DiFunction, DiLogic, MotionProgram, SegmentConfig, ServoConfig,
+//! };
+//!
+//! let model = ServoConfigModel::new(ServoConfig::new(1).with_max_speed(3000))
+//!     .with_di(1, DiFunction::ServoEnable, DiLogic::HighActive)
+//!     .with_multi_segment(
+//!         MotionProgram::builder(1, 2)
+//!             .with_segment(SegmentConfig::new(1)?.with_displacement(10000))
+//!             .with_segment(SegmentConfig::new(2)?.with_displacement(-10000))
+//!             .build()?,
+//!     );
+//! model.validate()?;
+//!
+//! let set = model.to_parameter_set()?;
+//! assert_eq!(set.get(registers::P00_MAX_SPEED), Some(3000));
+//! assert_eq!(set.get(registers::P13_SEG2_DISPLACEMENT), Some(-10000i32 as u32));
+//!
+//! // Multi-segment motion needs position control
+//! let mut bad = model.clone();
+//! bad.servo.control_mode = ControlMode::Speed;
+//! assert!(bad.validate().is_err());
+//! # Ok::<(), dsyrs::DsyrsError>(())
+//! ```
+
+use crate::params::ParameterSet;
+use crate::registers;
+use crate::types::*;
+
+/// Function and logic assigned to a digital input (P02.01-P02.03, P02.11-P02.13)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiMapping {
+    /// Digital input number (1-3)
+    pub input: u8,
+    /// Assigned function
+    pub function: DiFunction,
+    /// Input logic
+    pub logic: DiLogic,
+}
+
+/// Function and logic assigned to a digital output (P02.21-P02.22, P02.31-P02.32)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoMapping {
+    /// Digital output number (1-2)
+    pub output: u8,
+    /// Assigned function
+    pub function: DoFunction,
+    /// Output logic
+    pub logic: DoLogic,
+}
+
+/// Complete offline configuration of one drive
+///
+/// Only the parts that are `Some` (or non-empty) are validated and written;
+/// the basic settings in `servo` are always included.
+#[derive(Debug, Clone)]
+pub struct ServoConfigModel {
+    /// Basic settings (P00.00, P00.01, P00.07)
+    pub servo: ServoConfig,
+    /// Gain parameters (P07.00-P07.03)
+    pub gains: Option<GainParams>,
+    /// Homing configuration (P16.08-P16.14)
+    pub homing: Option<HomingConfig>,
+    /// Digital input map
+    pub di_map: Vec<DiMapping>,
+    /// Digital output map
+    pub do_map: Vec<DoMapping>,
+    /// Communication settings (P10)
+    pub comm: Option<CommConfig>,
+    /// Multi-segment position program (P13)
+    pub multi_segment: Option<MotionProgram>,
+}
+
+impl ServoConfigModel {
+    /// Create a model with only the basic settings
+    pub fn new(servo: ServoConfig) -> Self {
+        Self {
+            servo,
+            gains: None,
+            homing: None,
+            di_map: Vec::new(),
+            do_map: Vec::new(),
+            comm: None,
+            multi_segment: None,
+        }
+    }
+
+    /// Set gain parameters
+    pub fn with_gains(mut self, gains: GainParams) -> Self {
+        self.gains = Some(gains);
+        self
+    }
+
+    /// Set homing configuration
+    pub fn with_homing(mut self, homing: HomingConfig) -> Self {
+        self.homing = Some(homing);
+        self
+    }
+
+    /// Map a digital input
+    pub fn with_di(mut self, input: u8, function: DiFunction, logic: DiLogic) -> Self {
+        self.di_map.push(DiMapping {
+            input,
+            function,
+            logic,
+        });
+        self
+    }
+
+    /// Map a digital output
+    pub fn with_do(mut self, output: u8, function: DoFunction, logic: DoLogic) -> Self {
+        self.do_map.push(DoMapping {
+            output,
+            function,
+            logic,
+        });
+        self
+    }
+
+    /// Set communication settings
+    pub fn with_comm(mut self, comm: CommConfig) -> Self {
+        self.comm = Some(comm);
+        self
+    }
+
+    /// Set multi-segment position program
+    pub fn with_multi_segment(mut self, program: MotionProgram) -> Self {
+        self.multi_segment = Some(program);
+        self
+    }
+
+    /// Check every value and the cross-parameter constraints, without I/O
+    pub fn validate(&self) -> Result<()> {
+        self.validate_basic()?;
+        self.validate_gains()?;
+        self.validate_homing()?;
+        self.validate_io()?;
+        self.validate_comm()?;
+        self.validate_multi_segment()
+    }
+
+    /// Compute the register values of the model as a parameter set
+    ///
+    /// The model is validated first. A parameter set is written in address
+    /// order; use `apply_model()` to write with the ordering the drive needs.
+    pub fn to_parameter_set(&self) -> Result<ParameterSet> {
+        self.validate()?;
+        let mut set = ParameterSet::new();
+
+        set.set(
+            registers::P00_CONTROL_MODE,
+            u16::from(self.servo.control_mode) as u32,
+        )?;
+        set.set(
+            registers::P00_DIRECTION,
+            u16::from(self.servo.direction) as u32,
+        )?;
+        set.set(registers::P00_MAX_SPEED, self.servo.max_speed as u32)?;
+
+        if let Some(gains) = &self.gains {
+            set.set(registers::P07_POSITION_GAIN1, gains.position_gain as u32)?;
+            set.set(registers::P07_SPEED_GAIN1, gains.speed_gain as u32)?;
+            set.set(registers::P07_SPEED_INTEGRAL1, gains.speed_integral as u32)?;
+            set.set(registers::P07_SPEED_FILTER1, gains.speed_filter as u32)?;
+            if let Some(gain) = gains.position_gain_2 {
+                set.set(registers::P07_POSITION_GAIN2, gain as u32)?;
+            }
+            if let Some(gain) = gains.speed_gain_2 {
+                set.set(registers::P07_SPEED_GAIN2, gain as u32)?;
+            }
+        }
+
+        if let Some(homing) = &self.homing {
+            set.set(registers::P16_HOMING_MODE, u16::from(homing.mode) as u32)?;
+            set.set(registers::P16_HOMING_HIGH_SPEED, homing.high_speed as u32)?;
+            set.set(registers::P16_HOMING_LOW_SPEED, homing.low_speed as u32)?;
+            set.set(registers::P16_HOMING_ACCEL, homing.accel_limit as u32)?;
+            set.set(registers::P16_HOMING_TIMEOUT, homing.timeout as u32)?;
+            set.set(registers::P16_HOME_OFFSET, homing.offset as u32)?;
+            if let Some(mode) = homing.enable_mode {
+                set.set(registers::P16_HOMING_ENABLE_MODE, u16::from(mode) as u32)?;
+            }
+        }
+
+        for di in &self.di_map {
+            let function = registers::get_di_function_register(di.input)
+                .ok_or(DsyrsError::InvalidDigitalInput(di.input))?;
+            let logic = registers::get_di_logic_register(di.input)
+                .ok_or(DsyrsError::InvalidDigitalInput(di.input))?;
+            set.set(function, u16::from(di.function) as u32)?;
+            set.set(logic, u16::from(di.logic) as u32)?;
+        }
+
+        for dout in &self.do_map {
+            let function = registers::get_do_function_register(dout.output)
+                .ok_or(DsyrsError::InvalidDigitalOutput(dout.output))?;
+            let logic = registers::get_do_logic_register(dout.output)
+                .ok_or(DsyrsError::InvalidDigitalOutput(dout.output))?;
+            set.set(function, u16::from(dout.function) as u32)?;
+            set.set(logic, u16::from(dout.logic) as u32)?;
+        }
+
+        if let Some(comm) = &self.comm {
+            set.set(registers::P10_COMM_ADDRESS, comm.address as u32)?;
+            set.set(
+                registers::P10_MODBUS_BAUDRATE,
+                u16::from(comm.baud_rate) as u32,
+            )?;
+            set.set(
+                registers::P10_MODBUS_FORMAT,
+                u16::from(comm.data_format) as u32,
+            )?;
+            set.set(
+                registers::P10_RS485_ADDRESS_SOURCE,
+                u16::from(comm.address_source) as u32,
+            )?;
+        }
+
+        if let Some(program) = &self.multi_segment {
+            set.set(
+                registers::P13_OPERATION_MODE,
+                u16::from(program.operation_mode) as u32,
+            )?;
+            set.set(registers::P13_START_SEGMENT, program.start as u32)?;
+            set.set(registers::P13_END_SEGMENT, program.end as u32)?;
+            set.set(
+                registers::P13_POSITION_MODE,
+                u16::from(program.position_mode) as u32,
+            )?;
+            for seg in &program.segments {
+                let invalid = || DsyrsError::InvalidSegment(seg.segment);
+                set.set(
+                    registers::get_segment_displacement_register(seg.segment)
+                        .ok_or_else(invalid)?,
+                    seg.displacement as u32,
+                )?;
+                set.set(
+                    registers::get_segment_speed_register(seg.segment).ok_or_else(invalid)?,
+                    seg.speed as u32,
+                )?;
+                set.set(
+                    registers::get_segment_accel_decel_register(seg.segment).ok_or_else(invalid)?,
+                    seg.accel_decel_time as u32,
+                )?;
+                set.set(
+                    registers::get_segment_wait_time_register(seg.segment).ok_or_else(invalid)?,
+                    seg.wait_time as u32,
+                )?;
+            }
+        }
+
+        Ok(set)
+    }
+
+    fn validate_basic(&self) -> Result<()> {
+        if !(1..=247).contains(&self.servo.slave_id) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Slave ID must be 1-247, got {}",
+                self.servo.slave_id
+            )));
+        }
+        if self.servo.max_speed > 10000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Max speed must be 0-10000 rpm".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_gains(&self) -> Result<()> {
+        let Some(gains) = &self.gains else {
+            return Ok(());
+        };
+        if !(10..=20000).contains(&gains.position_gain) {
+            return Err(DsyrsError::InvalidParameter(
+                "Position gain must be 10-20000 (0.1 Hz)".into(),
+            ));
+        }
+        if !(10..=20000).contains(&gains.speed_gain) {
+            return Err(DsyrsError::InvalidParameter(
+                "Speed gain must be 10-20000 (0.1 Hz)".into(),
+            ));
+        }
+        if let Some(gain) = gains.position_gain_2 {
+            if !(10..=20000).contains(&gain) {
+                return Err(DsyrsError::InvalidParameter(
+                    "Position gain 2 must be 10-20000 (0.1 Hz)".into(),
+                ));
+            }
+        }
+        if let Some(gain) = gains.speed_gain_2 {
+            if !(10..=20000).contains(&gain) {
+                return Err(DsyrsError::InvalidParameter(
+                    "Speed gain 2 must be 10-20000 (0.1 Hz)".into(),
+                ));
+            }
+        }
+        if gains.speed_filter > 200 {
+            return Err(DsyrsError::InvalidParameter(
+                "Speed detection filter must be 0-200 (0.01 ms)".into(),
+            ));
+        }
+        // The documented integral range (15-512) contradicts its default (3100),
+        // so the speed integral time is not range-checked
+        Ok(())
+    }
+
+    fn validate_homing(&self) -> Result<()> {
+        let Some(homing) = &self.homing else {
+            return Ok(());
+        };
+        if !(10..=3000).contains(&homing.high_speed) {
+            return Err(DsyrsError::InvalidParameter(
+                "Homing high speed must be 10-3000 rpm".into(),
+            ));
+        }
+        if !(10..=1000).contains(&homing.low_speed) {
+            return Err(DsyrsError::InvalidParameter(
+                "Homing low speed must be 10-1000 rpm".into(),
+            ));
+        }
+        if homing.low_speed > homing.high_speed {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Homing low speed ({} rpm) exceeds high speed ({} rpm)",
+                homing.low_speed, homing.high_speed
+            )));
+        }
+        if homing.high_speed > self.servo.max_speed {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Homing high speed ({} rpm) exceeds max speed ({} rpm)",
+                homing.high_speed, self.servo.max_speed
+            )));
+        }
+        if !(-(1 << 30)..=(1 << 30)).contains(&homing.offset) {
+            return Err(DsyrsError::InvalidParameter(
+                "Home offset must be within ±2^30".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_io(&self) -> Result<()> {
+        for (i, di) in self.di_map.iter().enumerate() {
+            if registers::get_di_function_register(di.input).is_none() {
+                return Err(DsyrsError::InvalidDigitalInput(di.input));
+            }
+            for other in &self.di_map[..i] {
+                if other.input == di.input {
+                    return Err(DsyrsError::InvalidParameter(format!(
+                        "DI{} is mapped twice",
+                        di.input
+                    )));
+                }
+                if di.function != DiFunction::None && other.function == di.function {
+                    return Err(DsyrsError::InvalidParameter(format!(
+                        "{:?} is assigned to both DI{} and DI{}",
+                        di.function, other.input, di.input
+                    )));
+                }
+            }
+        }
+
+        for (i, dout) in self.do_map.iter().enumerate() {
+            if registers::get_do_function_register(dout.output).is_none() {
+                return Err(DsyrsError::InvalidDigitalOutput(dout.output));
+            }
+            // P02.22 is documented as fixed to the lock release output
+            if dout.output == 2 && dout.function != DoFunction::BrakeReleaseSignalOutput {
+                return Err(DsyrsError::InvalidParameter(
+                    "DO2 must be the brake release output (FunOUT.11)".into(),
+                ));
+            }
+            if self.do_map[..i].iter().any(|o| o.output == dout.output) {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "DO{} is mapped twice",
+                    dout.output
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_comm(&self) -> Result<()> {
+        let Some(comm) = &self.comm else {
+            return Ok(());
+        };
+        if comm.address > 247 {
+            return Err(DsyrsError::InvalidParameter(
+                "Communication address must be 0-247".into(),
+            ));
+        }
+        if comm.address == 0 && comm.address_source == AddressSource::HostSetting {
+            return Err(DsyrsError::InvalidParameter(
+                "Address 0 is broadcast: the drive would no longer answer requests".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_multi_segment(&self) -> Result<()> {
+        let Some(program) = &self.multi_segment else {
+            return Ok(());
+        };
+        if self.servo.control_mode != ControlMode::Position {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Multi-segment program requires position control, model uses {:?}",
+                self.servo.control_mode
+            )));
+        }
+        program.validate()?;
+        for seg in &program.segments {
+            if seg.speed > self.servo.max_speed {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "Segment {} speed ({} rpm) exceeds max speed ({} rpm)",
+                    seg.segment, seg.speed, self.servo.max_speed
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+//! Parameter sets for bulk configuration of DSY-RS servo drives
+//!
+//! A [`ParameterSet`] holds raw parameter values keyed by register address.
+//! It can be built in code or loaded from a parameter file exported by the
+//! vendor tuning software, then written to a drive with
+//! `apply_parameter_set()` on either client.
+//!
+//! # Vendor File Format
+//! One parameter per line, as `PXX.YY=value` or `PXX.YY,value`:
+//! - The `P` prefix is case-insensitive, whitespace around tokens is ignored
+//! - Values are decimal (optionally signed) or hexadecimal with a `0x` prefix
+//! - 32-bit parameters (e.g. P04.07, P13.08) take the full 32-bit value on one line
+//! - Blank lines are skipped; `#` and `;` start a comment (whole line or trailing)
+//! - A parameter listed twice keeps the last value
+//!
+//! Only documented configuration parameters are accepted (see
+//! [`registers::CONFIG_PARAMS`]); unknown or read-only P-codes are rejected
+//! with the offending line number.
+
+use crate::registers;
+use crate::types::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Collection of parameter values to write to a drive
+///
+/// Values are stored as they are written to the drive: 16-bit parameters use
+/// the low word, negative values are stored in two's complement.
+///
+/// # Example
+/// ```
+/// use dsyrs::ParameterSet;
+/// use dsyrs::registers;
+///
+/// let set: ParameterSet = "
+///     ; tuning from the GUI tool
+///     P00.04=15
+///     P04.07 = 131072   ; 32-bit gear numerator
+///     P05.03=-500
+/// "
+/// .parse()?;
+///
+/// assert_eq!(set.len(), 3);
+/// assert_eq!(set.get(registers::P04_GEAR1_NUMERATOR), Some(131072));
+/// assert_eq!(set.get(registers::P05_SPEED_COMMAND), Some((-500i16) as u16 as u32));
+/// assert!("P01.00=1".parse::<ParameterSet>().is_err());
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParameterSet {
+    values: BTreeMap<u16, u32>,
+}
+
+impl ParameterSet {
+    /// Create an empty parameter set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a parameter set from a vendor parameter file
+    pub fn from_vendor_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| DsyrsError::IoError(format!("{}: {}", path.display(), e)))?;
+        text.parse()
+    }
+
+    /// Set a parameter value (raw register value, 32-bit parameters use the full width)
+    pub fn set(&mut self, addr: u16, value: u32) -> Result<()> {
+        if !registers::is_config_param(addr) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Unknown or read-only parameter: {}",
+                format_param_code(addr)
+            )));
+        }
+        if !registers::is_32bit_param(addr) && value > u16::MAX as u32 {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Value {} out of 16-bit range for {}",
+                value,
+                format_param_code(addr)
+            )));
+        }
+        self.values.insert(addr, value);
+        Ok(())
+    }
+
+    /// Get a parameter value
+    pub fn get(&self, addr: u16) -> Option<u32> {
+        self.values.get(&addr).copied()
+    }
+
+    /// Remove a parameter, returning its value
+    pub fn remove(&mut self, addr: u16) -> Option<u32> {
+        self.values.remove(&addr)
+    }
+
+    /// Iterate over (address, value) pairs in address order
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u32)> + '_ {
+        self.values.iter().map(|(&addr, &value)| (addr, value))
+    }
+
+    /// Number of parameters in the set
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl FromStr for ParameterSet {
+    type Err = DsyrsError;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let mut set = Self::new();
+        for (index, raw) in text.lines().enumerate() {
+            let line_no = index + 1;
+            let line = raw.split(['#', ';']).next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (code, value) = line.split_once(['=', ',']).ok_or_else(|| {
+                DsyrsError::InvalidParameter(format!(
+                    "Line {}: expected PXX.YY=value, got '{}'",
+                    line_no, line
+                ))
+            })?;
+            let addr = parse_param_code(code.trim()).map_err(|e| line_error(line_no, e))?;
+            let value = parse_value(addr, value.trim()).map_err(|e| line_error(line_no, e))?;
+            set.set(addr, value).map_err(|e| line_error(line_no, e))?;
+        }
+        Ok(set)
+    }
+}
+
+/// Parse a parameter code like `P04.07` into its register address
+pub fn parse_param_code(code: &str) -> Result<u16> {
+    let invalid = || DsyrsError::InvalidParameter(format!("Invalid parameter code: '{}'", code));
+
+    let rest = code
+        .strip_prefix('P')
+        .or_else(|| code.strip_prefix('p'))
+        .ok_or_else(invalid)?;
+    let (group, param) = rest.split_once('.').ok_or_else(invalid)?;
+    let group: u8 = group.parse().map_err(|_| invalid())?;
+    let param: u8 = param.parse().map_err(|_| invalid())?;
+    Ok(registers::param_addr(group, param))
+}
+
+/// Format a register address as a parameter code (e.g. `P04.07`)
+pub fn format_param_code(addr: u16) -> String {
+    format!("P{:02}.{:02}", addr >> 8, addr & 0xFF)
+}
+
+/// Parse a value for the given parameter into its raw register representation
+fn parse_value(addr: u16, text: &str) -> Result<u32> {
+    let invalid = || DsyrsError::InvalidParameter(format!("Invalid value: '{}'", text));
+
+    let value: i64 = if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16).map_err(|_| invalid())?
+    } else {
+        text.parse().map_err(|_| invalid())?
+    };
+
+    let (min, max) = if registers::is_32bit_param(addr) {
+        (i32::MIN as i64, u32::MAX as i64)
+    } else {
+        (i16::MIN as i64, u16::MAX as i64)
+    };
+    if value < min || value > max {
+        return Err(DsyrsError::InvalidParameter(format!(
+            "Value {} out of range for {}",
+            value,
+            format_param_code(addr)
+        )));
+    }
+
+    // Negative values are stored in two's complement of the register width
+    Ok(if registers::is_32bit_param(addr) {
+        value as u32
+    } else {
+        value as u16 as u32
+    })
+}
+
+fn line_error(line_no: usize, err: DsyrsError) -> DsyrsError {
+    match err {
+        DsyrsError::InvalidParameter(msg) => {
+            DsyrsError::InvalidParameter(format!("Line {}: {}", line_no, msg))
+        }
+        other => other,
+    }
+}
@@ -0,0 +1,287 @@
+//! Sync client that reopens its serial port after a transport failure
+//!
+//! A USB-RS485 adapter that drops off the bus leaves its port handle dead:
+//! every later request fails with an I/O error until the port is reopened.
+//! [`ReconnectingClient`] owns what it needs to reopen it (the port builder
+//! and slave ID, or any connector closure) and wraps a [`DsyrsSyncClient`].
+//! When an operation fails with a communication error (see
+//! [`DsyrsError::is_transient`]), the port is closed and reopened. Single
+//! register reads and writes are then retried once on the new link; other
+//! operations, such as `init()` or `enable()`, run several transactions and
+//! may have been partly applied, so their error is returned and the caller
+//! decides whether to run them again. Modbus exceptions and other errors are
+//! returned as they are.
+//!
+//! The client state (configuration, emergency stop latch, counters) is kept
+//! across reconnections. The drive's state may not be: if the adapter dropped
+//! because the drive was power cycled, every parameter written to RAM only
+//! is back to its EEPROM value. Watch [`reconnects()`](ReconnectingClient::reconnects)
+//! and call `init()` again, or store parameters with `save_to_eeprom()`.
+//!
+//! # Example
+//! ```
+//! use dsyrs::mock::MockTransport;
+//! use dsyrs::reconnect::ReconnectingClient;
+//! use dsyrs::{registers, DsyrsError, ServoConfig};
+//! use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+//! use std::sync::Arc;
+//!
+//! let mock = MockTransport::new();
+//! mock.set_register(registers::P18_SPEED_FEEDBACK, 1200);
+//! let opened = Arc::new(AtomicU32::new(0));
+//! let plugged = Arc::new(AtomicBool::new(true));
+//! let (bus, count, present) = (mock.clone(), opened.clone(), plugged.clone());
+//! let mut servo = ReconnectingClient::with_connector(ServoConfig::new(1), move |_config| {
+//!     if !present.load(Ordering::SeqCst) {
+//!         return Err(DsyrsError::SerialError("/dev/ttyUSB0: not found".into()));
+//!     }
+//!     count.fetch_add(1, Ordering::SeqCst);
+//!     Ok(bus.clone())
+//! })?;
+//! assert_eq!(opened.load(Ordering::SeqCst), 1);
+//!
+//! // The adapter drops: the request fails, the port is reopened and the read retried
+//! mock.fail_next(1);
+//! assert_eq!(servo.get_speed()?, 1200);
+//! assert_eq!(opened.load(Ordering::SeqCst), 2);
+//! assert_eq!(servo.reconnects(), 1);
+//!
+//! // Exceptions are answers from the drive and do not reconnect
+//! mock.set_exception(registers::P18_LOAD_RATE, dsyrs::ExceptionCode::IllegalDataAddress);
+//! assert!(matches!(
+//!     servo.call(|s| s.get_load_rate()),
+//!     Err(DsyrsError::ModbusException(_))
+//! ));
+//! assert_eq!(servo.reconnects(), 1);
+//!
+//! // Operations running several transactions are not repeated
+//! mock.fail_next(1);
+//! assert!(servo.init().is_err());
+//! assert_eq!(servo.reconnects(), 1);
+//! assert_eq!(servo.get_speed()?, 1200);
+//! assert_eq!(servo.reconnects(), 2);
+//!
+//! // Still unplugged when reopening: the call fails, the next one tries again
+//! plugged.store(false, Ordering::SeqCst);
+//! mock.fail_next(1);
+//! assert!(matches!(servo.get_speed(), Err(DsyrsError::SerialError(_))));
+//! plugged.store(true, Ordering::SeqCst);
+//! assert_eq!(servo.get_speed()?, 1200);
+//! assert_eq!(servo.reconnects(), 3);
+//! # Ok::<(), DsyrsError>(())
+//! ```
+
+use crate::sync::DsyrsSyncClient;
+use crate::transport::SyncModbusTransport;
+use crate::types::*;
+use tokio_modbus::prelude::*;
+
+/// Opens the Modbus context for a configuration
+type Connector<T> = Box<dyn FnMut(&ServoConfig) -> Result<T> + Send>;
+
+/// Wrapped client, with or without an open context
+enum Link<T> {
+    Connected(DsyrsSyncClient<T>),
+    /// Port closed after a failure; the state waits for the next connection
+    Disconnected(DsyrsSyncClient<()>),
+}
+
+/// [`DsyrsSyncClient`] reopening its port on transport errors, see the [module docs](self)
+pub struct ReconnectingClient<T = client::sync::Context> {
+    /// `None` only while switching between the two link states
+    link: Option<Link<T>>,
+    connect: Connector<T>,
+    reconnects: u32,
+}
+
+impl ReconnectingClient {
+    /// Open a serial port for `config.slave_id`, like [`DsyrsSyncClient::connect`]
+    ///
+    /// The port builder is kept to reopen the port, using `config.request_timeout`
+    /// as the response timeout as [`DsyrsSyncClient::connect`] does. The
+    /// client is not initialized: call `init()` before use.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::reconnect::ReconnectingClient;
+    /// use dsyrs::ServoConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = ServoConfig::new(1).with_timeout(Duration::from_millis(100));
+    /// let mut servo = ReconnectingClient::open("/dev/ttyUSB0", 115200, config)?;
+    /// servo.init()?;
+    /// loop {
+    ///     println!("{} rpm", servo.get_speed()?);
+    /// #   break;
+    /// }
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn open(port: &str, baud: u32, config: ServoConfig) -> Result<Self> {
+        let builder = tokio_serial::new(port, baud);
+        let port = port.to_string();
+        Self::with_connector(config, move |config| {
+            client::sync::rtu::connect_slave_with_timeout(
+                &builder,
+                Slave(config.slave_id),
+                config.request_timeout,
+            )
+            .map_err(|e| DsyrsError::SerialError(format!("{}: {}", port, e)))
+        })
+    }
+}
+
+impl<T: SyncModbusTransport> ReconnectingClient<T> {
+    /// Connect with `connect`, which is called again to reopen the link after a failure
+    pub fn with_connector(
+        config: ServoConfig,
+        mut connect: impl FnMut(&ServoConfig) -> Result<T> + Send + 'static,
+    ) -> Result<Self> {
+        let ctx = connect(&config)?;
+        Ok(Self {
+            link: Some(Link::Connected(DsyrsSyncClient::new(ctx, config))),
+            connect: Box::new(connect),
+            reconnects: 0,
+        })
+    }
+
+    /// Number of times the link was reopened
+    pub fn reconnects(&self) -> u32 {
+        self.reconnects
+    }
+
+    /// The wrapped client, reopening the link first if the last attempt failed
+    ///
+    /// Calls made directly on it are not retried; use [`call()`](Self::call)
+    /// for that.
+    pub fn client_mut(&mut self) -> Result<&mut DsyrsSyncClient<T>> {
+        if let Some(Link::Disconnected(_)) = self.link {
+            self.reconnect()?;
+        }
+        match &mut self.link {
+            Some(Link::Connected(client)) => Ok(client),
+            _ => Err(DsyrsError::OperationFailed(
+                "Reconnecting client has no link".into(),
+            )),
+        }
+    }
+
+    /// Run `op` on the client, reopening the link on a communication error
+    ///
+    /// The operation is not repeated, as it may have been partly applied: the
+    /// error is returned and the next call runs on the new link. Use
+    /// [`call_retrying()`](Self::call_retrying) for operations safe to repeat.
+    pub fn call<R>(&mut self, op: impl FnOnce(&mut DsyrsSyncClient<T>) -> Result<R>) -> Result<R> {
+        let result = op(self.client_mut()?);
+        if let Err(e) = &result {
+            if e.is_transient() {
+                log_warn!("Communication error ({}), reopening the link", e);
+                self.disconnect();
+            }
+        }
+        result
+    }
+
+    /// Like [`call()`](Self::call), then run `op` once more on the reopened link
+    ///
+    /// Only for operations that can safely run twice, such as a single
+    /// register read or write: a write may have reached the drive before the
+    /// link failed.
+    pub fn call_retrying<R>(
+        &mut self,
+        mut op: impl FnMut(&mut DsyrsSyncClient<T>) -> Result<R>,
+    ) -> Result<R> {
+        // Connect first, so a failure below comes from `op`
+        self.client_mut()?;
+        match self.call(&mut op) {
+            Err(e) if e.is_transient() => op(self.client_mut()?),
+            result => result,
+        }
+    }
+
+    /// Close the link, keeping the client state for the next connection
+    fn disconnect(&mut self) {
+        self.link = match self.link.take() {
+            Some(Link::Connected(client)) => Some(Link::Disconnected(client.with_context(()))),
+            other => other,
+        };
+    }
+
+    /// Open a new link for the parked client state
+    fn reconnect(&mut self) -> Result<()> {
+        let Some(Link::Disconnected(parked)) = self.link.take() else {
+            return Ok(());
+        };
+        match (self.connect)(parked.config()) {
+            Ok(mut ctx) => {
+                ctx.set_slave(Slave(parked.config().slave_id));
+                self.link = Some(Link::Connected(parked.with_context(ctx)));
+                self.reconnects += 1;
+                Ok(())
+            }
+            Err(e) => {
+                self.link = Some(Link::Disconnected(parked));
+                Err(e)
+            }
+        }
+    }
+
+    /// Initialize the servo drive, see [`DsyrsSyncClient::init`]
+    pub fn init(&mut self) -> Result<()> {
+        self.call(|client| client.init())
+    }
+
+    /// Enable the servo, see [`DsyrsSyncClient::enable`]
+    pub fn enable(&mut self) -> Result<()> {
+        self.call(|client| client.enable())
+    }
+
+    /// Disable the servo, see [`DsyrsSyncClient::disable`]
+    pub fn disable(&mut self) -> Result<()> {
+        self.call(|client| client.disable())
+    }
+
+    /// Emergency stop, see [`DsyrsSyncClient::emergency_stop`]
+    pub fn emergency_stop(&mut self) -> Result<()> {
+        self.call(|client| client.emergency_stop())
+    }
+
+    /// Reset the current fault, see [`DsyrsSyncClient::reset_fault`]
+    pub fn reset_fault(&mut self) -> Result<()> {
+        self.call(|client| client.reset_fault())
+    }
+
+    /// Set speed command (P05.03), see [`DsyrsSyncClient::set_speed_command`]
+    pub fn set_speed_command(&mut self, rpm: i16) -> Result<()> {
+        self.call_retrying(|client| client.set_speed_command(rpm))
+    }
+
+    /// Get motor speed feedback (P18.01, rpm)
+    pub fn get_speed(&mut self) -> Result<i16> {
+        self.call_retrying(|client| client.get_speed())
+    }
+
+    /// Get absolute position (P18.07-P18.08)
+    pub fn get_position(&mut self) -> Result<i32> {
+        self.call_retrying(|client| client.get_position())
+    }
+
+    /// Get the servo state (P18.00)
+    pub fn get_servo_state(&mut self) -> Result<ServoState> {
+        self.call_retrying(|client| client.get_servo_state())
+    }
+
+    /// Get the complete servo status in a single transaction (P18.00-P18.09)
+    pub fn get_status_fast(&mut self) -> Result<ServoStatus> {
+        self.call_retrying(|client| client.get_status_fast())
+    }
+
+    /// Read holding registers, see [`DsyrsSyncClient::read_registers`]
+    pub fn read_registers(&mut self, addr: u16, count: u16) -> Result<Vec<u16>> {
+        self.call_retrying(|client| client.read_registers(addr, count))
+    }
+
+    /// Write a single holding register, see [`DsyrsSyncClient::write_register`]
+    pub fn write_register(&mut self, addr: u16, value: u16) -> Result<()> {
+        self.call_retrying(|client| client.write_register(addr, value))
+    }
+}
@@ -9,6 +9,9 @@
 //! # Data Format
 //! For U16 sending: [value & 0x00ff, (value & 0xff00) >> 8]
 
+use crate::params::format_param_code;
+use crate::types::{DsyrsError, Result};
+
 /// Calculate register address from parameter code (PXX.YY)
 pub const fn param_addr(group: u8, param: u8) -> u16 {
     (group as u16) * 256 + (param as u16)
@@ -367,7 +370,7 @@ pub const P06_TORQUE_CMD_SOURCE: u16 = param_addr(6, 0);
 /// - 3 = A/B switch
 pub const P06_TORQUE_CMD_SELECT: u16 = param_addr(6, 2);
 
-/// P06.04: Torque command filter time (-3000 to 3000, unit: 0.01 ms)
+/// P06.04: Torque command filter time (0-65535, unit: 0.01 ms)
 pub const P06_TORQUE_FILTER: u16 = param_addr(6, 4);
 
 /// P06.05: Torque command keyboard setting (-3000 to 3000, unit: 0.1% of rated)
@@ -846,7 +849,7 @@ pub const P14_SEG16_ACCEL_SELECT: u16 = param_addr(14, 54);
 /// P16.00: Fixed length interrupt enable (0-1)
 pub const P16_FIXED_LENGTH_ENABLE: u16 = param_addr(16, 0);
 
-/// P16.01: Fixed length 1 displacement (0-2^30 unit)
+/// P16.01: Fixed length 1 displacement (0-(2^30 - 1) unit)
 pub const P16_FIXED_LENGTH1_DISP: u16 = param_addr(16, 1);
 
 /// P16.03: Fixed length 1 speed (0-9000 rpm)
@@ -891,7 +894,7 @@ pub const P16_ENCODER_TURNS: u16 = param_addr(16, 30);
 /// P16.31: Zero wait count (0-65535 ms)
 pub const P16_ZERO_WAIT_COUNT: u16 = param_addr(16, 31);
 
-/// P16.37: Fixed length 2 displacement (±2^30 unit)
+/// P16.37: Fixed length 2 displacement (±(2^30 - 1) unit)
 pub const P16_FIXED_LENGTH2_DISP: u16 = param_addr(16, 37);
 
 /// P16.39: Fixed length 2 speed (0-9000 rpm)
@@ -900,6 +903,14 @@ pub const P16_FIXED_LENGTH2_SPEED: u16 = param_addr(16, 39);
 // ============================================================================
 // P18 – Display Parameters (Read-Only Status Registers)
 // ============================================================================
+//
+// Only P18.00-P18.09 are documented for the DSY-RS. There is no input pulse
+// counter or pulse frequency monitor in this range, so received command pulses
+// cannot be read back over Modbus; check them with the drive's front panel or
+// an external counter when debugging a pulse-commanded axis.
+//
+// Likewise there are no power-on time or motor run-time counters: maintenance
+// scheduling by operating hours has to be tracked on the host side.
 
 /// P18.00: Servo status (Ready/Run/Err/AL)
 pub const P18_SERVO_STATUS: u16 = param_addr(18, 0);
@@ -1024,6 +1035,96 @@ pub const fn get_segment_wait_time_register(segment: u8) -> Option<u16> {
     }
 }
 
+/// Get the multi-speed segment speed register for a given segment (1-16)
+pub const fn get_speed_segment_speed_register(segment: u8) -> Option<u16> {
+    match segment {
+        1 => Some(P14_SEG1_SPEED),
+        2 => Some(P14_SEG2_SPEED),
+        3 => Some(P14_SEG3_SPEED),
+        4 => Some(P14_SEG4_SPEED),
+        5 => Some(P14_SEG5_SPEED),
+        6 => Some(P14_SEG6_SPEED),
+        7 => Some(P14_SEG7_SPEED),
+        8 => Some(P14_SEG8_SPEED),
+        9 => Some(P14_SEG9_SPEED),
+        10 => Some(P14_SEG10_SPEED),
+        11 => Some(P14_SEG11_SPEED),
+        12 => Some(P14_SEG12_SPEED),
+        13 => Some(P14_SEG13_SPEED),
+        14 => Some(P14_SEG14_SPEED),
+        15 => Some(P14_SEG15_SPEED),
+        16 => Some(P14_SEG16_SPEED),
+        _ => None,
+    }
+}
+
+/// Get the multi-speed segment run time register for a given segment (1-16)
+pub const fn get_speed_segment_time_register(segment: u8) -> Option<u16> {
+    match segment {
+        1 => Some(P14_SEG1_TIME),
+        2 => Some(P14_SEG2_TIME),
+        3 => Some(P14_SEG3_TIME),
+        4 => Some(P14_SEG4_TIME),
+        5 => Some(P14_SEG5_TIME),
+        6 => Some(P14_SEG6_TIME),
+        7 => Some(P14_SEG7_TIME),
+        8 => Some(P14_SEG8_TIME),
+        9 => Some(P14_SEG9_TIME),
+        10 => Some(P14_SEG10_TIME),
+        11 => Some(P14_SEG11_TIME),
+        12 => Some(P14_SEG12_TIME),
+        13 => Some(P14_SEG13_TIME),
+        14 => Some(P14_SEG14_TIME),
+        15 => Some(P14_SEG15_TIME),
+        16 => Some(P14_SEG16_TIME),
+        _ => None,
+    }
+}
+
+/// Get the multi-speed segment accel/decel selection register for a given segment (1-16)
+pub const fn get_speed_segment_accel_select_register(segment: u8) -> Option<u16> {
+    match segment {
+        1 => Some(P14_SEG1_ACCEL_SELECT),
+        2 => Some(P14_SEG2_ACCEL_SELECT),
+        3 => Some(P14_SEG3_ACCEL_SELECT),
+        4 => Some(P14_SEG4_ACCEL_SELECT),
+        5 => Some(P14_SEG5_ACCEL_SELECT),
+        6 => Some(P14_SEG6_ACCEL_SELECT),
+        7 => Some(P14_SEG7_ACCEL_SELECT),
+        8 => Some(P14_SEG8_ACCEL_SELECT),
+        9 => Some(P14_SEG9_ACCEL_SELECT),
+        10 => Some(P14_SEG10_ACCEL_SELECT),
+        11 => Some(P14_SEG11_ACCEL_SELECT),
+        12 => Some(P14_SEG12_ACCEL_SELECT),
+        13 => Some(P14_SEG13_ACCEL_SELECT),
+        14 => Some(P14_SEG14_ACCEL_SELECT),
+        15 => Some(P14_SEG15_ACCEL_SELECT),
+        16 => Some(P14_SEG16_ACCEL_SELECT),
+        _ => None,
+    }
+}
+
+/// Get the multi-speed accel/decel time register for a given index (1-4)
+pub const fn get_multi_speed_accel_time_register(index: u8) -> Option<u16> {
+    match index {
+        1 => Some(P14_ACCEL_DECEL_TIME1),
+        2 => Some(P14_ACCEL_DECEL_TIME2),
+        3 => Some(P14_ACCEL_DECEL_TIME3),
+        4 => Some(P14_ACCEL_DECEL_TIME4),
+        _ => None,
+    }
+}
+
+/// Get the notch filter frequency register for a given notch (1)
+///
+/// Width and depth follow at +1 and +2. Only the 1st notch filter is documented.
+pub const fn get_notch_filter_register(index: u8) -> Option<u16> {
+    match index {
+        1 => Some(P08_NOTCH1_FREQUENCY),
+        _ => None,
+    }
+}
+
 /// Get the DI function register for a given input (1-3)
 pub const fn get_di_function_register(input: u8) -> Option<u16> {
     match input {
@@ -1061,3 +1162,735 @@ pub const fn get_do_logic_register(output: u8) -> Option<u16> {
         _ => None,
     }
 }
+
+// ============================================================================
+// Parameter Tables
+// ============================================================================
+
+/// Parameters stored as 32-bit values over two consecutive registers (see `WordOrder`)
+pub const PARAMS_32BIT: &[u16] = &[
+    P01_ENCODER_RESOLUTION,
+    P04_UNITS_PER_REV,
+    P04_GEAR1_NUMERATOR,
+    P04_GEAR1_DENOMINATOR,
+    P04_GEAR2_NUMERATOR,
+    P04_GEAR2_DENOMINATOR,
+    P09_POSITION_DEVIATION_THRESHOLD,
+    P13_SEG1_DISPLACEMENT,
+    P13_SEG2_DISPLACEMENT,
+    P13_SEG3_DISPLACEMENT,
+    P13_SEG4_DISPLACEMENT,
+    P13_SEG5_DISPLACEMENT,
+    P13_SEG6_DISPLACEMENT,
+    P13_SEG7_DISPLACEMENT,
+    P13_SEG8_DISPLACEMENT,
+    P13_SEG9_DISPLACEMENT,
+    P13_SEG10_DISPLACEMENT,
+    P13_SEG11_DISPLACEMENT,
+    P13_SEG12_DISPLACEMENT,
+    P13_SEG13_DISPLACEMENT,
+    P13_SEG14_DISPLACEMENT,
+    P13_SEG15_DISPLACEMENT,
+    P13_SEG16_DISPLACEMENT,
+    P16_FIXED_LENGTH1_DISP,
+    P16_HOME_OFFSET,
+    P16_ENCODER_ORIGIN,
+    P16_FIXED_LENGTH2_DISP,
+    P18_ABSOLUTE_POSITION,
+];
+
+/// Persistent configuration parameters documented in the manual
+///
+/// Excludes read-only groups (P01 motor data, P12 version codes, P18 display)
+/// and P11 / P10.04 command registers, which trigger actions rather than hold settings.
+pub const CONFIG_PARAMS: &[u16] = &[
+    P00_CONTROL_MODE,
+    P00_DIRECTION,
+    P00_PULSE_DIRECTION,
+    P00_RIGIDITY,
+    P00_INERTIA_RATIO,
+    P00_ABSOLUTE_SYSTEM,
+    P00_MAX_SPEED,
+    P00_SERVO_OFF_STOP_MODE,
+    P00_FAULT1_STOP_MODE,
+    P00_FAULT2_STOP_MODE,
+    P00_OVERTRAVEL_STOP_MODE,
+    P00_BRAKE_ON_DELAY,
+    P00_BRAKE_OFF_DELAY,
+    P00_BRAKE_SPEED_THRESHOLD,
+    P00_FAULT_BRAKE_DELAY,
+    P00_ENERGY_RESISTOR,
+    P00_EXT_RESISTOR_POWER,
+    P00_EXT_RESISTANCE,
+    P00_EXT_RESISTANCE_TIME,
+    P00_BRAKE_VOLTAGE,
+    P00_PULSE_INCREMENT_THRESHOLD,
+    P00_PULSELESS_CYCLE,
+    P02_FUNINL_STATE,
+    P02_DI1_FUNCTION,
+    P02_DI2_FUNCTION,
+    P02_DI3_FUNCTION,
+    P02_FUNINH_STATE,
+    P02_DI1_LOGIC,
+    P02_DI2_LOGIC,
+    P02_DI3_LOGIC,
+    P02_DO1_FUNCTION,
+    P02_DO2_FUNCTION,
+    P02_DO1_LOGIC,
+    P02_DO2_LOGIC,
+    P04_POSITION_CMD_SOURCE,
+    P04_STEP_AMOUNT,
+    P04_POSITION_FILTER,
+    P04_POSITION_FIR_FILTER,
+    P04_UNITS_PER_REV,
+    P04_GEAR1_NUMERATOR,
+    P04_GEAR1_DENOMINATOR,
+    P04_GEAR2_NUMERATOR,
+    P04_GEAR2_DENOMINATOR,
+    P04_PULSE_SHAPE,
+    P04_DEVIATION_CLEAR,
+    P04_COIN_CONDITION,
+    P04_POSITIONING_RANGE,
+    P04_POSITIONING_CLOSE_RANGE,
+    P05_SPEED_CMD_SOURCE,
+    P05_AUX_SPEED_SOURCE,
+    P05_SPEED_CMD_SELECT,
+    P05_SPEED_COMMAND,
+    P05_JOG_SPEED,
+    P05_ACCEL_TIME,
+    P05_DECEL_TIME,
+    P05_SPEED_LIMIT_SELECT,
+    P05_FORWARD_SPEED_LIMIT,
+    P05_BACKWARD_SPEED_LIMIT,
+    P05_SPEED_DIRECTION,
+    P05_ZERO_SPEED_VALUE,
+    P05_RUNNING_THRESHOLD,
+    P05_SPEED_UNIFORM_WIDTH,
+    P05_SPEED_REACHED_VALUE,
+    P05_ZERO_SPEED_THRESHOLD,
+    P06_TORQUE_CMD_SOURCE,
+    P06_TORQUE_CMD_SELECT,
+    P06_TORQUE_FILTER,
+    P06_TORQUE_COMMAND,
+    P06_TORQUE_LIMIT_SOURCE,
+    P06_FORWARD_TORQUE_LIMIT,
+    P06_BACKWARD_TORQUE_LIMIT,
+    P06_FORWARD_EXT_TORQUE_LIMIT,
+    P06_BACKWARD_EXT_TORQUE_LIMIT,
+    P06_SPEED_LIMIT_SOURCE,
+    P06_POSITIVE_SPEED_LIMIT,
+    P06_NEGATIVE_SPEED_LIMIT,
+    P06_TORQUE_SEGMENT1,
+    P06_TORQUE_SEGMENT2,
+    P06_TORQUE_SEGMENT3,
+    P07_POSITION_GAIN1,
+    P07_SPEED_GAIN1,
+    P07_SPEED_INTEGRAL1,
+    P07_SPEED_FILTER1,
+    P07_POSITION_GAIN2,
+    P07_SPEED_GAIN2,
+    P07_GAINSWITCH_ACTION,
+    P07_GAIN_SWITCH_MODE,
+    P08_ADAPTIVE_FILTER_MODE,
+    P08_NOTCH1_FREQUENCY,
+    P08_NOTCH1_WIDTH,
+    P08_NOTCH1_DEPTH,
+    P08_DAMPING_FILTER,
+    P08_DAMPING_FILTER_SELECT,
+    P08_INERTIA_ID_MODE,
+    P08_HF_VIBRATION_SUPPRESS,
+    P08_ANTI_DISTURBANCE,
+    P08_SPEED_COMPENSATION,
+    P08_MODEL_COMPENSATION,
+    P09_UNDERVOLTAGE_DELAY,
+    P09_RUNAWAY_PROTECTION,
+    P09_OVERLOAD_WARNING,
+    P09_MOTOR_OVERLOAD_FACTOR,
+    P09_UNDERVOLTAGE_POINT,
+    P09_OVERSPEED_POINT,
+    P09_POSITION_DEVIATION_THRESHOLD,
+    P09_LOCKED_ROTOR_TEMP,
+    P09_OVERLOAD_PROTECTION,
+    P10_COMM_ADDRESS,
+    P10_MODBUS_BAUDRATE,
+    P10_MODBUS_FORMAT,
+    P10_RS232_BAUDRATE,
+    P10_RS485_ADDRESS_SOURCE,
+    P12_LED_WARNING,
+    P12_DEFAULT_DISPLAY,
+    P12_SPEED_DISPLAY_FILTER,
+    P13_OPERATION_MODE,
+    P13_START_SEGMENT,
+    P13_END_SEGMENT,
+    P13_INTERRUPT_HANDLING,
+    P13_WAIT_TIME_UNIT,
+    P13_POSITION_MODE,
+    P13_SEG1_DISPLACEMENT,
+    P13_SEG1_SPEED,
+    P13_SEG1_ACCEL_DECEL,
+    P13_SEG1_WAIT_TIME,
+    P13_SEG2_DISPLACEMENT,
+    P13_SEG2_SPEED,
+    P13_SEG2_ACCEL_DECEL,
+    P13_SEG2_WAIT_TIME,
+    P13_SEG3_DISPLACEMENT,
+    P13_SEG3_SPEED,
+    P13_SEG3_ACCEL_DECEL,
+    P13_SEG3_WAIT_TIME,
+    P13_SEG4_DISPLACEMENT,
+    P13_SEG4_SPEED,
+    P13_SEG4_ACCEL_DECEL,
+    P13_SEG4_WAIT_TIME,
+    P13_SEG5_DISPLACEMENT,
+    P13_SEG5_SPEED,
+    P13_SEG5_ACCEL_DECEL,
+    P13_SEG5_WAIT_TIME,
+    P13_SEG6_DISPLACEMENT,
+    P13_SEG6_SPEED,
+    P13_SEG6_ACCEL_DECEL,
+    P13_SEG6_WAIT_TIME,
+    P13_SEG7_DISPLACEMENT,
+    P13_SEG7_SPEED,
+    P13_SEG7_ACCEL_DECEL,
+    P13_SEG7_WAIT_TIME,
+    P13_SEG8_DISPLACEMENT,
+    P13_SEG8_SPEED,
+    P13_SEG8_ACCEL_DECEL,
+    P13_SEG8_WAIT_TIME,
+    P13_SEG9_DISPLACEMENT,
+    P13_SEG9_SPEED,
+    P13_SEG9_ACCEL_DECEL,
+    P13_SEG9_WAIT_TIME,
+    P13_SEG10_DISPLACEMENT,
+    P13_SEG10_SPEED,
+    P13_SEG10_ACCEL_DECEL,
+    P13_SEG10_WAIT_TIME,
+    P13_SEG11_DISPLACEMENT,
+    P13_SEG11_SPEED,
+    P13_SEG11_ACCEL_DECEL,
+    P13_SEG11_WAIT_TIME,
+    P13_SEG12_DISPLACEMENT,
+    P13_SEG12_SPEED,
+    P13_SEG12_ACCEL_DECEL,
+    P13_SEG12_WAIT_TIME,
+    P13_SEG13_DISPLACEMENT,
+    P13_SEG13_SPEED,
+    P13_SEG13_ACCEL_DECEL,
+    P13_SEG13_WAIT_TIME,
+    P13_SEG14_DISPLACEMENT,
+    P13_SEG14_SPEED,
+    P13_SEG14_ACCEL_DECEL,
+    P13_SEG14_WAIT_TIME,
+    P13_SEG15_DISPLACEMENT,
+    P13_SEG15_SPEED,
+    P13_SEG15_ACCEL_DECEL,
+    P13_SEG15_WAIT_TIME,
+    P13_SEG16_DISPLACEMENT,
+    P13_SEG16_SPEED,
+    P13_SEG16_ACCEL_DECEL,
+    P13_SEG16_WAIT_TIME,
+    P14_OPERATION_MODE,
+    P14_END_SEGMENT,
+    P14_TIME_UNIT,
+    P14_ACCEL_DECEL_TIME1,
+    P14_ACCEL_DECEL_TIME2,
+    P14_ACCEL_DECEL_TIME3,
+    P14_ACCEL_DECEL_TIME4,
+    P14_SEG1_SPEED,
+    P14_SEG1_TIME,
+    P14_SEG1_ACCEL_SELECT,
+    P14_SEG2_SPEED,
+    P14_SEG2_TIME,
+    P14_SEG2_ACCEL_SELECT,
+    P14_SEG3_SPEED,
+    P14_SEG3_TIME,
+    P14_SEG3_ACCEL_SELECT,
+    P14_SEG4_SPEED,
+    P14_SEG4_TIME,
+    P14_SEG4_ACCEL_SELECT,
+    P14_SEG5_SPEED,
+    P14_SEG5_TIME,
+    P14_SEG5_ACCEL_SELECT,
+    P14_SEG6_SPEED,
+    P14_SEG6_TIME,
+    P14_SEG6_ACCEL_SELECT,
+    P14_SEG7_SPEED,
+    P14_SEG7_TIME,
+    P14_SEG7_ACCEL_SELECT,
+    P14_SEG8_SPEED,
+    P14_SEG8_TIME,
+    P14_SEG8_ACCEL_SELECT,
+    P14_SEG9_SPEED,
+    P14_SEG9_TIME,
+    P14_SEG9_ACCEL_SELECT,
+    P14_SEG10_SPEED,
+    P14_SEG10_TIME,
+    P14_SEG10_ACCEL_SELECT,
+    P14_SEG11_SPEED,
+    P14_SEG11_TIME,
+    P14_SEG11_ACCEL_SELECT,
+    P14_SEG12_SPEED,
+    P14_SEG12_TIME,
+    P14_SEG12_ACCEL_SELECT,
+    P14_SEG13_SPEED,
+    P14_SEG13_TIME,
+    P14_SEG13_ACCEL_SELECT,
+    P14_SEG14_SPEED,
+    P14_SEG14_TIME,
+    P14_SEG14_ACCEL_SELECT,
+    P14_SEG15_SPEED,
+    P14_SEG15_TIME,
+    P14_SEG15_ACCEL_SELECT,
+    P14_SEG16_SPEED,
+    P14_SEG16_TIME,
+    P14_SEG16_ACCEL_SELECT,
+    P16_FIXED_LENGTH_ENABLE,
+    P16_FIXED_LENGTH1_DISP,
+    P16_FIXED_LENGTH1_SPEED,
+    P16_FIXED_LENGTH_ACCEL,
+    P16_FIXED_LENGTH_DECEL,
+    P16_LOCK_RELEASE_ENABLE,
+    P16_HOMING_ENABLE_MODE,
+    P16_HOMING_MODE,
+    P16_HOMING_HIGH_SPEED,
+    P16_HOMING_LOW_SPEED,
+    P16_HOMING_ACCEL,
+    P16_HOMING_TIMEOUT,
+    P16_HOME_OFFSET,
+    P16_ENCODER_ORIGIN,
+    P16_ENCODER_TURNS,
+    P16_ZERO_WAIT_COUNT,
+    P16_FIXED_LENGTH2_DISP,
+    P16_FIXED_LENGTH2_SPEED,
+];
+
+/// Registers read by `dump_parameters()` and written by `restore_parameters()`
+///
+/// The documented persistent parameters ([`CONFIG_PARAMS`]), so read-only P01,
+/// P12 and P18 monitors are never written back. 32-bit entries also cover their
+/// low word at address + 1.
+pub const WRITABLE_REGISTERS: &[u16] = CONFIG_PARAMS;
+
+/// Absolute encoder origin (P16.28-P16.30), specific to one motor's encoder
+///
+/// `restore_parameters()` skips these: copied onto another motor they would
+/// shift its absolute zero.
+pub const ENCODER_ORIGIN_PARAMS: &[u16] = &[P16_ENCODER_ORIGIN, P16_ENCODER_TURNS];
+
+/// RS485 link parameters: changing one makes the drive stop answering the client
+///
+/// Address (P10.00), baud rate (P10.02), data format (P10.03) and address
+/// source (P10.06). `apply_parameter_set()` refuses them; use
+/// `set_comm_address()` or `change_baud_rate()` instead.
+pub const LINK_PARAMS: &[u16] = &[
+    P10_COMM_ADDRESS,
+    P10_MODBUS_BAUDRATE,
+    P10_MODBUS_FORMAT,
+    P10_RS485_ADDRESS_SOURCE,
+];
+
+/// Check if a parameter is stored as a 32-bit value
+pub fn is_32bit_param(addr: u16) -> bool {
+    PARAMS_32BIT.contains(&addr)
+}
+
+/// Check if a parameter is a documented persistent configuration parameter
+pub fn is_config_param(addr: u16) -> bool {
+    CONFIG_PARAMS.contains(&addr)
+}
+
+// ============================================================================
+// Parameter Descriptors
+// ============================================================================
+
+/// Register width of a parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamWidth {
+    /// One 16-bit register
+    Word,
+    /// 32-bit value over two consecutive registers (see `WordOrder`)
+    DoubleWord,
+}
+
+/// Documented range and unit of a parameter, for generic tooling
+///
+/// `min` and `max` are raw register values (signed for signed parameters);
+/// the physical value is `raw × scale` in `unit`. Ranges come from the manual.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamInfo {
+    /// Register address
+    pub addr: u16,
+    /// Parameter name
+    pub name: &'static str,
+    /// Minimum raw value
+    pub min: i64,
+    /// Maximum raw value
+    pub max: i64,
+    /// Physical value per raw count
+    pub scale: f32,
+    /// Physical unit (empty for counts, codes and selections)
+    pub unit: &'static str,
+    /// False for monitors the host can only read (P18)
+    pub writable: bool,
+    /// Register width
+    pub width: ParamWidth,
+}
+
+impl ParamInfo {
+    /// Check if a raw value is within the documented range
+    pub fn contains(&self, value: i64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// Descriptor of a parameter; the width follows [`PARAMS_32BIT`], P18 is read-only
+const fn param(
+    addr: u16,
+    name: &'static str,
+    min: i64,
+    max: i64,
+    scale: f32,
+    unit: &'static str,
+) -> ParamInfo {
+    let mut width = ParamWidth::Word;
+    let mut i = 0;
+    while i < PARAMS_32BIT.len() {
+        if PARAMS_32BIT[i] == addr {
+            width = ParamWidth::DoubleWord;
+        }
+        i += 1;
+    }
+    ParamInfo {
+        addr,
+        name,
+        min,
+        max,
+        scale,
+        unit,
+        writable: addr >> 8 != 18,
+        width,
+    }
+}
+
+/// Parameters with a documented range, sorted by address
+///
+/// Multi-segment position and speed entries (P13.08+, P14.07+) have no
+/// documented range and are not listed.
+///
+/// # Example
+/// ```
+/// use dsyrs::registers::PARAM_TABLE;
+///
+/// assert!(PARAM_TABLE.windows(2).all(|w| w[0].addr < w[1].addr));
+/// assert!(PARAM_TABLE.iter().all(|info| info.min <= info.max));
+/// ```
+#[rustfmt::skip]
+pub const PARAM_TABLE: &[ParamInfo] = &[
+    param(P00_CONTROL_MODE, "Control mode selection", 0, 2, 1.0, ""),
+    param(P00_DIRECTION, "Direction of rotation", 0, 1, 1.0, ""),
+    param(P00_PULSE_DIRECTION, "Pulse output forward-direction definition", 0, 1, 1.0, ""),
+    param(P00_RIGIDITY, "Rigidity level setting", 0, 31, 1.0, ""),
+    param(P00_INERTIA_RATIO, "Inertia ratio", 0, 3000, 0.01, ""),
+    param(P00_ABSOLUTE_SYSTEM, "Absolute value system selection", 0, 2, 1.0, ""),
+    param(P00_MAX_SPEED, "System maximum speed", 0, 10000, 1.0, "rpm"),
+    param(P00_SERVO_OFF_STOP_MODE, "Servo OFF stop mode", 0, 1, 1.0, ""),
+    param(P00_FAULT1_STOP_MODE, "Fault No.1 stop mode selection", 0, 1, 1.0, ""),
+    param(P00_FAULT2_STOP_MODE, "Fault No.2 stop mode selection", 0, 1, 1.0, ""),
+    param(P00_OVERTRAVEL_STOP_MODE, "Stop mode when overtravel", 0, 2, 1.0, ""),
+    param(P00_BRAKE_ON_DELAY, "Brake output ON delay after command", 0, 10000, 1.0, "ms"),
+    param(P00_BRAKE_OFF_DELAY, "Brake output OFF delay", 10, 10000, 1.0, "ms"),
+    param(P00_BRAKE_SPEED_THRESHOLD, "Speed threshold for brake output OFF", 0, 1000, 1.0, "rpm"),
+    param(P00_FAULT_BRAKE_DELAY, "No.1 fault in running: delay between servo OFF and brake OFF", 0, 10000, 1.0, "ms"),
+    param(P00_ENERGY_RESISTOR, "Energy consumption resistor setting", 0, 3, 1.0, ""),
+    param(P00_EXT_RESISTOR_POWER, "External resistor power capacity", 1, 65535, 1.0, "W"),
+    param(P00_EXT_RESISTANCE, "External resistance value", 1, 1000, 1.0, "Ω"),
+    param(P00_EXT_RESISTANCE_TIME, "External resistance heating time constant", 1000, 65535, 1.0, "ms"),
+    param(P00_BRAKE_VOLTAGE, "Braking start voltage", 0, 1000, 1.0, "V"),
+    param(P00_PULSE_INCREMENT_THRESHOLD, "Pulse increment threshold", 0, 200, 1.0, ""),
+    param(P00_PULSELESS_CYCLE, "Continuous pulseless reception cycle number", 1, 200, 1.0, ""),
+    param(P01_MOTOR_MODEL, "Motor model code", 0, 65535, 1.0, ""),
+    param(P01_PHASE_SEQUENCE, "Motor power line phase sequence direction", 0, 1, 1.0, ""),
+    param(P01_RATED_VOLTAGE, "Rated voltage", 1, 1000, 1.0, "V"),
+    param(P01_RATED_POWER, "Rated power", 0, 65535, 0.01, "kW"),
+    param(P01_RATED_CURRENT, "Rated current", 1, 10000, 0.01, "A"),
+    param(P01_RATED_TORQUE, "Rated torque", 0, 65535, 0.01, "Nm"),
+    param(P01_MAX_SPEED, "Max speed", 0, 9000, 1.0, "rpm"),
+    param(P01_ROTOR_INERTIA, "Rotor inertia", 0, 10000, 0.01, "kg·cm²"),
+    param(P01_POLE_PAIRS, "Pole pairs PMSM", 1, 50, 1.0, ""),
+    param(P01_STATOR_RESISTANCE, "Stator resistance Rs", 1, 65535, 0.001, "Ω"),
+    param(P01_Q_INDUCTANCE, "Q-axis inductance Lq", 1, 65535, 0.01, "mH"),
+    param(P01_D_INDUCTANCE, "D-axis inductance Ld", 1, 65535, 0.01, "mH"),
+    param(P01_BACK_EMF, "Back EMF", 1, 65535, 0.01, "mV/rpm"),
+    param(P01_TORQUE_FACTOR, "Torque factor", 1, 65535, 0.001, "Nm/A"),
+    param(P01_ENCODER_SELECTION, "Encoder selection", 0, 4, 1.0, ""),
+    param(P01_ENCODER_RESOLUTION, "Encoder resolution", 1, 1 << 30, 1.0, ""),
+    param(P01_Z_ELECTRICAL_ANGLE, "Z electrical angle", 0, 3600, 0.1, "°"),
+    param(P01_U_ELECTRICAL_ANGLE, "U rising-edge electrical angle", 0, 3600, 0.1, "°"),
+    param(P02_FUNINL_STATE, "FunINL unassigned state (HEX)", 0, 65535, 1.0, ""),
+    param(P02_DI1_FUNCTION, "DI1 terminal function selection", 0, 45, 1.0, ""),
+    param(P02_DI2_FUNCTION, "DI2 terminal function selection", 0, 45, 1.0, ""),
+    param(P02_DI3_FUNCTION, "DI3 terminal function selection", 0, 45, 1.0, ""),
+    param(P02_FUNINH_STATE, "FunINH unassigned state (HEX)", 0, 65535, 1.0, ""),
+    param(P02_DI1_LOGIC, "DI1 terminal logic selection", 0, 4, 1.0, ""),
+    param(P02_DI2_LOGIC, "DI2 terminal logic selection", 0, 4, 1.0, ""),
+    param(P02_DI3_LOGIC, "DI3 terminal logic selection", 0, 4, 1.0, ""),
+    param(P02_DO1_FUNCTION, "DO1 terminal function selection", 0, 25, 1.0, ""),
+    param(P02_DO2_FUNCTION, "DO2 terminal function selection", 11, 11, 1.0, ""),
+    param(P02_DO1_LOGIC, "DO1 terminal logic", 0, 1, 1.0, ""),
+    param(P02_DO2_LOGIC, "DO2 terminal logic", 0, 1, 1.0, ""),
+    param(P04_POSITION_CMD_SOURCE, "Main position command A source", 0, 5, 1.0, ""),
+    param(P04_STEP_AMOUNT, "Step amount", -9999, 9999, 1.0, ""),
+    param(P04_POSITION_FILTER, "Position command smoothing filter", 0, 65535, 0.1, "ms"),
+    param(P04_POSITION_FIR_FILTER, "Position command FIR filter", 0, 1280, 0.1, "ms"),
+    param(P04_UNITS_PER_REV, "Units required for one revolution", 16, 1 << 30, 1.0, "unit/turn"),
+    param(P04_GEAR1_NUMERATOR, "Electronic gear 1 numerator", 1, 1 << 30, 1.0, ""),
+    param(P04_GEAR1_DENOMINATOR, "Electronic gear 1 denominator", 1, 1 << 30, 1.0, ""),
+    param(P04_GEAR2_NUMERATOR, "Electronic gear 2 numerator", 1, 1 << 30, 1.0, ""),
+    param(P04_GEAR2_DENOMINATOR, "Electronic gear 2 denominator", 1, 1 << 30, 1.0, ""),
+    param(P04_PULSE_SHAPE, "Pulse shape", 0, 5, 1.0, ""),
+    param(P04_DEVIATION_CLEAR, "Position deviation clear", 0, 2, 1.0, ""),
+    param(P04_COIN_CONDITION, "COIN output condition", 0, 2, 1.0, ""),
+    param(P04_POSITIONING_RANGE, "Positioning completion range", 1, 65535, 1.0, "pulse"),
+    param(P04_POSITIONING_CLOSE_RANGE, "Positioning close range", 1, 65535, 1.0, "pulse"),
+    param(P05_SPEED_CMD_SOURCE, "Main speed command A source", 0, 2, 1.0, ""),
+    param(P05_AUX_SPEED_SOURCE, "Auxiliary speed command B source", 0, 3, 1.0, ""),
+    param(P05_SPEED_CMD_SELECT, "Speed command selection", 0, 3, 1.0, ""),
+    param(P05_SPEED_COMMAND, "Speed command keyboard setting", -9000, 9000, 1.0, "rpm"),
+    param(P05_JOG_SPEED, "Jog speed setting", 0, 9000, 1.0, "rpm"),
+    param(P05_ACCEL_TIME, "Acceleration time", 0, 10000, 1.0, "ms"),
+    param(P05_DECEL_TIME, "Deceleration time", 0, 10000, 1.0, "ms"),
+    param(P05_SPEED_LIMIT_SELECT, "Speed limit selection", 0, 0, 1.0, ""),
+    param(P05_FORWARD_SPEED_LIMIT, "Forward speed limit", 0, 9000, 1.0, "rpm"),
+    param(P05_BACKWARD_SPEED_LIMIT, "Backward speed limit", 0, 9000, 1.0, "rpm"),
+    param(P05_SPEED_DIRECTION, "Speed direction selection", 0, 3, 1.0, ""),
+    param(P05_ZERO_SPEED_VALUE, "Zero fixed speed value", 0, 6000, 1.0, "rpm"),
+    param(P05_RUNNING_THRESHOLD, "Motor running signal speed threshold", 0, 1000, 1.0, "rpm"),
+    param(P05_SPEED_UNIFORM_WIDTH, "Speed uniform signal width", 0, 100, 1.0, "rpm"),
+    param(P05_SPEED_REACHED_VALUE, "Speed reaches specified value", 0, 6000, 1.0, "rpm"),
+    param(P05_ZERO_SPEED_THRESHOLD, "Zero-speed judgment threshold", 0, 6000, 1.0, "rpm"),
+    param(P06_TORQUE_CMD_SOURCE, "Main torque command A source", 0, 1, 1.0, ""),
+    param(P06_TORQUE_CMD_SELECT, "Torque command selection", 0, 3, 1.0, ""),
+    param(P06_TORQUE_FILTER, "Torque command filter time", 0, 65535, 0.01, "ms"),
+    param(P06_TORQUE_COMMAND, "Torque command keyboard setting", -3000, 3000, 0.1, "%"),
+    param(P06_TORQUE_LIMIT_SOURCE, "Torque limit source", 0, 1, 1.0, ""),
+    param(P06_FORWARD_TORQUE_LIMIT, "Forward internal torque limit", 0, 5000, 0.1, "%"),
+    param(P06_BACKWARD_TORQUE_LIMIT, "Backward internal torque limit", 0, 5000, 0.1, "%"),
+    param(P06_FORWARD_EXT_TORQUE_LIMIT, "Forward external torque limit", 0, 5000, 0.1, "%"),
+    param(P06_BACKWARD_EXT_TORQUE_LIMIT, "Backward external torque limit", 0, 5000, 0.1, "%"),
+    param(P06_SPEED_LIMIT_SOURCE, "Speed limit source", 0, 1, 1.0, ""),
+    param(P06_POSITIVE_SPEED_LIMIT, "Positive speed limit in torque mode", 0, 9000, 1.0, "rpm"),
+    param(P06_NEGATIVE_SPEED_LIMIT, "Negative speed limit in torque mode", 0, 9000, 1.0, "rpm"),
+    param(P06_TORQUE_SEGMENT1, "Multi-segment torque command 1", -3000, 3000, 0.1, "%"),
+    param(P06_TORQUE_SEGMENT2, "Multi-segment torque command 2", -3000, 3000, 0.1, "%"),
+    param(P06_TORQUE_SEGMENT3, "Multi-segment torque command 3", -3000, 3000, 0.1, "%"),
+    param(P07_POSITION_GAIN1, "Position loop gain 1", 10, 20000, 0.1, "Hz"),
+    param(P07_SPEED_GAIN1, "Speed loop gain 1", 10, 20000, 0.1, "Hz"),
+    param(P07_SPEED_INTEGRAL1, "Speed loop integral time 1", 15, 512, 0.01, "ms"),
+    param(P07_SPEED_FILTER1, "Speed detection filter 1", 0, 200, 0.01, "ms"),
+    param(P07_POSITION_GAIN2, "Position loop gain 2", 10, 20000, 0.1, "Hz"),
+    param(P07_SPEED_GAIN2, "Speed loop gain 2", 10, 20000, 0.1, "Hz"),
+    param(P07_GAINSWITCH_ACTION, "GAINSWITCH action select", 0, 1, 1.0, ""),
+    param(P07_GAIN_SWITCH_MODE, "Gain switching mode", 0, 13, 1.0, ""),
+    param(P08_ADAPTIVE_FILTER_MODE, "Adaptive filter mode", 0, 5, 1.0, ""),
+    param(P08_NOTCH1_FREQUENCY, "1st notch filter frequency", 10, 4000, 1.0, "Hz"),
+    param(P08_NOTCH1_WIDTH, "1st notch filter width", 0, 8, 1.0, ""),
+    param(P08_NOTCH1_DEPTH, "1st notch filter depth", 0, 100, 1.0, ""),
+    param(P08_DAMPING_FILTER, "Damping filter switch", 0, 1, 1.0, ""),
+    param(P08_DAMPING_FILTER_SELECT, "Damping filter selection", 0, 1, 1.0, ""),
+    param(P08_INERTIA_ID_MODE, "Inertia identification mode", 0, 1, 1.0, ""),
+    param(P08_HF_VIBRATION_SUPPRESS, "HF vibration suppression switch", 0, 1, 1.0, ""),
+    param(P08_ANTI_DISTURBANCE, "Anti-disturbance compensation", 0, 1, 1.0, ""),
+    param(P08_SPEED_COMPENSATION, "Momentary speed compensation", 0, 1, 1.0, ""),
+    param(P08_MODEL_COMPENSATION, "Model compensation switch", 0, 2, 1.0, ""),
+    param(P09_UNDERVOLTAGE_DELAY, "Undervoltage detection delay", 100, 20000, 0.1, "ms"),
+    param(P09_RUNAWAY_PROTECTION, "Out-of-control protection", 0, 1, 1.0, ""),
+    param(P09_OVERLOAD_WARNING, "Overload warning value", 1, 100, 1.0, "%"),
+    param(P09_MOTOR_OVERLOAD_FACTOR, "Motor overload factor", 10, 300, 1.0, "%"),
+    param(P09_UNDERVOLTAGE_POINT, "Undervoltage protection point", 50, 100, 1.0, "%"),
+    param(P09_OVERSPEED_POINT, "Overspeed fault point", 50, 120, 1.0, "%"),
+    param(P09_POSITION_DEVIATION_THRESHOLD, "Position deviation excessive threshold", 1, 1 << 30, 1.0, "pulse"),
+    param(P09_LOCKED_ROTOR_TEMP, "Locked-rotor over-temp enable", 0, 1, 1.0, ""),
+    param(P09_OVERLOAD_PROTECTION, "Overload protection enable", 0, 3, 1.0, ""),
+    param(P10_COMM_ADDRESS, "Communication address", 0, 247, 1.0, ""),
+    param(P10_MODBUS_BAUDRATE, "Modbus baud rate setting", 0, 6, 1.0, ""),
+    param(P10_MODBUS_FORMAT, "Modbus data format", 0, 3, 1.0, ""),
+    param(P10_WRITE_EEPROM, "Write comm params to EEPROM", 0, 1, 1.0, ""),
+    param(P10_RS232_BAUDRATE, "RS232 baud rate setting", 0, 6, 1.0, ""),
+    param(P10_RS485_ADDRESS_SOURCE, "RS485 address source", 0, 1, 1.0, ""),
+    param(P11_FAULT_RESET, "Fault reset", 0, 1, 1.0, ""),
+    param(P11_SOFT_RESET, "Soft reset", 0, 1, 1.0, ""),
+    param(P11_ENCODER_RESET, "Absolute encoder reset", 0, 2, 1.0, ""),
+    param(P11_SOFT_LIMIT_SET, "Absolute system soft limit set", 0, 2, 1.0, ""),
+    param(P11_SYSTEM_INIT, "System initialization", 0, 2, 1.0, ""),
+    param(P11_FORCED_DIDO, "Forced DIDO enable", 0, 3, 1.0, ""),
+    param(P11_FORCED_DI_VALUE, "Set DI forced input", 0, 511, 1.0, ""),
+    param(P11_FORCED_DO_VALUE, "Set DO forced output", 0, 31, 1.0, ""),
+    param(P11_EMERGENCY_STOP, "Emergency stop settings", 0, 1, 1.0, ""),
+    param(P12_LED_WARNING, "LED warning display selection", 0, 1, 1.0, ""),
+    param(P12_DEFAULT_DISPLAY, "Default display settings", 0, 100, 1.0, ""),
+    param(P12_SPEED_DISPLAY_FILTER, "Speed display filter time", 0, 10000, 0.1, "ms"),
+    param(P13_OPERATION_MODE, "Operation mode", 0, 2, 1.0, ""),
+    param(P13_START_SEGMENT, "Start segment", 1, 16, 1.0, ""),
+    param(P13_END_SEGMENT, "End segment", 1, 16, 1.0, ""),
+    param(P13_INTERRUPT_HANDLING, "Interrupt handling", 0, 1, 1.0, ""),
+    param(P13_WAIT_TIME_UNIT, "Wait time unit", 0, 1, 1.0, ""),
+    param(P13_POSITION_MODE, "Position mode", 0, 1, 1.0, ""),
+    param(P14_OPERATION_MODE, "Operation mode", 0, 2, 1.0, ""),
+    param(P14_END_SEGMENT, "End segment", 1, 16, 1.0, ""),
+    param(P14_TIME_UNIT, "Time unit", 0, 1, 1.0, ""),
+    param(P14_ACCEL_DECEL_TIME1, "Accel/Decel time 1", 0, 10000, 1.0, "ms"),
+    param(P14_ACCEL_DECEL_TIME2, "Accel/Decel time 2", 0, 10000, 1.0, "ms"),
+    param(P14_ACCEL_DECEL_TIME3, "Accel/Decel time 3", 0, 10000, 1.0, "ms"),
+    param(P14_ACCEL_DECEL_TIME4, "Accel/Decel time 4", 0, 10000, 1.0, "ms"),
+    param(P16_FIXED_LENGTH_ENABLE, "Fixed length interrupt enable", 0, 1, 1.0, ""),
+    param(P16_FIXED_LENGTH1_DISP, "Fixed length 1 displacement", 0, (1 << 30) - 1, 1.0, "unit"),
+    param(P16_FIXED_LENGTH1_SPEED, "Fixed length 1 speed", 0, 9000, 1.0, "rpm"),
+    param(P16_FIXED_LENGTH_ACCEL, "Fixed length accel time", 0, 1000, 1.0, "ms"),
+    param(P16_FIXED_LENGTH_DECEL, "Fixed length decel time", 0, 1000, 1.0, "ms"),
+    param(P16_LOCK_RELEASE_ENABLE, "Lock release enable", 0, 1, 1.0, ""),
+    param(P16_HOMING_ENABLE_MODE, "Homing enable mode", 0, 6, 1.0, ""),
+    param(P16_HOMING_MODE, "Homing mode", 0, 17, 1.0, ""),
+    param(P16_HOMING_HIGH_SPEED, "Homing high speed", 10, 3000, 1.0, "rpm"),
+    param(P16_HOMING_LOW_SPEED, "Homing low speed", 10, 1000, 1.0, "rpm"),
+    param(P16_HOMING_ACCEL, "Homing accel limit", 0, 65535, 1.0, "ms"),
+    param(P16_HOMING_TIMEOUT, "Homing timeout", 0, 65535, 1.0, "ms"),
+    param(P16_HOME_OFFSET, "Mechanical home offset", -(1 << 30), 1 << 30, 1.0, "unit"),
+    param(P16_ENCODER_ORIGIN, "Absolute encoder origin", 0, u32::MAX as i64, 1.0, "inc"),
+    param(P16_ENCODER_TURNS, "Encoder turns at origin", 0, 32767, 1.0, "turn"),
+    param(P16_ZERO_WAIT_COUNT, "Zero wait count", 0, 65535, 1.0, "ms"),
+    param(P16_FIXED_LENGTH2_DISP, "Fixed length 2 displacement", -(1 << 30) + 1, (1 << 30) - 1, 1.0, "unit"),
+    param(P16_FIXED_LENGTH2_SPEED, "Fixed length 2 speed", 0, 9000, 1.0, "rpm"),
+    param(P18_SPEED_FEEDBACK, "Motor speed feedback", -9000, 9000, 1.0, "rpm"),
+    param(P18_LOAD_RATE, "Average load rate", 0, 3000, 0.1, "%"),
+    param(P18_SPEED_COMMAND, "Speed command", -9000, 9000, 1.0, "rpm"),
+    param(P18_INTERNAL_TORQUE, "Internal torque", -5000, 5000, 0.1, "%"),
+    param(P18_PHASE_CURRENT, "Phase current RMS", 0, 10000, 0.01, "A"),
+    param(P18_BUS_VOLTAGE, "DC bus voltage", 0, 10000, 0.1, "V"),
+    param(P18_ABSOLUTE_POSITION, "Absolute position", -(1 << 30), 1 << 30, 1.0, "unit"),
+    param(P18_ELECTRICAL_ANGLE, "Electrical angle", 0, 3600, 0.1, "°"),
+];
+
+/// Look up the descriptor of a parameter
+///
+/// # Example
+/// ```
+/// use dsyrs::registers::{param_info, ParamWidth, P00_RIGIDITY, P04_GEAR1_NUMERATOR, P18_BUS_VOLTAGE};
+///
+/// let info = param_info(P00_RIGIDITY).unwrap();
+/// assert_eq!((info.min, info.max), (0, 31));
+/// assert_eq!(param_info(P04_GEAR1_NUMERATOR).unwrap().width, ParamWidth::DoubleWord);
+///
+/// let info = param_info(P18_BUS_VOLTAGE).unwrap();
+/// assert!(!info.writable);
+/// assert_eq!((info.scale, info.unit), (0.1, "V"));
+///
+/// assert!(param_info(0xFFFF).is_none());
+/// ```
+pub fn param_info(addr: u16) -> Option<&'static ParamInfo> {
+    PARAM_TABLE
+        .binary_search_by_key(&addr, |info| info.addr)
+        .ok()
+        .map(|index| &PARAM_TABLE[index])
+}
+
+/// Check a raw value against the descriptor of a parameter before writing it
+///
+/// Fails with `InvalidParameter` for read-only parameters and values outside
+/// the documented range. Parameters without a descriptor are not checked.
+///
+/// # Example
+/// ```
+/// use dsyrs::registers::{validate_param, P05_SPEED_COMMAND, P00_RIGIDITY, P18_SPEED_FEEDBACK};
+///
+/// assert!(validate_param(P00_RIGIDITY, 31).is_ok());
+/// assert!(validate_param(P00_RIGIDITY, 32).is_err());
+/// assert!(validate_param(P05_SPEED_COMMAND, -9000).is_ok());
+/// assert!(validate_param(P05_SPEED_COMMAND, -9001).is_err());
+/// assert!(validate_param(P18_SPEED_FEEDBACK, 0).is_err());
+/// ```
+pub fn validate_param(addr: u16, value: i64) -> Result<()> {
+    let Some(info) = param_info(addr) else {
+        return Ok(());
+    };
+    if !info.writable {
+        return Err(DsyrsError::InvalidParameter(format!(
+            "{} ({}) is read-only",
+            format_param_code(addr),
+            info.name
+        )));
+    }
+    if !info.contains(value) {
+        return Err(DsyrsError::InvalidParameter(format!(
+            "{} out of range for {} ({}): {}-{}",
+            value,
+            format_param_code(addr),
+            info.name,
+            info.min,
+            info.max
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Read Batching
+// ============================================================================
+
+/// Maximum number of registers in one Read Holding Registers request
+pub const MAX_READ_REGISTERS: u16 = 125;
+
+/// Default gap merged by `coalesce_reads()` in `read_params()`
+///
+/// Reading up to 3 unrequested registers is cheaper than another round trip.
+pub const DEFAULT_READ_GAP: u16 = 3;
+
+/// Group addresses into `(start, count)` reads
+///
+/// Addresses are sorted and deduplicated. Neighbours are merged into one read
+/// when at most `max_gap` unrequested registers lie between them, and reads
+/// are split to stay within [`MAX_READ_REGISTERS`]. Merged reads also read the
+/// gap registers; use a gap of 0 if those may be undocumented on your drive.
+///
+/// # Example
+/// ```
+/// use dsyrs::registers::{coalesce_reads, param_addr, DEFAULT_READ_GAP};
+///
+/// // Contiguous addresses collapse to one read
+/// let addrs: Vec<u16> = (0..10).map(|p| param_addr(18, p)).collect();
+/// assert_eq!(coalesce_reads(&addrs, DEFAULT_READ_GAP), vec![(param_addr(18, 0), 10)]);
+///
+/// // Small gaps are merged, larger ones split; input order does not matter
+/// let addrs = [param_addr(5, 20), param_addr(5, 2), param_addr(5, 5), param_addr(5, 0)];
+/// assert_eq!(
+///     coalesce_reads(&addrs, DEFAULT_READ_GAP),
+///     vec![(param_addr(5, 0), 6), (param_addr(5, 20), 1)]
+/// );
+/// assert_eq!(coalesce_reads(&addrs, 0).len(), 4);
+///
+/// // A large sparse set stays within the 125-register limit
+/// let addrs: Vec<u16> = (0..300).map(|i| i * 2).collect();
+/// let reads = coalesce_reads(&addrs, DEFAULT_READ_GAP);
+/// assert_eq!(reads.len(), 5);
+/// assert!(reads.iter().all(|&(_, count)| count <= 125));
+/// assert_eq!(reads[1], (126, 125));
+/// ```
+pub fn coalesce_reads(addrs: &[u16], max_gap: u16) -> Vec<(u16, u16)> {
+    let mut sorted = addrs.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut reads: Vec<(u16, u16)> = Vec::new();
+    for addr in sorted {
+        if let Some((start, count)) = reads.last_mut() {
+            let end = *start as u32 + *count as u32 - 1;
+            let gap = addr as u32 - end - 1;
+            let span = addr as u32 - *start as u32 + 1;
+            if gap <= max_gap as u32 && span <= MAX_READ_REGISTERS as u32 {
+                *count = span as u16;
+                continue;
+            }
+        }
+        reads.push((addr, 1));
+    }
+    reads
+}
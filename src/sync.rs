@@ -3,23 +3,49 @@
 //! This module provides a native synchronous Modbus RTU client,
 //! compatible with em2rs library for shared bus operation.
 
+use crate::model::ServoConfigModel;
+use crate::params::{format_param_code, ParameterSet};
 use crate::registers;
+use crate::transport::SyncModbusTransport;
 use crate::types::*;
-#[cfg(feature = "modbus-delay")]
+use crate::units::{Amps, Rpm, TorquePercent, Volts};
+use std::collections::BTreeMap;
+use std::io;
 use std::thread;
-#[cfg(feature = "modbus-delay")]
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio_modbus::prelude::*;
 
-/// Default delay after modbus requests (1ms)
-#[cfg(feature = "modbus-delay")]
-const MODBUS_DELAY: Duration = Duration::from_millis(1);
+/// Polling interval used while waiting for a motion to complete
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Time allowed for a broadcast write to go out (slaves never answer slave 0)
+const BROADCAST_TIMEOUT: Duration = Duration::from_millis(20);
+
+/// Time allowed for a drive to answer at its new baud rate after `change_baud_rate()`
+const BAUD_CHANGE_WINDOW: Duration = Duration::from_secs(2);
+
+/// Delay between reconnection attempts in `change_baud_rate()`
+const BAUD_CHANGE_RETRY: Duration = Duration::from_millis(100);
+
+/// Response timeout used while probing the new baud rate, if none is configured
+const BAUD_PROBE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Sampling time after the target speed is reached, in `measure_acceleration()`
+const ACCEL_SETTLE_WINDOW: Duration = Duration::from_millis(200);
 
 /// Synchronous DSY-RS servo drive controller client
 ///
 /// This client uses tokio-modbus sync API for blocking Modbus RTU communication.
 /// It is designed to be compatible with em2rs for shared bus operation.
 ///
+/// The client is generic over its [`SyncModbusTransport`], which defaults to
+/// the tokio-modbus `client::sync::Context`; tests can use `MockTransport`
+/// instead (`test-util` feature).
+///
+/// Requests are bounded by the transport's own response timeout (the serial
+/// port timeout for RTU), not by `ServoConfig::request_timeout`; only
+/// [`connect()`](DsyrsSyncClient::connect) uses it to set up the context.
+///
 /// # Example
 /// ```no_run
 /// use dsyrs::{DsyrsSyncClient, ServoConfig, ControlMode, Slave};
@@ -47,17 +73,201 @@ const MODBUS_DELAY: Duration = Duration::from_millis(1);
 ///     Ok(())
 /// }
 /// ```
-pub struct DsyrsSyncClient {
-    ctx: client::sync::Context,
+pub struct DsyrsSyncClient<T = client::sync::Context> {
+    ctx: T,
     slave_id: u8,
     config: ServoConfig,
+    /// Motor maximum speed (P01.08), cached after the first read
+    motor_max_speed: Option<u16>,
+    /// P11.10 value to restore on `disable()`, set when `enable()` turned on DI forcing
+    forced_dido_restore: Option<u16>,
+    /// Set by `emergency_stop()` until `clear_emergency_stop()`, blocks motion commands
+    estop_latched: bool,
+    /// Communication counters, see `stats()`
+    stats: CommStats,
 }
 
 impl DsyrsSyncClient {
+    /// Open a serial port and create a client for `config.slave_id` on it
+    ///
+    /// Connects with `client::sync::rtu::connect_slave`, using
+    /// `config.request_timeout` as the response timeout of the context (no
+    /// timeout if unset). The client is not initialized: call `init()` before
+    /// use. Use [`new()`](DsyrsSyncClient::new) to share a bus or configure the
+    /// port further.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    /// use std::time::Duration;
+    ///
+    /// let config = ServoConfig::new(1).with_timeout(Duration::from_millis(100));
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, config)?;
+    /// servo.init()?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn connect(port: &str, baud: u32, config: ServoConfig) -> Result<Self> {
+        let builder = tokio_serial::new(port, baud);
+        let ctx = client::sync::rtu::connect_slave_with_timeout(
+            &builder,
+            Slave(config.slave_id),
+            config.request_timeout,
+        )
+        .map_err(|e| DsyrsError::SerialError(format!("{}: {}", port, e)))?;
+        Ok(Self::new(ctx, config))
+    }
+
+    /// Change the Modbus baud rate (P10.02) and reconnect at the new rate
+    ///
+    /// Writes P10.02, saves it to EEPROM (P10.04), closes the serial port and
+    /// reopens `port` at the new rate, retrying for up to 2 s until the drive
+    /// answers a read of P10.02 with the new setting. Some firmware switches
+    /// rate as soon as P10.02 is written, possibly before answering: a
+    /// transient error (no or garbled response) on the write or the save is
+    /// then taken as a switch and logged, and the save is repeated at the new
+    /// rate. An exception answer to either is the drive refusing it and is
+    /// returned at once. The client is consumed because the port has to be
+    /// closed before it can be reopened; the returned client keeps the
+    /// configuration and state of this one.
+    ///
+    /// Some firmware only applies P10.02 after a soft reset (P11.02) or a power
+    /// cycle. The drive then keeps answering at the old rate and this fails:
+    /// reconnect at the old rate with `connect()`, call `soft_reset()`, and
+    /// connect again at the new rate.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{BaudRate, DsyrsSyncClient, ServoConfig};
+    /// use std::time::Duration;
+    ///
+    /// let config = ServoConfig::new(1).with_timeout(Duration::from_millis(100));
+    /// let servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 9600, config)?;
+    /// let mut servo = servo.change_baud_rate(BaudRate::Baud115200, "/dev/ttyUSB0")?;
+    /// servo.init()?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn change_baud_rate(mut self, new: BaudRate, port: &str) -> Result<Self> {
+        match self.set_baud_rate(new) {
+            Ok(()) => {}
+            Err(e) if e.is_transient() => {
+                log_warn!(
+                    "No answer to the P10.02 write, probing at {} baud: {}",
+                    new.to_bps(),
+                    e
+                );
+            }
+            Err(e) => return Err(e),
+        }
+        let saved = match self.save_to_eeprom() {
+            Ok(()) => true,
+            Err(e) if e.is_transient() => {
+                log_warn!(
+                    "EEPROM save failed at the old rate, repeating it at {} baud: {}",
+                    new.to_bps(),
+                    e
+                );
+                false
+            }
+            Err(e) => return Err(e),
+        };
+
+        let DsyrsSyncClient {
+            ctx,
+            slave_id,
+            mut config,
+            motor_max_speed,
+            forced_dido_restore,
+            estop_latched,
+            stats,
+        } = self;
+        // Close the port before reopening it
+        drop(ctx);
+        config.slave_id = slave_id;
+        let mut probe_config = config.clone();
+        probe_config.request_timeout = config.request_timeout.or(Some(BAUD_PROBE_TIMEOUT));
+
+        let deadline = Instant::now() + BAUD_CHANGE_WINDOW;
+        loop {
+            let attempt = match Self::connect(port, new.to_bps(), probe_config.clone()) {
+                Ok(mut client) => match client.get_baud_rate() {
+                    Ok(rate) if rate == new => Ok(client),
+                    Ok(rate) => Err(DsyrsError::OperationFailed(format!(
+                        "Drive reports {:?} after the baud rate change",
+                        rate
+                    ))),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
+            match attempt {
+                Ok(mut client) => {
+                    if !saved {
+                        client.save_to_eeprom()?;
+                    }
+                    client.ctx.set_timeout(config.request_timeout);
+                    client.config = config;
+                    client.motor_max_speed = motor_max_speed;
+                    client.forced_dido_restore = forced_dido_restore;
+                    client.estop_latched = estop_latched;
+                    client.stats = stats;
+                    return Ok(client);
+                }
+                Err(e) if Instant::now() >= deadline => {
+                    return Err(DsyrsError::OperationFailed(format!(
+                        "No response at {} baud on {}: {}",
+                        new.to_bps(),
+                        port,
+                        e
+                    )));
+                }
+                Err(e) => {
+                    log_debug!("Reconnecting at {} baud: {}", new.to_bps(), e);
+                    thread::sleep(BAUD_CHANGE_RETRY);
+                }
+            }
+        }
+    }
+}
+
+impl<T> DsyrsSyncClient<T> {
+    /// Get the current configuration
+    pub fn config(&self) -> &ServoConfig {
+        &self.config
+    }
+
+    /// Move the client state (configuration, caches, latches, counters) onto another context
+    ///
+    /// The current context is dropped first, so a serial port can be closed
+    /// before the replacement is opened.
+    pub(crate) fn with_context<U>(self, ctx: U) -> DsyrsSyncClient<U> {
+        let DsyrsSyncClient {
+            ctx: old,
+            slave_id,
+            config,
+            motor_max_speed,
+            forced_dido_restore,
+            estop_latched,
+            stats,
+        } = self;
+        drop(old);
+        DsyrsSyncClient {
+            ctx,
+            slave_id,
+            config,
+            motor_max_speed,
+            forced_dido_restore,
+            estop_latched,
+            stats,
+        }
+    }
+}
+
+impl<T: SyncModbusTransport> DsyrsSyncClient<T> {
     /// Create a new synchronous DSY-RS client with an existing tokio-modbus sync context
     ///
     /// # Arguments
-    /// * `ctx` - Tokio-modbus sync context (already initialized for RTU communication)
+    /// * `ctx` - Tokio-modbus sync context (already initialized for RTU communication),
+    ///   or any other [`SyncModbusTransport`]
     /// * `config` - Servo configuration including slave ID
     ///
     /// # Example
@@ -71,14 +281,30 @@ impl DsyrsSyncClient {
     /// let mut servo = DsyrsSyncClient::new(ctx, config);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(ctx: client::sync::Context, config: ServoConfig) -> Self {
+    pub fn new(ctx: T, config: ServoConfig) -> Self {
         Self {
             ctx,
             slave_id: config.slave_id,
             config,
+            motor_max_speed: None,
+            forced_dido_restore: None,
+            estop_latched: false,
+            stats: CommStats::default(),
         }
     }
 
+    /// Create a client writing to every drive on the bus at once (slave 0)
+    ///
+    /// The context is switched to the broadcast address; all drives apply the
+    /// writes simultaneously, e.g. to start or stop a group of axes together.
+    /// Broadcast writes get no response, so no error is reported by the drives,
+    /// and reads cannot be broadcast (they fail with `InvalidParameter`). Set
+    /// the slave back on the context returned by `into_context()` afterwards.
+    pub fn broadcast(mut ctx: T) -> Self {
+        ctx.set_slave(Slave::broadcast());
+        Self::new(ctx, ServoConfig::new(0))
+    }
+
     /// Consume the client and return the underlying Modbus context
     ///
     /// This is useful when you want to reuse the same physical connection
@@ -101,34 +327,39 @@ impl DsyrsSyncClient {
     /// // Now use ctx with em2rs: Em2rsSyncClient::new(ctx, stepper_config)
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn into_context(self) -> client::sync::Context {
+    pub fn into_context(self) -> T {
         self.ctx
     }
 
     /// Get a mutable reference to the Modbus context
-    pub fn context_mut(&mut self) -> &mut client::sync::Context {
+    pub fn context_mut(&mut self) -> &mut T {
         &mut self.ctx
     }
 
-    /// Get the current configuration
-    pub fn config(&self) -> &ServoConfig {
-        &self.config
-    }
-
     /// Get the slave ID
     pub fn slave_id(&self) -> u8 {
         self.slave_id
     }
 
+    /// Communication counters since the client was created or `reset_stats()`
+    ///
+    /// See [`CommStats`] for what is counted.
+    pub fn stats(&self) -> &CommStats {
+        &self.stats
+    }
+
+    /// Reset the communication counters
+    pub fn reset_stats(&mut self) {
+        self.stats = CommStats::default();
+    }
+
     /// Initialize the servo drive with configured parameters
     pub fn init(&mut self) -> Result<()> {
         self.ctx.set_slave(Slave::from(self.slave_id));
 
-        // Set control mode (P00.00)
-        self.write_register(registers::P00_CONTROL_MODE, self.config.control_mode.into())?;
-
-        // Set direction (P00.01)
-        self.write_register(registers::P00_DIRECTION, self.config.direction.into())?;
+        // Set control mode (P00.00) and direction (P00.01), checking both were kept
+        self.write_register_verified(registers::P00_CONTROL_MODE, self.config.control_mode.into())?;
+        self.write_register_verified(registers::P00_DIRECTION, self.config.direction.into())?;
 
         // Set max speed (P00.07)
         self.write_register(registers::P00_MAX_SPEED, self.config.max_speed)?;
@@ -138,11 +369,7 @@ impl DsyrsSyncClient {
         let motor_model = self.read_register(registers::P01_MOTOR_MODEL)?;
         if let Some(expected_model) = self.config.motor_model_code {
             if motor_model != expected_model {
-                log::warn!(
-                    "Motor model mismatch: expected {}, read {}",
-                    expected_model,
-                    motor_model
-                );
+                init_mismatch!("Motor model (P01.00)", expected_model, motor_model);
             }
         }
 
@@ -151,34 +378,32 @@ impl DsyrsSyncClient {
         let rated_current = rated_current_raw as f32 / 100.0;
         if let Some(expected_current) = self.config.rated_current {
             if (rated_current - expected_current).abs() > 0.01 {
-                log::warn!(
-                    "Rated current mismatch: expected {} A, read {} A",
-                    expected_current,
-                    rated_current
-                );
+                init_mismatch!("Rated current (A, P01.04)", expected_current, rated_current);
             }
         }
 
+        // Read motor max speed (P01.08) and cache it for speed_percent_of_max()
+        self.motor_max_speed = Some(self.read_register(registers::P01_MAX_SPEED)?);
+
         // Read encoder type (P01.18)
         let encoder_type_raw = self.read_register(registers::P01_ENCODER_SELECTION)?;
         if let Some(expected_encoder) = self.config.encoder_type {
             let expected_value: u16 = expected_encoder.into();
             if encoder_type_raw != expected_value {
-                log::warn!(
-                    "Encoder type mismatch: expected {:?}, read {}",
-                    expected_encoder,
-                    encoder_type_raw
-                );
+                init_mismatch!("Encoder type (P01.18)", expected_encoder, encoder_type_raw);
             }
         }
 
         // Read encoder resolution (P01.20) - stored as two 16-bit registers
         let resolution_regs = self.read_registers(registers::P01_ENCODER_RESOLUTION, 2)?;
-        let encoder_resolution = ((resolution_regs[0] as u32) << 16) | (resolution_regs[1] as u32);
+        let encoder_resolution = self
+            .config
+            .word_order
+            .join([resolution_regs[0], resolution_regs[1]]);
         if let Some(expected_resolution) = self.config.encoder_resolution {
             if encoder_resolution != expected_resolution {
-                log::warn!(
-                    "Encoder resolution mismatch: expected {}, read {}",
+                init_mismatch!(
+                    "Encoder resolution (P01.20)",
                     expected_resolution,
                     encoder_resolution
                 );
@@ -193,51 +418,292 @@ impl DsyrsSyncClient {
     // ========================================================================
 
     /// Write a single holding register
+    ///
+    /// With slave ID 0 (broadcast) no response is awaited, see `broadcast_write()`.
     pub fn write_register(&mut self, addr: u16, value: u16) -> Result<()> {
-        self.ctx.write_single_register(addr, value)??;
-        #[cfg(feature = "modbus-delay")]
-        thread::sleep(MODBUS_DELAY);
+        bus_event!(write, self.slave_id, addr, value);
+        if self.slave_id == 0 {
+            return self.broadcast_write(|ctx| ctx.write_single_register(addr, value));
+        }
+        let mut retries = 0;
+        loop {
+            self.stats.requests += 1;
+            match self.ctx.write_single_register(addr, value) {
+                Ok(response) => break self.stats.check(response)?,
+                Err(e) => retries = self.retry_or_fail(retries, e.into())?,
+            }
+        }
+        self.request_delay();
+        Ok(())
+    }
+
+    /// Write a single holding register and check that the drive kept the value
+    ///
+    /// Some parameters are only writable while the servo is disabled or in a
+    /// given mode, and the drive may acknowledge a write it then ignores. This
+    /// reads the register back and fails with `OperationFailed` if it differs,
+    /// so only use it for parameters that read back immediately. Broadcast
+    /// writes (slave 0) cannot be read back and are not verified.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{registers, DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.write_register_verified(registers::P00_MAX_SPEED, 3000)?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn write_register_verified(&mut self, addr: u16, value: u16) -> Result<()> {
+        self.write_register(addr, value)?;
+        if self.slave_id == 0 {
+            return Ok(());
+        }
+        let stored = self.read_register(addr)?;
+        if stored != value {
+            return Err(DsyrsError::OperationFailed(format!(
+                "Register 0x{:04X} written with {} but the drive holds {}",
+                addr, value, stored
+            )));
+        }
         Ok(())
     }
 
     /// Write multiple holding registers
+    ///
+    /// With slave ID 0 (broadcast) no response is awaited, see `broadcast_write()`.
     pub fn write_registers(&mut self, addr: u16, values: &[u16]) -> Result<()> {
-        self.ctx.write_multiple_registers(addr, values)??;
-        #[cfg(feature = "modbus-delay")]
-        thread::sleep(MODBUS_DELAY);
+        bus_event!(write, self.slave_id, addr, values);
+        if self.slave_id == 0 {
+            return self.broadcast_write(|ctx| ctx.write_multiple_registers(addr, values));
+        }
+        let mut retries = 0;
+        loop {
+            self.stats.requests += 1;
+            match self.ctx.write_multiple_registers(addr, values) {
+                Ok(response) => break self.stats.check(response)?,
+                Err(e) => retries = self.retry_or_fail(retries, e.into())?,
+            }
+        }
+        self.request_delay();
+        Ok(())
+    }
+
+    /// Perform a broadcast write (slave 0), for which the drives send no response
+    ///
+    /// The blocking tokio-modbus call would otherwise wait for the full context
+    /// timeout (or forever if none is set). The timeout is shortened to
+    /// `BROADCAST_TIMEOUT` for this request and the expected timeout is mapped
+    /// to `Ok(())`; the previous timeout is restored afterwards.
+    fn broadcast_write(
+        &mut self,
+        write: impl FnOnce(&mut T) -> tokio_modbus::Result<()>,
+    ) -> Result<()> {
+        let timeout = self.ctx.timeout();
+        self.ctx.set_timeout(Some(BROADCAST_TIMEOUT));
+        self.stats.requests += 1;
+        let result = write(&mut self.ctx);
+        self.ctx.set_timeout(timeout);
+
+        match result {
+            Ok(response) => self.stats.check(response)?,
+            Err(tokio_modbus::Error::Transport(e)) if e.kind() == io::ErrorKind::TimedOut => {}
+            Err(e) => {
+                let err = e.into();
+                self.stats.record_error(&err);
+                return Err(err);
+            }
+        }
+        self.request_delay();
         Ok(())
     }
 
+    /// Pause after a transaction for `ServoConfig::request_delay`
+    ///
+    /// Only with the `modbus-delay` feature; blocks the thread with
+    /// `thread::sleep`.
+    fn request_delay(&self) {
+        #[cfg(feature = "modbus-delay")]
+        if let Some(delay) = self.config.request_delay {
+            thread::sleep(delay);
+        }
+    }
+
     /// Read holding registers
+    ///
+    /// Reads cannot be broadcast: with slave ID 0 this fails immediately. A
+    /// reply holding fewer registers than requested fails with
+    /// `OperationFailed`, so the result can always be indexed up to `count`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{registers, DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let regs = servo.read_registers(registers::P18_SERVO_STATUS, 2)?;
+    /// println!("status {:#06X}, speed {} rpm", regs[0], regs[1] as i16);
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
     pub fn read_registers(&mut self, addr: u16, count: u16) -> Result<Vec<u16>> {
-        let data = self.ctx.read_holding_registers(addr, count)??;
-        #[cfg(feature = "modbus-delay")]
-        thread::sleep(MODBUS_DELAY);
+        if self.slave_id == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "Reads cannot be broadcast (slave 0)".into(),
+            ));
+        }
+        bus_event!(read, self.slave_id, addr, count);
+        let mut retries = 0;
+        let data = loop {
+            self.stats.requests += 1;
+            match self.ctx.read_holding_registers(addr, count) {
+                Ok(response) => break self.stats.check(response)?,
+                Err(e) => retries = self.retry_or_fail(retries, e.into())?,
+            }
+        };
+        self.request_delay();
+        if data.len() < count as usize {
+            self.stats.protocol_errors += 1;
+            return Err(DsyrsError::OperationFailed(format!(
+                "short read: expected {} got {}",
+                count,
+                data.len()
+            )));
+        }
         Ok(data)
     }
 
+    /// Wait before retrying a failed request, or give up with the final error
+    ///
+    /// Returns the updated retry count when the configured [`RetryPolicy`]
+    /// allows another attempt.
+    fn retry_or_fail(&mut self, retries: u8, err: DsyrsError) -> Result<u8> {
+        self.stats.record_error(&err);
+        let policy = self.config.retry_policy;
+        if !err.is_transient() || retries >= policy.max_retries {
+            if retries == 0 {
+                return Err(err);
+            }
+            return Err(DsyrsError::RetriesExhausted {
+                retries,
+                last: Box::new(err),
+            });
+        }
+        log_debug!(
+            "Modbus request failed ({}), retry {}/{}",
+            err,
+            retries + 1,
+            policy.max_retries
+        );
+        thread::sleep(policy.delay(retries));
+        self.stats.retries += 1;
+        Ok(retries + 1)
+    }
+
     /// Read a single holding register
     pub fn read_register(&mut self, addr: u16) -> Result<u16> {
         let data = self.read_registers(addr, 1)?;
         Ok(data[0])
     }
 
-    /// Write a 32-bit value as two consecutive registers
+    /// Set or clear one bit (0-15) of a register, keeping the other bits
+    ///
+    /// This is a read followed by a write: a change made to the register by
+    /// another master between the two requests is overwritten.
+    pub fn set_register_bit(&mut self, addr: u16, bit: u8, value: bool) -> Result<()> {
+        let mask = bit_mask(bit)?;
+        let current = self.read_register(addr)?;
+        let updated = if value {
+            current | mask
+        } else {
+            current & !mask
+        };
+        self.write_register(addr, updated)
+    }
+
+    /// Invert one bit (0-15) of a register, keeping the other bits
+    ///
+    /// Returns the new state of the bit. Like `set_register_bit()`, this is a
+    /// read followed by a write.
+    pub fn toggle_register_bit(&mut self, addr: u16, bit: u8) -> Result<bool> {
+        let mask = bit_mask(bit)?;
+        let updated = self.read_register(addr)? ^ mask;
+        self.write_register(addr, updated)?;
+        Ok(updated & mask != 0)
+    }
+
+    /// Read scattered parameters with as few requests as possible
+    ///
+    /// Values are returned in the requested order. Nearby addresses are merged
+    /// into one request when the gap is at most [`registers::DEFAULT_READ_GAP`]
+    /// registers; see `read_params_with_gap()`.
+    pub fn read_params(&mut self, addrs: &[u16]) -> Result<Vec<u16>> {
+        self.read_params_with_gap(addrs, registers::DEFAULT_READ_GAP)
+    }
+
+    /// Read scattered parameters, merging reads across gaps of up to `max_gap` registers
+    ///
+    /// A `max_gap` of 0 only merges strictly contiguous addresses, so no
+    /// unrequested register is read.
+    pub fn read_params_with_gap(&mut self, addrs: &[u16], max_gap: u16) -> Result<Vec<u16>> {
+        let mut values = BTreeMap::new();
+        for (start, count) in registers::coalesce_reads(addrs, max_gap) {
+            let data = self.read_registers(start, count)?;
+            for (offset, value) in data.into_iter().enumerate() {
+                values.insert(start + offset as u16, value);
+            }
+        }
+        addrs
+            .iter()
+            .map(|addr| {
+                values.get(addr).copied().ok_or_else(|| {
+                    DsyrsError::OperationFailed(format!("No value read for {:#06X}", addr))
+                })
+            })
+            .collect()
+    }
+
+    /// Write a 32-bit value as two consecutive registers, in the configured word order
     pub fn write_u32(&mut self, addr: u16, value: u32) -> Result<()> {
-        let high = (value >> 16) as u16;
-        let low = (value & 0xFFFF) as u16;
-        self.write_registers(addr, &[high, low])
+        let words = self.config.word_order.split(value);
+        self.write_registers(addr, &words)
     }
 
     /// Write a signed 32-bit value as two consecutive registers
+    ///
+    /// Negative values are sent in 32-bit two's complement, split in the
+    /// configured word order: -2 is written as 0xFFFF, 0xFFFE high word first.
     pub fn write_i32(&mut self, addr: u16, value: i32) -> Result<()> {
         self.write_u32(addr, value as u32)
     }
 
-    /// Read a 32-bit value from two consecutive registers
+    /// Write a signed displacement limited to ±(2^30 - 1), as two consecutive registers
+    ///
+    /// Displacement parameters (P13 segments, P16.01, P16.37) are 32-bit but
+    /// documented with a 2^30 limit; values with `|value| >= 2^30` are rejected
+    /// with `InvalidParameter` before anything is written. The encoding is the
+    /// same two's complement as `write_i32()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{registers, DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.write_i30(registers::P16_FIXED_LENGTH2_DISP, -100_000)?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn write_i30(&mut self, addr: u16, value: i32) -> Result<()> {
+        if value.unsigned_abs() >= 1 << 30 {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Value {} for {} must be within ±(2^30 - 1)",
+                value,
+                format_param_code(addr)
+            )));
+        }
+        self.write_i32(addr, value)
+    }
+
+    /// Read a 32-bit value from two consecutive registers, in the configured word order
     pub fn read_u32(&mut self, addr: u16) -> Result<u32> {
         let data = self.read_registers(addr, 2)?;
-        Ok(((data[0] as u32) << 16) | (data[1] as u32))
+        Ok(self.config.word_order.join([data[0], data[1]]))
     }
 
     /// Read a signed 32-bit value from two consecutive registers
@@ -245,13 +711,60 @@ impl DsyrsSyncClient {
         Ok(self.read_u32(addr)? as i32)
     }
 
+    /// Read a 32-bit value decoded in both word orders (diagnostic helper)
+    ///
+    /// Returns `(high_word_first, low_word_first)`. Read a parameter with a known
+    /// value (e.g. a gear ratio you just set) to find out which word order your
+    /// firmware or gateway uses, then set it with `ServoConfig::with_word_order()`.
+    pub fn read_u32_both(&mut self, addr: u16) -> Result<(u32, u32)> {
+        let data = self.read_registers(addr, 2)?;
+        let high_first = ((data[0] as u32) << 16) | (data[1] as u32);
+        let low_first = ((data[1] as u32) << 16) | (data[0] as u32);
+        Ok((high_first, low_first))
+    }
+
+    /// Measure the bus round-trip time over `samples` reads
+    ///
+    /// Performs `samples` harmless read-only transactions (product code,
+    /// P12.14) and times each request/response, excluding the optional
+    /// `modbus-delay` pause. Use the result to choose operation timeouts and
+    /// inter-frame delays for the actual adapter and cabling.
+    ///
+    /// Samples go through `read_registers()`, so the request timeout and
+    /// retry policy apply and they are counted in `stats()`; a retried sample
+    /// includes its retries.
+    pub fn measure_latency(&mut self, samples: usize) -> Result<LatencyStats> {
+        if samples == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "Latency measurement needs at least 1 sample".into(),
+            ));
+        }
+        // read_registers() ends with the modbus-delay pause
+        let pause = if cfg!(feature = "modbus-delay") {
+            self.config.request_delay.unwrap_or_default()
+        } else {
+            Duration::ZERO
+        };
+        let mut times = Vec::with_capacity(samples);
+        for _ in 0..samples {
+            let start = Instant::now();
+            self.read_registers(registers::P12_PRODUCT_CODE, 1)?;
+            times.push(start.elapsed().saturating_sub(pause));
+        }
+        LatencyStats::from_samples(times)
+            .ok_or_else(|| DsyrsError::OperationFailed("No latency samples".into()))
+    }
+
     // ========================================================================
     // P00 - BASIC CONTROL OPERATIONS
     // ========================================================================
 
-    /// Set control mode (P00.00)
+    /// Set control mode (P00.00), checked by reading it back
+    ///
+    /// Fails with `OperationFailed` if the drive did not take the new mode,
+    /// see `write_register_verified()`.
     pub fn set_control_mode(&mut self, mode: ControlMode) -> Result<()> {
-        self.write_register(registers::P00_CONTROL_MODE, mode.into())
+        self.write_register_verified(registers::P00_CONTROL_MODE, mode.into())
     }
 
     /// Get control mode (P00.00)
@@ -265,6 +778,11 @@ impl DsyrsSyncClient {
         self.write_register(registers::P00_DIRECTION, direction.into())
     }
 
+    /// Get direction (P00.01)
+    pub fn get_direction(&mut self) -> Result<Direction> {
+        Direction::try_from(self.read_register(registers::P00_DIRECTION)?)
+    }
+
     /// Set rigidity level (P00.04, 0-31)
     pub fn set_rigidity(&mut self, level: u8) -> Result<()> {
         if level > 31 {
@@ -293,6 +811,60 @@ impl DsyrsSyncClient {
         self.write_register(registers::P00_MAX_SPEED, rpm)
     }
 
+    /// Get absolute value system selection (P00.06)
+    pub fn get_absolute_system(&mut self) -> Result<AbsoluteSystem> {
+        let value = self.read_register(registers::P00_ABSOLUTE_SYSTEM)?;
+        AbsoluteSystem::try_from(value)
+    }
+
+    /// Set servo OFF stop mode (P00.10)
+    ///
+    /// See `configure_servo_off_behavior()` to set the deceleration time of a
+    /// zero-speed stop along with it.
+    pub fn set_servo_off_stop_mode(&mut self, mode: ServoOffStopMode) -> Result<()> {
+        self.write_register(registers::P00_SERVO_OFF_STOP_MODE, mode.into())
+    }
+
+    /// Set stop mode on overtravel (P00.13)
+    pub fn set_overtravel_stop_mode(&mut self, mode: OvertravelStopMode) -> Result<()> {
+        self.write_register(registers::P00_OVERTRAVEL_STOP_MODE, mode.into())
+    }
+
+    /// Get stop mode on overtravel (P00.13)
+    pub fn get_overtravel_stop_mode(&mut self) -> Result<OvertravelStopMode> {
+        let value = self.read_register(registers::P00_OVERTRAVEL_STOP_MODE)?;
+        OvertravelStopMode::try_from(value)
+    }
+
+    /// Configure how the motor stops on servo OFF (P00.10 and P05.06)
+    ///
+    /// `ServoOffStopMode::ZeroSpeed` decelerates with the speed-mode deceleration
+    /// time P05.06, so both are written together. A zero-speed stop with a
+    /// 0 ms deceleration is logged as a warning, as it stops abruptly instead of ramping.
+    pub fn configure_servo_off_behavior(
+        &mut self,
+        mode: ServoOffStopMode,
+        decel_ms: u16,
+    ) -> Result<()> {
+        if decel_ms > 10000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Deceleration time must be 0-10000 ms".into(),
+            ));
+        }
+        if mode == ServoOffStopMode::ZeroSpeed && decel_ms == 0 {
+            log_warn!("Zero-speed servo OFF stop with 0 ms deceleration (P05.06)");
+        }
+        self.write_register(registers::P00_SERVO_OFF_STOP_MODE, mode.into())?;
+        self.write_register(registers::P05_DECEL_TIME, decel_ms)
+    }
+
+    /// Get servo OFF stop mode (P00.10) and deceleration time (P05.06, ms)
+    pub fn get_servo_off_behavior(&mut self) -> Result<(ServoOffStopMode, u16)> {
+        let mode = self.read_register(registers::P00_SERVO_OFF_STOP_MODE)?;
+        let decel_ms = self.read_register(registers::P05_DECEL_TIME)?;
+        Ok((ServoOffStopMode::try_from(mode)?, decel_ms))
+    }
+
     /// Set brake ON delay (P00.14, 0-10000 ms)
     pub fn set_brake_on_delay(&mut self, ms: u16) -> Result<()> {
         self.write_register(registers::P00_BRAKE_ON_DELAY, ms)
@@ -303,6 +875,190 @@ impl DsyrsSyncClient {
         self.write_register(registers::P00_BRAKE_OFF_DELAY, ms)
     }
 
+    /// Set brake speed threshold (P00.16, 0-1000 rpm)
+    ///
+    /// Below this speed the brake engages after servo OFF, without waiting for
+    /// the OFF delay (P00.15). See `BrakeConfig` for the safety interaction.
+    pub fn set_brake_speed_threshold(&mut self, rpm: u16) -> Result<()> {
+        if rpm > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Brake speed threshold must be 0-1000 rpm".into(),
+            ));
+        }
+        self.write_register(registers::P00_BRAKE_SPEED_THRESHOLD, rpm)
+    }
+
+    /// Get brake speed threshold (P00.16, rpm)
+    pub fn get_brake_speed_threshold(&mut self) -> Result<u16> {
+        self.read_register(registers::P00_BRAKE_SPEED_THRESHOLD)
+    }
+
+    /// Set fault brake delay (P00.17, 0-10000 ms)
+    ///
+    /// On a No.1 fault while running, the delay between servo OFF and the
+    /// brake output turning OFF.
+    pub fn set_fault_brake_delay(&mut self, ms: u16) -> Result<()> {
+        if ms > 10000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fault brake delay must be 0-10000 ms".into(),
+            ));
+        }
+        self.write_register(registers::P00_FAULT_BRAKE_DELAY, ms)
+    }
+
+    /// Apply brake configuration (P00.10, P00.13-P00.17)
+    ///
+    /// Out-of-range values are rejected; suspicious combinations reported by
+    /// `BrakeConfig::warnings()` are logged but still written.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{BrakeConfig, DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.apply_brake_config(&BrakeConfig::default().with_off_delay(300))?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn apply_brake_config(&mut self, config: &BrakeConfig) -> Result<()> {
+        config.validate()?;
+        for warning in config.warnings() {
+            log_warn!("{}", warning);
+        }
+        self.write_register(
+            registers::P00_SERVO_OFF_STOP_MODE,
+            config.servo_off_stop_mode.into(),
+        )?;
+        self.write_registers(
+            registers::P00_OVERTRAVEL_STOP_MODE,
+            &[
+                config.overtravel_stop_mode.into(),
+                config.on_delay,
+                config.off_delay,
+                config.speed_threshold,
+                config.fault_delay,
+            ],
+        )
+    }
+
+    /// Read brake configuration (P00.10, P00.13-P00.17)
+    pub fn read_brake_config(&mut self) -> Result<BrakeConfig> {
+        let regs = self.read_registers(registers::P00_SERVO_OFF_STOP_MODE, 8)?;
+        Ok(BrakeConfig {
+            servo_off_stop_mode: ServoOffStopMode::try_from(regs[0])?,
+            overtravel_stop_mode: OvertravelStopMode::try_from(regs[3])?,
+            on_delay: regs[4],
+            off_delay: regs[5],
+            speed_threshold: regs[6],
+            fault_delay: regs[7],
+        })
+    }
+
+    /// Set braking start voltage (P00.22, 0-1000 V)
+    ///
+    /// The brake chopper fires above this bus voltage. It should sit above the
+    /// nominal bus voltage with some margin (about 10-20%): below nominal the
+    /// chopper conducts continuously and overheats the braking resistor, too
+    /// high and regeneration trips an overvoltage fault first. The current bus
+    /// voltage (P18.06) is read and a warning is logged when the setting is
+    /// below it or less than 10% above it.
+    pub fn set_brake_start_voltage(&mut self, volts: u16) -> Result<()> {
+        if volts > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Braking start voltage must be 0-1000 V".into(),
+            ));
+        }
+        let bus_voltage = self.get_bus_voltage()?;
+        let volts_f = volts as f32;
+        if volts_f <= bus_voltage {
+            log_warn!(
+                "Braking start voltage {} V is not above the bus voltage {:.1} V: \
+                 the braking resistor will conduct continuously",
+                volts,
+                bus_voltage
+            );
+        } else if volts_f < bus_voltage * 1.1 {
+            log_warn!(
+                "Braking start voltage {} V is less than 10% above the bus voltage {:.1} V",
+                volts,
+                bus_voltage
+            );
+        }
+        self.write_register(registers::P00_BRAKE_VOLTAGE, volts)
+    }
+
+    /// Get braking start voltage (P00.22, V)
+    pub fn get_brake_start_voltage(&mut self) -> Result<u16> {
+        self.read_register(registers::P00_BRAKE_VOLTAGE)
+    }
+
+    /// Set energy consumption resistor (P00.18)
+    ///
+    /// Set the external ratings with `set_external_resistor()` before
+    /// selecting an external resistor, or use `apply_braking_resistor_config()`.
+    pub fn set_energy_resistor(&mut self, resistor: EnergyResistor) -> Result<()> {
+        self.write_register(registers::P00_ENERGY_RESISTOR, resistor.into())
+    }
+
+    /// Set external resistor ratings (P00.19-P00.21)
+    ///
+    /// Power capacity 1-65535 W, resistance 1-1000 Ω, heating time constant
+    /// 1000-65535 ms. These must match the fitted resistor for the drive's
+    /// overload protection to work.
+    pub fn set_external_resistor(
+        &mut self,
+        power_w: u16,
+        resistance_ohm: u16,
+        time_constant_ms: u16,
+    ) -> Result<()> {
+        BrakingResistorConfig::check_external_resistor(power_w, resistance_ohm, time_constant_ms)?;
+        self.write_registers(
+            registers::P00_EXT_RESISTOR_POWER,
+            &[power_w, resistance_ohm, time_constant_ms],
+        )
+    }
+
+    /// Write the braking resistor configuration (P00.18-P00.22)
+    ///
+    /// The configuration is validated first. External ratings are written
+    /// before the resistor selection, so the drive never protects an external
+    /// resistor with stale values; they are left untouched otherwise. The
+    /// braking voltage goes through `set_brake_start_voltage()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{BrakingResistorConfig, DsyrsSyncClient, EnergyResistor, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let config = BrakingResistorConfig::new(EnergyResistor::ExternalNatural, 380)
+    ///     .with_external_resistor(200, 50, 5000);
+    /// servo.apply_braking_resistor_config(&config)?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn apply_braking_resistor_config(&mut self, config: &BrakingResistorConfig) -> Result<()> {
+        config.validate()?;
+        if config.resistor.is_external() {
+            self.set_external_resistor(
+                config.power_w,
+                config.resistance_ohm,
+                config.time_constant_ms,
+            )?;
+        }
+        self.set_energy_resistor(config.resistor)?;
+        self.set_brake_start_voltage(config.brake_voltage)
+    }
+
+    /// Read the braking resistor configuration (P00.18-P00.22)
+    pub fn read_braking_resistor_config(&mut self) -> Result<BrakingResistorConfig> {
+        let regs = self.read_registers(registers::P00_ENERGY_RESISTOR, 5)?;
+        Ok(BrakingResistorConfig {
+            resistor: EnergyResistor::try_from(regs[0])?,
+            power_w: regs[1],
+            resistance_ohm: regs[2],
+            time_constant_ms: regs[3],
+            brake_voltage: regs[4],
+        })
+    }
+
     // ========================================================================
     // P01 - SERVO MOTOR PARAMETERS
     // ========================================================================
@@ -321,7 +1077,7 @@ impl DsyrsSyncClient {
 
     /// Set pole pairs (P01.10, 1-50)
     pub fn set_pole_pairs(&mut self, pairs: u8) -> Result<()> {
-        if pairs < 1 || pairs > 50 {
+        if !(1..=50).contains(&pairs) {
             return Err(DsyrsError::InvalidParameter(
                 "Pole pairs must be 1-50".into(),
             ));
@@ -334,6 +1090,28 @@ impl DsyrsSyncClient {
         self.write_register(registers::P01_ENCODER_SELECTION, encoder.into())
     }
 
+    /// Get encoder type (P01.18)
+    pub fn get_encoder_type(&mut self) -> Result<EncoderType> {
+        EncoderType::try_from(self.read_register(registers::P01_ENCODER_SELECTION)?)
+    }
+
+    /// Get encoder resolution (P01.20, increments per revolution)
+    pub fn get_encoder_resolution(&mut self) -> Result<u32> {
+        self.read_u32(registers::P01_ENCODER_RESOLUTION)
+    }
+
+    /// Get motor maximum speed (P01.08, rpm)
+    ///
+    /// The value is read once (or during `init()`) and cached afterwards.
+    pub fn get_motor_max_speed(&mut self) -> Result<u16> {
+        if let Some(rpm) = self.motor_max_speed {
+            return Ok(rpm);
+        }
+        let rpm = self.read_register(registers::P01_MAX_SPEED)?;
+        self.motor_max_speed = Some(rpm);
+        Ok(rpm)
+    }
+
     // ========================================================================
     // P02 - DIGITAL I/O CONFIGURATION
     // ========================================================================
@@ -366,24 +1144,217 @@ impl DsyrsSyncClient {
         self.write_register(register, logic.into())
     }
 
-    // ========================================================================
-    // P04 - POSITION CONTROL
-    // ========================================================================
+    /// Get digital input function (DI1-DI3)
+    ///
+    /// Codes the library does not know are returned as `DiFunction::Unknown`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// for input in 1..=3 {
+    ///     println!("DI{}: {:?}", input, servo.get_di_function(input)?);
+    /// }
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn get_di_function(&mut self, input: u8) -> Result<DiFunction> {
+        let register = registers::get_di_function_register(input)
+            .ok_or(DsyrsError::InvalidDigitalInput(input))?;
+        Ok(DiFunction::from_raw(self.read_register(register)?))
+    }
 
-    /// Set position command source (P04.00)
-    pub fn set_position_cmd_source(&mut self, source: PositionCmdSource) -> Result<()> {
-        self.write_register(registers::P04_POSITION_CMD_SOURCE, source.into())
+    /// Get digital input logic (DI1-DI3)
+    pub fn get_di_logic(&mut self, input: u8) -> Result<DiLogic> {
+        let register = registers::get_di_logic_register(input)
+            .ok_or(DsyrsError::InvalidDigitalInput(input))?;
+        DiLogic::try_from(self.read_register(register)?)
     }
 
-    /// Set step amount (P04.02, -9999 to 9999)
-    pub fn set_step_amount(&mut self, amount: i16) -> Result<()> {
-        self.write_register(registers::P04_STEP_AMOUNT, amount as u16)
+    /// Get digital output function (DO1-DO2)
+    pub fn get_do_function(&mut self, output: u8) -> Result<DoFunction> {
+        let register = registers::get_do_function_register(output)
+            .ok_or(DsyrsError::InvalidDigitalOutput(output))?;
+        DoFunction::try_from(self.read_register(register)?)
     }
 
-    /// Set electronic gear ratio (P04.07/P04.09)
+    /// Get digital output logic (DO1-DO2)
+    pub fn get_do_logic(&mut self, output: u8) -> Result<DoLogic> {
+        let register = registers::get_do_logic_register(output)
+            .ok_or(DsyrsError::InvalidDigitalOutput(output))?;
+        DoLogic::try_from(self.read_register(register)?)
+    }
+
+    /// Drive a digital output (DO1-DO2) as a "motor is moving" signal
+    ///
+    /// Sets the running threshold (P05.16) and assigns the motor rotation output
+    /// (FunOUT.4) to the given DO.
+    pub fn configure_motion_output(&mut self, output: u8, threshold_rpm: u16) -> Result<()> {
+        if registers::get_do_function_register(output).is_none() {
+            return Err(DsyrsError::InvalidDigitalOutput(output));
+        }
+        self.set_motor_running_threshold(threshold_rpm)?;
+        self.set_do_function(output, DoFunction::MotorRotationOutputSignal)
+    }
+
+    // ========================================================================
+    // P04 - POSITION CONTROL
+    // ========================================================================
+
+    /// Set position command source (P04.00)
+    pub fn set_position_cmd_source(&mut self, source: PositionCmdSource) -> Result<()> {
+        self.write_register(registers::P04_POSITION_CMD_SOURCE, source.into())
+    }
+
+    /// Get position command source (P04.00)
+    pub fn get_position_cmd_source(&mut self) -> Result<PositionCmdSource> {
+        let value = self.read_register(registers::P04_POSITION_CMD_SOURCE)?;
+        PositionCmdSource::try_from(value)
+    }
+
+    /// Set step amount (P04.02, -9999 to 9999)
+    pub fn set_step_amount(&mut self, amount: i16) -> Result<()> {
+        self.write_register(registers::P04_STEP_AMOUNT, amount as u16)
+    }
+
+    /// Set electronic gear ratio (P04.07/P04.09), reduced to lowest terms
+    ///
+    /// The ratio is reduced first (see [`GearRatio::new`]), e.g. 10000/5000 is
+    /// written as 2/1, since the drive has limited internal precision. Zero
+    /// terms are rejected. Use `set_gear_ratio_raw()` to write the terms as
+    /// given, or `verify_gear_ratio()` to also check the read-back.
     pub fn set_gear_ratio(&mut self, numerator: u32, denominator: u32) -> Result<()> {
-        self.write_u32(registers::P04_GEAR1_NUMERATOR, numerator)?;
-        self.write_u32(registers::P04_GEAR1_DENOMINATOR, denominator)
+        let ratio = GearRatio::new(numerator, denominator)?;
+        self.set_gear_ratio_raw(Gear::Gear1, ratio.numerator, ratio.denominator)
+    }
+
+    /// Set electronic gear ratio 2 (P04.11/P04.13), reduced to lowest terms
+    ///
+    /// Same rules as `set_gear_ratio()`. Gear 2 applies while the
+    /// ElectronicGearSelection input is active, see `select_gear()`.
+    pub fn set_gear_ratio_2(&mut self, numerator: u32, denominator: u32) -> Result<()> {
+        let ratio = GearRatio::new(numerator, denominator)?;
+        self.set_gear_ratio_raw(Gear::Gear2, ratio.numerator, ratio.denominator)
+    }
+
+    /// Set the ratio of `gear` (P04.07/P04.09 or P04.11/P04.13) with the terms as given
+    ///
+    /// No reduction is applied; a ratio that is not in lowest terms is logged
+    /// as a warning. Both terms must be 1-1073741824 (2^30).
+    pub fn set_gear_ratio_raw(
+        &mut self,
+        gear: Gear,
+        numerator: u32,
+        denominator: u32,
+    ) -> Result<()> {
+        let terms = 1..=GearRatio::MAX_TERM;
+        if !terms.contains(&numerator) || !terms.contains(&denominator) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "{:?} ratio terms must be 1-1073741824",
+                gear
+            )));
+        }
+        let ratio = GearRatio {
+            numerator,
+            denominator,
+        };
+        if !ratio.is_reduced() {
+            log_warn!(
+                "Gear ratio {}/{} is not reduced; set_gear_ratio() reduces it",
+                numerator,
+                denominator
+            );
+        }
+        let (numerator_register, denominator_register) = match gear {
+            Gear::Gear1 => (
+                registers::P04_GEAR1_NUMERATOR,
+                registers::P04_GEAR1_DENOMINATOR,
+            ),
+            Gear::Gear2 => (
+                registers::P04_GEAR2_NUMERATOR,
+                registers::P04_GEAR2_DENOMINATOR,
+            ),
+        };
+        self.write_u32(numerator_register, numerator)?;
+        self.write_u32(denominator_register, denominator)
+    }
+
+    /// Select the active electronic gear by forcing the ElectronicGearSelection input
+    ///
+    /// Uses the forced-DI mechanism (P11.10/P11.11) like `enable()`, with the
+    /// same caveat: while DI forcing is on, the physical DI terminals are
+    /// ignored. The gear selection input (FunIN.23) must be assigned to a DI.
+    pub fn select_gear(&mut self, gear: Gear) -> Result<()> {
+        self.force_di_function(DiFunction::ElectronicGearSelection, gear == Gear::Gear2)
+    }
+
+    /// Read electronic gear ratio 1 (P04.07/P04.09) as stored, without reduction
+    pub fn read_gear_ratio(&mut self) -> Result<GearRatio> {
+        let numerator = self.read_u32(registers::P04_GEAR1_NUMERATOR)?;
+        let denominator = self.read_u32(registers::P04_GEAR1_DENOMINATOR)?;
+        Ok(GearRatio {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Read electronic gear ratio 2 (P04.11/P04.13) as stored, without reduction
+    pub fn read_gear_ratio_2(&mut self) -> Result<GearRatio> {
+        let numerator = self.read_u32(registers::P04_GEAR2_NUMERATOR)?;
+        let denominator = self.read_u32(registers::P04_GEAR2_DENOMINATOR)?;
+        Ok(GearRatio {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Get electronic gear ratio 1 (P04.07/P04.09) as (numerator, denominator)
+    ///
+    /// Same values as `read_gear_ratio()`, as stored by the drive.
+    pub fn get_gear_ratio(&mut self) -> Result<(u32, u32)> {
+        let ratio = self.read_gear_ratio()?;
+        Ok((ratio.numerator, ratio.denominator))
+    }
+
+    /// Set electronic gear ratio 1 (P04.07/P04.09) and check that the drive kept it
+    ///
+    /// The ratio is reduced to lowest terms first (see [`GearRatio::new`]),
+    /// since the drive has limited internal precision, then written and read
+    /// back. Fails with `OperationFailed` if the read-back differs, e.g. when
+    /// the firmware rejects the ratio. Returns the ratio written. To write the
+    /// terms unreduced, use `set_gear_ratio_raw()` and compare `get_gear_ratio()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let ratio = servo.verify_gear_ratio(131_072, 10_000)?;
+    /// println!("gear ratio {}/{}", ratio.numerator, ratio.denominator);
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn verify_gear_ratio(&mut self, numerator: u32, denominator: u32) -> Result<GearRatio> {
+        let ratio = GearRatio::new(numerator, denominator)?;
+        self.set_gear_ratio(ratio.numerator, ratio.denominator)?;
+        let stored = self.read_gear_ratio()?;
+        if stored != ratio {
+            return Err(DsyrsError::OperationFailed(format!(
+                "Gear ratio {}/{} written but the drive holds {}/{}",
+                ratio.numerator, ratio.denominator, stored.numerator, stored.denominator
+            )));
+        }
+        Ok(ratio)
+    }
+
+    /// Set gear 1 so that one motor revolution equals `user_units_per_motor_rev` units
+    ///
+    /// Reads the encoder resolution (P01.20) and writes the reduced ratio
+    /// resolution / units to gear 1 (P04.07/P04.09). Gear 2 is left untouched.
+    /// E.g. a 5 mm lead screw at 1 µm per unit needs 5000 units per revolution.
+    pub fn set_resolution(&mut self, user_units_per_motor_rev: u32) -> Result<()> {
+        let encoder_resolution = self.get_encoder_resolution()?;
+        let ratio = GearRatio::for_resolution(encoder_resolution, user_units_per_motor_rev)?;
+        self.set_gear_ratio(ratio.numerator, ratio.denominator)
     }
 
     /// Set pulse shape (P04.21)
@@ -396,12 +1367,106 @@ impl DsyrsSyncClient {
         self.write_register(registers::P04_POSITIONING_RANGE, pulses)
     }
 
+    /// Set positioning close range (P04.25, 1-65535 pulses)
+    pub fn set_positioning_close_range(&mut self, pulses: u16) -> Result<()> {
+        if pulses == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "Positioning close range must be 1-65535 pulses".into(),
+            ));
+        }
+        self.write_register(registers::P04_POSITIONING_CLOSE_RANGE, pulses)
+    }
+
+    /// Set the COIN output condition (P04.23)
+    pub fn set_coin_condition(&mut self, condition: CoinCondition) -> Result<()> {
+        self.write_register(registers::P04_COIN_CONDITION, condition.into())
+    }
+
+    /// Get the COIN output condition (P04.23)
+    pub fn get_coin_condition(&mut self) -> Result<CoinCondition> {
+        let value = self.read_register(registers::P04_COIN_CONDITION)?;
+        CoinCondition::try_from(value)
+    }
+
     // ========================================================================
     // P05 - SPEED CONTROL
     // ========================================================================
 
+    /// Set main speed command A source (P05.00)
+    pub fn set_speed_cmd_source(&mut self, source: SpeedCmdSource) -> Result<()> {
+        self.write_register(registers::P05_SPEED_CMD_SOURCE, source.into())
+    }
+
+    /// Get main speed command A source (P05.00)
+    pub fn get_speed_cmd_source(&mut self) -> Result<SpeedCmdSource> {
+        SpeedCmdSource::try_from(self.read_register(registers::P05_SPEED_CMD_SOURCE)?)
+    }
+
+    /// Set auxiliary speed command B source (P05.01)
+    pub fn set_aux_speed_source(&mut self, source: AuxSpeedSource) -> Result<()> {
+        self.write_register(registers::P05_AUX_SPEED_SOURCE, source.into())
+    }
+
+    /// Get auxiliary speed command B source (P05.01)
+    pub fn get_aux_speed_source(&mut self) -> Result<AuxSpeedSource> {
+        let value = self.read_register(registers::P05_AUX_SPEED_SOURCE)?;
+        AuxSpeedSource::try_from(value)
+    }
+
+    /// Set speed command selection (P05.02)
+    ///
+    /// With [`SpeedCmdSelect::ABSwitch`], the MainAuxiliaryCommandSwitch input
+    /// (FunIN.4) switches between the A and B commands, e.g. two setpoints or
+    /// the keyboard value and the multi-speed profile.
+    pub fn set_speed_cmd_select(&mut self, select: SpeedCmdSelect) -> Result<()> {
+        self.write_register(registers::P05_SPEED_CMD_SELECT, select.into())
+    }
+
+    /// Get speed command selection (P05.02)
+    pub fn get_speed_cmd_select(&mut self) -> Result<SpeedCmdSelect> {
+        SpeedCmdSelect::try_from(self.read_register(registers::P05_SPEED_CMD_SELECT)?)
+    }
+
+    /// Route the speed command to the P14 multi-speed profile
+    ///
+    /// Sets the auxiliary source B to multi-speed command (P05.01 = 3) and
+    /// selects B as the speed command (P05.02 = 2).
+    pub fn use_multi_speed(&mut self) -> Result<()> {
+        self.check_estop()?;
+        self.set_aux_speed_source(AuxSpeedSource::MultiSpeedCommand)?;
+        self.set_speed_cmd_select(SpeedCmdSelect::B)
+    }
+
+    /// Set motor running signal threshold (P05.16, 0-1000 rpm)
+    ///
+    /// Above this speed the motor rotation output (FunOUT.4) is active.
+    pub fn set_motor_running_threshold(&mut self, rpm: u16) -> Result<()> {
+        if rpm > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Running threshold must be 0-1000 rpm".into(),
+            ));
+        }
+        self.write_register(registers::P05_RUNNING_THRESHOLD, rpm)
+    }
+
+    /// Get motor running signal threshold (P05.16, rpm)
+    pub fn get_motor_running_threshold(&mut self) -> Result<u16> {
+        self.read_register(registers::P05_RUNNING_THRESHOLD)
+    }
+
     /// Set speed command (P05.03, -9000 to 9000 rpm)
+    ///
+    /// Rejects commands above the lower of 9000 rpm and the configured maximum
+    /// speed (`ServoConfig::max_speed`).
     pub fn set_speed_command(&mut self, rpm: i16) -> Result<()> {
+        self.check_estop()?;
+        let limit = self.config.max_speed.min(9000);
+        if rpm.unsigned_abs() > limit {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Speed command {} rpm exceeds the allowed ±{} rpm",
+                rpm, limit
+            )));
+        }
         self.write_register(registers::P05_SPEED_COMMAND, rpm as u16)
     }
 
@@ -442,25 +1507,399 @@ impl DsyrsSyncClient {
         self.set_decel_time(config.decel_time)
     }
 
+    /// Jog for `duration` by forcing the ForwardJog/BackwardJog input (FunIN.17/18)
+    ///
+    /// Forces the jog input active through P11.10/P11.11, polls P18.00 while
+    /// waiting, then releases the input and restores P11.10 if DI forcing was
+    /// turned on for the jog. If the servo faults mid-jog, the input is
+    /// released and the fault returned. Jog speed and ramps come from
+    /// `apply_jog_config()`.
+    ///
+    /// The jog input must be assigned to a DI, and the servo enabled. While DI
+    /// forcing is on, every DI follows P11.11: a servo enabled through a wired
+    /// input is disabled by the forcing, so use `enable()` beforehand.
+    pub fn jog(&mut self, direction: JogDirection, duration: Duration) -> Result<()> {
+        self.check_estop()?;
+        let function = DiFunction::from(direction);
+        let forcing_before = self.forced_dido_restore;
+        self.force_di_function(function, true)?;
+
+        let result = self.jog_wait(duration);
+
+        // Always release the input, even if the wait failed
+        let release = self.force_di_function(function, false);
+        let restore = self.restore_forcing(forcing_before);
+        result.and(release).and(restore)
+    }
+
+    /// Wait out a jog, failing early if the servo faults
+    fn jog_wait(&mut self, duration: Duration) -> Result<()> {
+        let deadline = Instant::now() + duration;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+            thread::sleep(POLL_INTERVAL.min(deadline - now));
+            let word = self.get_status_word()?;
+            if word.fault {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Servo fault during jog: {}",
+                    FaultCode(word.code.unwrap_or(0))
+                )));
+            }
+        }
+    }
+
+    /// Apply speed output signal thresholds (P05.15-P05.18, P05.20)
+    pub fn apply_speed_signal_config(&mut self, config: &SpeedSignalConfig) -> Result<()> {
+        config.validate()?;
+        self.write_registers(
+            registers::P05_ZERO_SPEED_VALUE,
+            &[
+                config.zero_fixed_speed,
+                config.running_threshold,
+                config.uniform_width,
+                config.reached_value,
+            ],
+        )?;
+        self.write_register(
+            registers::P05_ZERO_SPEED_THRESHOLD,
+            config.zero_speed_threshold,
+        )
+    }
+
+    /// Read speed output signal thresholds (P05.15-P05.18, P05.20)
+    ///
+    /// Values outside their documented range are returned as read and logged.
+    pub fn read_speed_signal_config(&mut self) -> Result<SpeedSignalConfig> {
+        // P05.19 is not documented, so P05.20 is read separately
+        let regs = self.read_registers(registers::P05_ZERO_SPEED_VALUE, 4)?;
+        let zero_speed_threshold = self.read_register(registers::P05_ZERO_SPEED_THRESHOLD)?;
+        let config = SpeedSignalConfig {
+            zero_fixed_speed: regs[0],
+            running_threshold: regs[1],
+            uniform_width: regs[2],
+            reached_value: regs[3],
+            zero_speed_threshold,
+        };
+        if let Err(e) = config.validate() {
+            log_warn!("Speed signal config read back out of range: {}", e);
+        }
+        Ok(config)
+    }
+
+    /// Set the zero fixed speed (P05.15, 0-6000 rpm)
+    ///
+    /// While the zero fixed input (FunIN.11) is active in speed mode and the
+    /// speed command is within this value, the motor is held at zero speed,
+    /// see `enable_zero_clamp()`.
+    pub fn set_zero_speed_clamp(&mut self, rpm: u16) -> Result<()> {
+        if rpm > 6000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Zero fixed speed must be 0-6000 rpm".into(),
+            ));
+        }
+        self.write_register(registers::P05_ZERO_SPEED_VALUE, rpm)
+    }
+
+    /// Set the zero-speed judgment threshold (P05.20, 0-6000 rpm)
+    pub fn set_zero_speed_threshold(&mut self, rpm: u16) -> Result<()> {
+        if rpm > 6000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Zero-speed threshold must be 0-6000 rpm".into(),
+            ));
+        }
+        self.write_register(registers::P05_ZERO_SPEED_THRESHOLD, rpm)
+    }
+
+    /// Activate or release the zero fixed input (FunIN.11) by forcing its DI
+    ///
+    /// Works like `enable()`: FunIN.11 must be assigned to a DI with level
+    /// logic, and DI forcing (P11.10) is turned on to activate it.
+    pub fn enable_zero_clamp(&mut self, enabled: bool) -> Result<()> {
+        self.force_di_function(DiFunction::ZeroFixedEnable, enabled)
+    }
+
+    /// Check the zero-speed condition (FunOUT.5)
+    ///
+    /// The DO terminal levels cannot be read over Modbus (see
+    /// `read_forced_do()`), so this evaluates the signal as the drive does:
+    /// the speed feedback (P18.01) is within the zero-speed threshold (P05.20).
+    /// See [`SpeedSignalConfig::is_zero_speed`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.set_zero_speed_threshold(20)?;
+    /// if servo.is_at_zero_speed()? {
+    ///     println!("motor stopped");
+    /// }
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn is_at_zero_speed(&mut self) -> Result<bool> {
+        let threshold = self.read_register(registers::P05_ZERO_SPEED_THRESHOLD)?;
+        let speed = self.get_speed()?;
+        Ok(SpeedSignalConfig::default()
+            .with_zero_speed_threshold(threshold)
+            .is_zero_speed(speed))
+    }
+
+    /// Command a speed step and record the realized acceleration
+    ///
+    /// Requires speed control mode (P00.00). Sets the speed command (P05.03)
+    /// to `target_rpm`, then samples the speed feedback (P18.01) every 10 ms.
+    /// The target counts as reached within the speed uniform width (P05.17);
+    /// sampling then goes on for a 200 ms settle window to catch overshoot.
+    /// Fails with `Timeout` if the target is not reached within `timeout`.
+    /// The speed command is left at `target_rpm`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{ControlMode, DsyrsSyncClient, ServoConfig};
+    /// use std::time::Duration;
+    ///
+    /// let config = ServoConfig::new(1).with_control_mode(ControlMode::Speed);
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, config)?;
+    /// servo.init()?;
+    /// let profile = servo.measure_acceleration(1000, Duration::from_secs(2))?;
+    /// println!("{:?} to target, {} rpm overshoot", profile.time_to_target, profile.overshoot_rpm);
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn measure_acceleration(
+        &mut self,
+        target_rpm: i16,
+        timeout: Duration,
+    ) -> Result<AccelProfile> {
+        let mode = self.get_control_mode()?;
+        if mode != ControlMode::Speed {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Acceleration measurement requires speed control mode (P00.00), drive is in {:?} mode",
+                mode
+            )));
+        }
+        let accel_time_ms = self.read_register(registers::P05_ACCEL_TIME)?;
+        let tolerance = self.read_register(registers::P05_SPEED_UNIFORM_WIDTH)?;
+        self.set_speed_command(target_rpm)?;
+        let start = Instant::now();
+        let mut samples = Vec::new();
+        let mut time_to_target = None;
+        let mut next = start;
+        loop {
+            let speed = self.get_speed()?;
+            let elapsed = start.elapsed();
+            samples.push((elapsed, speed));
+            if time_to_target.is_none()
+                && (i32::from(speed) - i32::from(target_rpm)).unsigned_abs() <= u32::from(tolerance)
+            {
+                time_to_target = Some(elapsed);
+            }
+            match time_to_target {
+                Some(reached) if elapsed >= reached + ACCEL_SETTLE_WINDOW => break,
+                None if elapsed >= timeout => return Err(DsyrsError::Timeout),
+                _ => {}
+            }
+            next += POLL_INTERVAL;
+            thread::sleep(next.saturating_duration_since(Instant::now()));
+        }
+        let initial = samples[0].1;
+        let overshoot = samples
+            .iter()
+            .map(|&(_, speed)| {
+                let past = i32::from(speed) - i32::from(target_rpm);
+                if target_rpm >= initial {
+                    past
+                } else {
+                    -past
+                }
+            })
+            .max()
+            .unwrap_or(0)
+            .clamp(0, i16::MAX as i32) as i16;
+        Ok(AccelProfile {
+            time_to_target: time_to_target.unwrap_or(timeout),
+            overshoot_rpm: overshoot,
+            accel_time_ms,
+            samples,
+        })
+    }
+
+    /// Wait until the speed feedback (P18.01) is within `tolerance` rpm of `target`
+    ///
+    /// A `tolerance` of 0 uses the speed uniform width (P05.17), the band the
+    /// drive uses for its speed uniform output. Polls P18.00-P18.01 every
+    /// 10 ms. Fails with `OperationFailed` on a fault, or with `Timeout`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    /// use std::time::Duration;
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.set_speed_command(1000)?;
+    /// servo.wait_for_speed(1000, 0, Duration::from_secs(2))?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn wait_for_speed(&mut self, target: i16, tolerance: u16, timeout: Duration) -> Result<()> {
+        let tolerance = match tolerance {
+            0 => self.read_register(registers::P05_SPEED_UNIFORM_WIDTH)?,
+            tolerance => tolerance,
+        };
+        let deadline = Instant::now() + timeout;
+        loop {
+            let regs = self.read_registers(registers::P18_SERVO_STATUS, 2)?;
+            if let Some(fault) = FaultCode::from_status(regs[0]) {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Fault {} while waiting for speed",
+                    fault
+                )));
+            }
+            let speed = regs[1] as i16;
+            if (i32::from(speed) - i32::from(target)).unsigned_abs() <= u32::from(tolerance) {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(DsyrsError::Timeout);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
     // ========================================================================
     // P06 - TORQUE CONTROL
     // ========================================================================
 
     /// Set torque command (P06.05, -3000 to 3000, unit: 0.1% of rated)
     pub fn set_torque_command(&mut self, torque: i16) -> Result<()> {
+        self.check_estop()?;
+        if torque.unsigned_abs() > 3000 {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Torque command {} exceeds the allowed ±3000 (0.1% of rated)",
+                torque
+            )));
+        }
         self.write_register(registers::P06_TORQUE_COMMAND, torque as u16)
     }
 
     /// Set forward torque limit (P06.08, 0-5000, unit: 0.1%)
     pub fn set_forward_torque_limit(&mut self, limit: u16) -> Result<()> {
+        check_torque_limit(limit)?;
         self.write_register(registers::P06_FORWARD_TORQUE_LIMIT, limit)
     }
 
     /// Set backward torque limit (P06.09, 0-5000, unit: 0.1%)
     pub fn set_backward_torque_limit(&mut self, limit: u16) -> Result<()> {
+        check_torque_limit(limit)?;
         self.write_register(registers::P06_BACKWARD_TORQUE_LIMIT, limit)
     }
 
+    /// Set torque limit source (P06.06)
+    pub fn set_torque_limit_source(&mut self, source: TorqueLimitSource) -> Result<()> {
+        self.write_register(registers::P06_TORQUE_LIMIT_SOURCE, source.into())
+    }
+
+    /// Set forward external torque limit (P06.10, 0-5000, unit: 0.1%)
+    ///
+    /// Applies while the P_CL input (FunIN.15) is active and P06.06 selects
+    /// external limits.
+    pub fn set_forward_external_torque_limit(&mut self, limit: u16) -> Result<()> {
+        check_torque_limit(limit)?;
+        self.write_register(registers::P06_FORWARD_EXT_TORQUE_LIMIT, limit)
+    }
+
+    /// Set backward external torque limit (P06.11, 0-5000, unit: 0.1%)
+    ///
+    /// Applies while the N_CL input (FunIN.16) is active and P06.06 selects
+    /// external limits.
+    pub fn set_backward_external_torque_limit(&mut self, limit: u16) -> Result<()> {
+        check_torque_limit(limit)?;
+        self.write_register(registers::P06_BACKWARD_EXT_TORQUE_LIMIT, limit)
+    }
+
+    /// Write the torque limit source and the four torque limits (P06.06, P06.08-P06.11)
+    ///
+    /// The limits are validated first and written in one request, so the
+    /// ceilings can be swapped at runtime (e.g. gripping vs. free move).
+    pub fn apply_torque_limits(&mut self, limits: &TorqueLimits) -> Result<()> {
+        limits.validate()?;
+        self.set_torque_limit_source(limits.source)?;
+        self.write_registers(
+            registers::P06_FORWARD_TORQUE_LIMIT,
+            &[
+                limits.forward_internal,
+                limits.backward_internal,
+                limits.forward_external,
+                limits.backward_external,
+            ],
+        )
+    }
+
+    /// Set speed limit source in torque control (P06.13)
+    pub fn set_torque_speed_limit_source(&mut self, source: TorqueSpeedLimitSource) -> Result<()> {
+        self.write_register(registers::P06_SPEED_LIMIT_SOURCE, source.into())
+    }
+
+    /// Set positive speed limit in torque control (P06.15, 0-9000 rpm)
+    pub fn set_positive_speed_limit(&mut self, rpm: u16) -> Result<()> {
+        TorqueModeConfig::check_speed_limit(rpm)?;
+        self.write_register(registers::P06_POSITIVE_SPEED_LIMIT, rpm)
+    }
+
+    /// Set negative speed limit in torque control (P06.16, 0-9000 rpm)
+    pub fn set_negative_speed_limit(&mut self, rpm: u16) -> Result<()> {
+        TorqueModeConfig::check_speed_limit(rpm)?;
+        self.write_register(registers::P06_NEGATIVE_SPEED_LIMIT, rpm)
+    }
+
+    /// Write the torque mode speed ceiling (P06.13, P06.15-P06.16)
+    ///
+    /// Set this before switching to torque mode: without a load, the motor
+    /// otherwise accelerates up to these limits.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig, TorqueModeConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.apply_torque_mode_config(&TorqueModeConfig::new(1500, 1500))?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn apply_torque_mode_config(&mut self, config: &TorqueModeConfig) -> Result<()> {
+        config.validate()?;
+        self.set_torque_speed_limit_source(config.speed_limit_source)?;
+        self.write_registers(
+            registers::P06_POSITIVE_SPEED_LIMIT,
+            &[config.positive_speed_limit, config.negative_speed_limit],
+        )
+    }
+
+    /// Read the complete torque configuration (P06.02-P06.16)
+    ///
+    /// Reads around the undocumented P06.03, P06.07, P06.12 and P06.14.
+    pub fn read_torque_config(&mut self) -> Result<TorqueConfig> {
+        let select = self.read_register(registers::P06_TORQUE_CMD_SELECT)?;
+        let cmd = self.read_registers(registers::P06_TORQUE_FILTER, 3)?;
+        let limits = self.read_registers(registers::P06_FORWARD_TORQUE_LIMIT, 4)?;
+        let speed_source = self.read_register(registers::P06_SPEED_LIMIT_SOURCE)?;
+        let speed = self.read_registers(registers::P06_POSITIVE_SPEED_LIMIT, 2)?;
+
+        Ok(TorqueConfig {
+            selection: TorqueCmdSelect::try_from(select)?,
+            filter_ms: cmd[0] as f32 * 0.01,
+            command: cmd[1] as i16 as f32 * 0.1,
+            limit_source: TorqueLimitSource::try_from(cmd[2])?,
+            forward_internal_limit: limits[0] as f32 * 0.1,
+            backward_internal_limit: limits[1] as f32 * 0.1,
+            forward_external_limit: limits[2] as f32 * 0.1,
+            backward_external_limit: limits[3] as f32 * 0.1,
+            speed_limit_source: TorqueSpeedLimitSource::try_from(speed_source)?,
+            positive_speed_limit: speed[0],
+            negative_speed_limit: speed[1],
+        })
+    }
+
     // ========================================================================
     // P07 - GAIN PARAMETERS
     // ========================================================================
@@ -480,12 +1919,256 @@ impl DsyrsSyncClient {
         self.write_register(registers::P07_SPEED_INTEGRAL1, time)
     }
 
+    /// Set position loop gain 2 (P07.05, 10-20000, unit: 0.1 Hz)
+    pub fn set_position_gain_2(&mut self, gain: u16) -> Result<()> {
+        self.write_register(registers::P07_POSITION_GAIN2, gain)
+    }
+
+    /// Set speed loop gain 2 (P07.06, 10-20000, unit: 0.1 Hz)
+    pub fn set_speed_gain_2(&mut self, gain: u16) -> Result<()> {
+        self.write_register(registers::P07_SPEED_GAIN2, gain)
+    }
+
+    /// Set gain switching action (P07.10)
+    pub fn set_gain_switch_action(&mut self, action: GainSwitchAction) -> Result<()> {
+        self.write_register(registers::P07_GAINSWITCH_ACTION, action.into())
+    }
+
+    /// Set gain switching mode (P07.11, 0-11 or 13)
+    ///
+    /// Selects when gain set 2 is used while P07.10 is Gain1/Gain2 switching:
+    /// 0/1 fix set 1/2, 2 follows a DI and 3-10 switch on torque, speed or
+    /// position deviation conditions (see the manual for each mode). 12 is
+    /// not a valid setting and is refused.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// // Switch to gain set 2 through a DI
+    /// servo.set_gain_switch_mode(2)?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn set_gain_switch_mode(&mut self, mode: u8) -> Result<()> {
+        if mode > 13 || mode == 12 {
+            return Err(DsyrsError::InvalidParameter(
+                "Gain switch mode must be 0-11 or 13".into(),
+            ));
+        }
+        self.write_register(registers::P07_GAIN_SWITCH_MODE, mode as u16)
+    }
+
+    /// Switch to the given gain set immediately (P07.10/P07.11)
+    ///
+    /// This is a direct, host-commanded swap: P07.10 is put in Gain1/Gain2
+    /// switching mode and P07.11 is forced to "Gain1 fixed" or "Gain2 fixed".
+    /// It differs from the automatic switching conditions of P07.11 (modes 2-13,
+    /// driven by a DI or by torque/speed/deviation thresholds), which it overrides
+    /// until the switching mode is changed again.
+    pub fn switch_gain_group(&mut self, group: GainGroup) -> Result<()> {
+        // Gain1/Gain2 switching is required for P07.11 to select a set
+        self.set_gain_switch_action(GainSwitchAction::Gain1Gain2)?;
+        self.write_register(registers::P07_GAIN_SWITCH_MODE, group.into())
+    }
+
     /// Apply gain parameters
+    ///
+    /// Writes gain set 1 (P07.00-P07.03), then gain set 2 (P07.05/P07.06)
+    /// values that are present.
     pub fn apply_gain_params(&mut self, params: &GainParams) -> Result<()> {
         self.set_position_gain(params.position_gain)?;
         self.set_speed_gain(params.speed_gain)?;
         self.set_speed_integral(params.speed_integral)?;
-        self.write_register(registers::P07_SPEED_FILTER1, params.speed_filter)
+        self.write_register(registers::P07_SPEED_FILTER1, params.speed_filter)?;
+        if let Some(gain) = params.position_gain_2 {
+            self.set_position_gain_2(gain)?;
+        }
+        if let Some(gain) = params.speed_gain_2 {
+            self.set_speed_gain_2(gain)?;
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // P08 - ADVANCED ADJUSTMENT
+    // ========================================================================
+
+    /// Read notch filter parameters (P08.02-P08.04 for notch 1)
+    ///
+    /// Only notch 1 is documented. Values outside their documented range are
+    /// returned as read and logged.
+    pub fn read_notch_filter(&mut self, index: u8) -> Result<NotchFilter> {
+        let register = registers::get_notch_filter_register(index).ok_or_else(|| {
+            DsyrsError::InvalidParameter(format!("Invalid notch filter: {}. Must be 1", index))
+        })?;
+        let regs = self.read_registers(register, 3)?;
+        let filter = NotchFilter {
+            frequency: regs[0],
+            width: regs[1],
+            depth: regs[2],
+        };
+        if let Err(e) = filter.validate() {
+            log_warn!("Notch filter {} read back out of range: {}", index, e);
+        }
+        Ok(filter)
+    }
+
+    /// Write the 1st notch filter (P08.02-P08.04)
+    ///
+    /// The filter is validated before anything is written.
+    pub fn set_notch_filter(&mut self, filter: &NotchFilter) -> Result<()> {
+        filter.validate()?;
+        self.write_registers(
+            registers::P08_NOTCH1_FREQUENCY,
+            &[filter.frequency, filter.width, filter.depth],
+        )
+    }
+
+    /// Disable the 1st notch filter by setting its depth to 0 (P08.04)
+    pub fn disable_notch_filter(&mut self) -> Result<()> {
+        self.write_register(registers::P08_NOTCH1_DEPTH, 0)
+    }
+
+    /// Set adaptive filter mode (P08.00, 0-5)
+    pub fn set_adaptive_filter_mode(&mut self, mode: u8) -> Result<()> {
+        if mode > 5 {
+            return Err(DsyrsError::InvalidParameter(
+                "Adaptive filter mode must be 0-5".into(),
+            ));
+        }
+        self.write_register(registers::P08_ADAPTIVE_FILTER_MODE, mode as u16)
+    }
+
+    /// Read adaptive filter mode (P08.00)
+    pub fn get_adaptive_filter_mode(&mut self) -> Result<u8> {
+        let value = self.read_register(registers::P08_ADAPTIVE_FILTER_MODE)?;
+        if value > 5 {
+            log_warn!("Adaptive filter mode read back out of range: {}", value);
+        }
+        Ok(value.min(u8::MAX as u16) as u8)
+    }
+
+    /// Run inertia identification and return the identified inertia ratio
+    ///
+    /// Selects the identification mode (P08.23), starts identification through
+    /// P11.03 and waits for it to end, then reads the result back from P00.05
+    /// (unit: 0.01). The manual documents no completion flag: the drive is
+    /// assumed to clear P11.03 back to 0 when identification finishes, as for
+    /// the other "Enter to execute" auxiliary functions.
+    ///
+    /// The servo must be enabled (`ServoNotReady` otherwise). Fails as soon as
+    /// the servo faults, and returns `Timeout` once `timeout` elapses.
+    pub fn identify_inertia(&mut self, mode: InertiaIdMode, timeout: Duration) -> Result<u16> {
+        if !self.is_enabled()? {
+            return Err(DsyrsError::ServoNotReady);
+        }
+        self.write_register(registers::P08_INERTIA_ID_MODE, mode.into())?;
+        self.write_register(registers::P11_INERTIA_RECOGNITION, 1)?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            if let Some(fault) = self.get_fault_code()? {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Fault {} during inertia identification",
+                    fault
+                )));
+            }
+            if self.read_register(registers::P11_INERTIA_RECOGNITION)? == 0 {
+                break;
+            }
+            if Instant::now() >= deadline {
+                return Err(DsyrsError::Timeout);
+            }
+        }
+        self.read_register(registers::P00_INERTIA_RATIO)
+    }
+
+    // ========================================================================
+    // P09 - FAULT AND PROTECTION
+    // ========================================================================
+
+    /// Set overload warning value (P09.05, 1-100 %)
+    pub fn set_overload_warning(&mut self, percent: u8) -> Result<()> {
+        if !(1..=100).contains(&percent) {
+            return Err(DsyrsError::InvalidParameter(
+                "Overload warning value must be 1-100 %".into(),
+            ));
+        }
+        self.write_register(registers::P09_OVERLOAD_WARNING, percent as u16)
+    }
+
+    /// Set motor overload factor (P09.06, 10-300 %)
+    pub fn set_motor_overload_factor(&mut self, percent: u16) -> Result<()> {
+        if !(10..=300).contains(&percent) {
+            return Err(DsyrsError::InvalidParameter(
+                "Motor overload factor must be 10-300 %".into(),
+            ));
+        }
+        self.write_register(registers::P09_MOTOR_OVERLOAD_FACTOR, percent)
+    }
+
+    /// Set position deviation excessive threshold (P09.09, 32-bit, 1-1073741824 pulses)
+    pub fn set_position_deviation_threshold(&mut self, pulses: u32) -> Result<()> {
+        if !(1..=1 << 30).contains(&pulses) {
+            return Err(DsyrsError::InvalidParameter(
+                "Position deviation threshold must be 1-1073741824 pulses".into(),
+            ));
+        }
+        self.write_u32(registers::P09_POSITION_DEVIATION_THRESHOLD, pulses)
+    }
+
+    /// Get position deviation excessive threshold (P09.09, pulses)
+    ///
+    /// Only the threshold can be read: the manual documents no P18 monitor
+    /// for the position deviation itself (P18 ends at P18.09), so how close
+    /// the axis runs to this limit cannot be observed over Modbus.
+    pub fn get_position_deviation_threshold(&mut self) -> Result<u32> {
+        self.read_u32(registers::P09_POSITION_DEVIATION_THRESHOLD)
+    }
+
+    /// Apply protection thresholds (P09.05, P09.06, P09.08, P09.09)
+    ///
+    /// The whole configuration is validated before anything is written.
+    pub fn apply_protection_config(&mut self, config: &ProtectionConfig) -> Result<()> {
+        config.validate()?;
+        self.set_overload_warning(config.overload_warning)?;
+        self.set_motor_overload_factor(config.motor_overload_factor)?;
+        self.set_overspeed_point(config.overspeed_point)?;
+        if let Some(pulses) = config.position_deviation_threshold {
+            self.set_position_deviation_threshold(pulses)?;
+        }
+        Ok(())
+    }
+
+    /// Set overspeed fault point (P09.08, 50-120 % of system max speed P00.07)
+    ///
+    /// The trip speed scales with P00.07, so changing the max speed also moves
+    /// the overspeed trip; see `overspeed_trip_rpm()`.
+    pub fn set_overspeed_point(&mut self, percent: u8) -> Result<()> {
+        if !(50..=120).contains(&percent) {
+            return Err(DsyrsError::InvalidParameter(
+                "Overspeed point must be 50-120 %".into(),
+            ));
+        }
+        self.write_register(registers::P09_OVERSPEED_POINT, percent as u16)
+    }
+
+    /// Get overspeed fault point (P09.08, % of system max speed)
+    pub fn get_overspeed_point(&mut self) -> Result<u8> {
+        let value = self.read_register(registers::P09_OVERSPEED_POINT)?;
+        if !(50..=120).contains(&value) {
+            log_warn!("Overspeed point read back out of range: {} %", value);
+        }
+        Ok(value.min(u8::MAX as u16) as u8)
+    }
+
+    /// Compute the absolute overspeed trip speed (rpm) from P00.07 and P09.08
+    pub fn overspeed_trip_rpm(&mut self) -> Result<u16> {
+        let max_speed = self.read_register(registers::P00_MAX_SPEED)? as u32;
+        let percent = self.get_overspeed_point()? as u32;
+        Ok((max_speed * percent / 100) as u16)
     }
 
     // ========================================================================
@@ -498,10 +2181,18 @@ impl DsyrsSyncClient {
     }
 
     /// Set Modbus baud rate (P10.02)
+    ///
+    /// The client keeps talking at the old rate; use `change_baud_rate()` to
+    /// switch the drive and reconnect in one step.
     pub fn set_baud_rate(&mut self, baud: BaudRate) -> Result<()> {
         self.write_register(registers::P10_MODBUS_BAUDRATE, baud.into())
     }
 
+    /// Get Modbus baud rate (P10.02)
+    pub fn get_baud_rate(&mut self) -> Result<BaudRate> {
+        BaudRate::try_from(self.read_register(registers::P10_MODBUS_BAUDRATE)?)
+    }
+
     /// Set Modbus data format (P10.03)
     pub fn set_data_format(&mut self, format: DataFormat) -> Result<()> {
         self.write_register(registers::P10_MODBUS_FORMAT, format.into())
@@ -523,6 +2214,20 @@ impl DsyrsSyncClient {
         )
     }
 
+    /// Read the current communication configuration (P10.00-P10.03, P10.06)
+    pub fn get_comm_config(&mut self) -> Result<CommConfig> {
+        // P10.01 is not documented, so the address is read on its own
+        let address = self.read_register(registers::P10_COMM_ADDRESS)?;
+        let serial = self.read_registers(registers::P10_MODBUS_BAUDRATE, 2)?;
+        let address_source = self.read_register(registers::P10_RS485_ADDRESS_SOURCE)?;
+        Ok(CommConfig {
+            address: address as u8,
+            baud_rate: BaudRate::try_from(serial[0])?,
+            data_format: DataFormat::try_from(serial[1])?,
+            address_source: AddressSource::try_from(address_source)?,
+        })
+    }
+
     // ========================================================================
     // P11 - AUXILIARY FUNCTIONS
     // ========================================================================
@@ -538,10 +2243,76 @@ impl DsyrsSyncClient {
     }
 
     /// Factory reset (P11.09)
-    pub fn factory_reset(&mut self) -> Result<()> {
+    ///
+    /// Resets every parameter except P01 & P17, including the P10 communication
+    /// settings. Over RS485 this can leave the drive unreachable after its next
+    /// power cycle if the address, baud rate or data format were customized;
+    /// prefer `factory_reset_preserving_comm()` in that case.
+    ///
+    /// This cannot be undone: tuning, I/O assignments and motion parameters
+    /// are lost unless exported first (see `dump_parameters()`). `confirm`
+    /// must be `true`, otherwise nothing is written and `InvalidParameter` is
+    /// returned.
+    pub fn factory_reset(&mut self, confirm: bool) -> Result<()> {
+        Self::check_factory_reset_confirmed(confirm)?;
         self.write_register(registers::P11_SYSTEM_INIT, SystemInit::FactoryReset.into())
     }
 
+    /// Factory reset (P11.09) that keeps the drive reachable on the bus
+    ///
+    /// Reads the current communication configuration (P10.00-P10.03, P10.06)
+    /// and the RS232 baud rate (P10.05), performs the factory reset, then
+    /// re-applies these settings and saves them to EEPROM, so the drive keeps
+    /// its address, baud rate and data format. Every other parameter is reset
+    /// as with `factory_reset()`, which cannot be undone; `confirm` must be
+    /// `true`.
+    ///
+    /// The settings are only written back once the drive has answered the
+    /// P11.09 write, and they are read back before the EEPROM save: a value
+    /// the reset overwrote afterwards fails with `OperationFailed` before
+    /// anything is saved, so do not power cycle the drive then. The manual
+    /// does not say when P11.09 takes effect; a firmware deferring the reset
+    /// to the next power-up would still lose these settings.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.factory_reset_preserving_comm(true)?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn factory_reset_preserving_comm(&mut self, confirm: bool) -> Result<()> {
+        Self::check_factory_reset_confirmed(confirm)?;
+        let comm = self.get_comm_config()?;
+        let rs232_baud = self.read_register(registers::P10_RS232_BAUDRATE)?;
+        self.factory_reset(true)?;
+        self.apply_comm_config(&comm)?;
+        self.write_register(registers::P10_RS232_BAUDRATE, rs232_baud)?;
+        if self.get_comm_config()? != comm
+            || self.read_register(registers::P10_RS232_BAUDRATE)? != rs232_baud
+        {
+            return Err(DsyrsError::OperationFailed(
+                "Communication settings changed after the factory reset, not saved".into(),
+            ));
+        }
+        self.save_to_eeprom()
+    }
+
+    /// Refuse a factory reset unless `confirm` is set
+    ///
+    /// Shared by `factory_reset()` and `factory_reset_preserving_comm()` so
+    /// both fail the same way before writing anything.
+    fn check_factory_reset_confirmed(confirm: bool) -> Result<()> {
+        if confirm {
+            Ok(())
+        } else {
+            Err(DsyrsError::InvalidParameter(
+                "Factory reset is irreversible and must be confirmed".into(),
+            ))
+        }
+    }
+
     /// Clear fault record (P11.09)
     pub fn clear_fault_record(&mut self) -> Result<()> {
         self.write_register(
@@ -550,67 +2321,690 @@ impl DsyrsSyncClient {
         )
     }
 
+    /// Clear latched encoder warnings (P11.06 = clear warnings/errors)
+    ///
+    /// Acknowledges absolute encoder warnings such as a low battery. Other
+    /// warnings are not latched and clear by themselves once their cause is
+    /// gone; faults are cleared with `reset_fault()`.
+    pub fn clear_warnings(&mut self) -> Result<()> {
+        self.reset_encoder(EncoderReset::ClearWarnings)
+    }
+
     /// Reset absolute encoder (P11.06)
     pub fn reset_encoder(&mut self, reset: EncoderReset) -> Result<()> {
         self.write_register(registers::P11_ENCODER_RESET, reset.into())
     }
 
-    /// Emergency stop (P11.13)
-    pub fn emergency_stop(&mut self) -> Result<()> {
-        self.write_register(registers::P11_EMERGENCY_STOP, 1)
+    /// Reset the absolute encoder (P11.06) and wait for the drive to confirm it
+    ///
+    /// Issues the reset, then a soft reset (P11.02) if `soft_reset` is set, and
+    /// polls P18.00 until no encoder warning (AL.73x) is active. While polling
+    /// after a soft reset, read errors are retried until `timeout`, as the
+    /// drive does not answer while it restarts. For `ResetMultiTurn`, the
+    /// absolute position is then read back and must lie within the first turn
+    /// (see `get_absolute_position_multiturn()`, which needs an absolute
+    /// encoder).
+    ///
+    /// Firmware differs in when a multi-turn reset takes effect: some versions
+    /// apply it at once, others only after a soft reset, and others only after
+    /// a power cycle, reporting AL.941 until then. AL.941 fails immediately,
+    /// since no soft reset clears it: power cycle the drive and check the
+    /// position again. Fails with `OperationFailed` if an encoder warning is
+    /// still active at `timeout` or the turns were not cleared.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, EncoderReset, EncoderType, ServoConfig};
+    /// use std::time::Duration;
+    ///
+    /// let config = ServoConfig::new(1).with_encoder_type(EncoderType::Bit17Absolute);
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, config)?;
+    /// servo.reset_encoder_and_verify(EncoderReset::ResetMultiTurn, true, Duration::from_secs(1))?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn reset_encoder_and_verify(
+        &mut self,
+        reset: EncoderReset,
+        soft_reset: bool,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.reset_encoder(reset)?;
+        if soft_reset {
+            self.soft_reset()?;
+        }
+        let deadline = Instant::now() + timeout;
+        loop {
+            match self.get_warning_code() {
+                Ok(Some(WarningCode::PowerCycleRequired)) => {
+                    return Err(DsyrsError::OperationFailed(
+                        "Encoder reset requires a power cycle (AL.941)".into(),
+                    ));
+                }
+                Ok(Some(warning)) if warning.is_encoder() => {
+                    if Instant::now() >= deadline {
+                        return Err(DsyrsError::OperationFailed(format!(
+                            "Encoder warning {} still active after reset",
+                            warning
+                        )));
+                    }
+                }
+                Ok(_) => break,
+                Err(e) if !soft_reset || Instant::now() >= deadline => return Err(e),
+                Err(e) => log_debug!("Waiting for the drive after soft reset: {}", e),
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        if reset == EncoderReset::ResetMultiTurn {
+            let position = self.get_absolute_position_multiturn()?;
+            if position.turns != 0 {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Multi-turn data not cleared: {} turns after reset",
+                    position.turns
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Emergency stop (P11.13)
+    ///
+    /// Latches the client: `set_speed_command()`, `set_torque_command()`,
+    /// `jog()`, homing, positioning, `enable()`, `force_di_bit()`,
+    /// `download_program()` and `use_multi_speed()` fail with
+    /// [`DsyrsError::EmergencyStopActive`] until `clear_emergency_stop()`.
+    /// `disable()` keeps working. The latch is set even if the write fails.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.emergency_stop()?;
+    /// servo.disable()?;
+    /// // Motion commands are refused until the stop is cleared
+    /// servo.clear_emergency_stop()?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn emergency_stop(&mut self) -> Result<()> {
+        self.estop_latched = true;
+        self.write_register(registers::P11_EMERGENCY_STOP, 1)
+    }
+
+    /// Emergency stop (P11.13), then wait for the motor to stop
+    ///
+    /// Polls the speed feedback (P18.01) until it is within the zero speed
+    /// threshold (P05.20). Fails with `Timeout` if the motor is still turning
+    /// when `timeout` elapses; the emergency stop stays latched either way.
+    pub fn emergency_stop_and_wait(&mut self, timeout: Duration) -> Result<()> {
+        let start = Instant::now();
+        self.emergency_stop()?;
+        let threshold = self.read_register(registers::P05_ZERO_SPEED_THRESHOLD)?;
+        loop {
+            if self.get_speed()?.unsigned_abs() <= threshold {
+                return Ok(());
+            }
+            if start.elapsed() >= timeout {
+                return Err(DsyrsError::Timeout);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Clear emergency stop (P11.13) and release the motion command latch
+    pub fn clear_emergency_stop(&mut self) -> Result<()> {
+        self.write_register(registers::P11_EMERGENCY_STOP, 0)?;
+        self.estop_latched = false;
+        Ok(())
+    }
+
+    /// Whether `emergency_stop()` was called without `clear_emergency_stop()` since
+    pub fn is_emergency_stop_latched(&self) -> bool {
+        self.estop_latched
+    }
+
+    /// Fail motion commands and servo enabling while the emergency stop is latched
+    fn check_estop(&self) -> Result<()> {
+        if self.estop_latched {
+            return Err(DsyrsError::EmergencyStopActive);
+        }
+        Ok(())
+    }
+
+    /// Enable the servo by forcing its ServoEnable (FunIN.1) input (P11.10/P11.11)
+    ///
+    /// Looks up the DI assigned to ServoEnable (P02.01-P02.03) and its logic
+    /// (P02.11-P02.13), sets that input active in the forced DI value (P11.11,
+    /// bit 0 = DI1, 1 = high level) and turns on DI forcing (P11.10) if needed.
+    ///
+    /// While DI forcing is on, every DI terminal follows P11.11 and the physical
+    /// inputs are ignored, including wired limit switches. The drive has no
+    /// documented communication enable, so this is the only software path.
+    pub fn enable(&mut self) -> Result<()> {
+        self.check_estop()?;
+        self.force_di_function(DiFunction::ServoEnable, true)
+    }
+
+    /// Disable the servo through the forced ServoEnable input
+    ///
+    /// Sets the input inactive in P11.11, then restores P11.10 if `enable()`
+    /// turned DI forcing on. The physical DI terminals apply again from then
+    /// on, so a wired ServoEnable input that is active re-enables the servo.
+    pub fn disable(&mut self) -> Result<()> {
+        self.force_di_function(DiFunction::ServoEnable, false)?;
+        if let Some(previous) = self.forced_dido_restore.take() {
+            self.write_register(registers::P11_FORCED_DIDO, previous)?;
+        }
+        Ok(())
+    }
+
+    /// Check whether the servo is enabled (P18.00 in Run state)
+    pub fn is_enabled(&mut self) -> Result<bool> {
+        Ok(self.get_status_word()?.enabled)
+    }
+
+    /// Set the forced level of the DI assigned to `function`, turning on DI forcing to activate it
+    fn force_di_function(&mut self, function: DiFunction, active: bool) -> Result<()> {
+        let functions = self.read_registers(registers::P02_DI1_FUNCTION, 3)?;
+        let input = functions
+            .iter()
+            .position(|&f| f == u16::from(function))
+            .map(|i| i as u8 + 1)
+            .ok_or_else(|| {
+                DsyrsError::OperationFailed(format!("No DI is assigned to {:?}", function))
+            })?;
+        let logic_register = registers::get_di_logic_register(input)
+            .ok_or(DsyrsError::InvalidDigitalInput(input))?;
+        let logic = self.read_register(logic_register)?;
+        let high = if logic == u16::from(DiLogic::LowActive) {
+            !active
+        } else if logic == u16::from(DiLogic::HighActive) {
+            active
+        } else {
+            return Err(DsyrsError::OperationFailed(format!(
+                "DI{} ({:?}) uses edge logic {}, which cannot be forced as a level",
+                input, function, logic
+            )));
+        };
+
+        self.set_register_bit(registers::P11_FORCED_DI_VALUE, input - 1, high)?;
+
+        if active {
+            // P11.10: bit 0 = force DI, bit 1 = force DO
+            let mode = self.read_register(registers::P11_FORCED_DIDO)?;
+            if mode & 1 == 0 {
+                self.write_register(registers::P11_FORCED_DIDO, mode | 1)?;
+                self.forced_dido_restore.get_or_insert(mode);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the forced level of a DI terminal (P11.11, DI1-DI9)
+    ///
+    /// Only this terminal's bit is changed. The level is used by the drive while
+    /// DI forcing is enabled (P11.10 bit 0), which this does not turn on.
+    pub fn force_di_bit(&mut self, terminal: u8, high: bool) -> Result<()> {
+        self.check_estop()?;
+        if !(1..=9).contains(&terminal) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DI terminal: {}. Must be 1-9",
+                terminal
+            )));
+        }
+        self.set_register_bit(registers::P11_FORCED_DI_VALUE, terminal - 1, high)
+    }
+
+    /// Set the forced level of a DO terminal (P11.12, DO1-DO5)
+    ///
+    /// Only this terminal's bit is changed. The level is output while DO
+    /// forcing is enabled (P11.10 bit 1), which this does not turn on.
+    pub fn force_do_bit(&mut self, terminal: u8, high: bool) -> Result<()> {
+        if !(1..=5).contains(&terminal) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DO terminal: {}. Must be 1-5",
+                terminal
+            )));
+        }
+        self.set_register_bit(registers::P11_FORCED_DO_VALUE, terminal - 1, high)
+    }
+
+    /// Force the DO terminals to `mask` (P11.12, bit 0 = DO1) until the guard is released
+    ///
+    /// Writes the levels and turns on DO forcing (P11.10 bit 1), keeping DI
+    /// forcing as it was. The returned guard derefs to the client, and
+    /// restores P11.10 to its previous value (0 unless forcing was already on)
+    /// on `release()`, which reports the result, or on drop. The drop path is
+    /// best effort: a failed write is only logged.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let mut forced = servo.force_outputs(0b0_0001)?;
+    /// forced.force_do_bit(2, true)?;
+    /// forced.release()?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn force_outputs(&mut self, mask: u16) -> Result<SyncForcedIoGuard<'_, T>> {
+        if mask > 0x1F {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DO mask: {:#x}. Must be 0-0x1F",
+                mask
+            )));
+        }
+        let previous = self.read_register(registers::P11_FORCED_DIDO)?;
+        self.write_register(registers::P11_FORCED_DO_VALUE, mask)?;
+        // P11.10: bit 0 = force DI, bit 1 = force DO
+        self.write_register(registers::P11_FORCED_DIDO, previous | 2)?;
+        Ok(SyncForcedIoGuard {
+            client: self,
+            previous: Some(previous),
+        })
+    }
+
+    /// Turn DI forcing back off if it was turned on after `forcing_before` was taken
+    ///
+    /// Used by operations forcing an input only for their own duration.
+    fn restore_forcing(&mut self, forcing_before: Option<u16>) -> Result<()> {
+        match (forcing_before, self.forced_dido_restore) {
+            (None, Some(previous)) => {
+                self.forced_dido_restore = None;
+                self.write_register(registers::P11_FORCED_DIDO, previous)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Read the forced DI levels (P11.11)
+    ///
+    /// These are the levels written by the host, not the wired inputs: the
+    /// manual documents no register reporting the live terminal levels (P02.00
+    /// only holds the state assumed for unassigned functions, and P18 has no
+    /// I/O monitor). While DI forcing is enabled (P11.10 bit 0), the drive
+    /// takes its inputs from P11.11, which is returned here; otherwise this
+    /// fails.
+    pub fn read_forced_di(&mut self) -> Result<DiStates> {
+        let regs = self.read_registers(registers::P11_FORCED_DIDO, 2)?;
+        if regs[0] & 1 == 0 {
+            return Err(DsyrsError::OperationFailed(
+                "Forced DI levels are only in effect while DI forcing is enabled (P11.10)".into(),
+            ));
+        }
+        Ok(DiStates::from(regs[1]))
+    }
+
+    /// Read the forced DO levels (P11.12)
+    ///
+    /// Like `read_forced_di()`: returns the levels written by the host while
+    /// DO forcing is enabled (P11.10 bit 1), and fails otherwise. The levels
+    /// the drive outputs on its own cannot be read over Modbus.
+    pub fn read_forced_do(&mut self) -> Result<DoStates> {
+        let regs = self.read_registers(registers::P11_FORCED_DIDO, 3)?;
+        if regs[0] & 2 == 0 {
+            return Err(DsyrsError::OperationFailed(
+                "Forced DO levels are only in effect while DO forcing is enabled (P11.10)".into(),
+            ));
+        }
+        Ok(DoStates::from(regs[2]))
+    }
+
+    // ========================================================================
+    // P12 - KEYBOARD DISPLAY
+    // ========================================================================
+
+    /// Set front-panel default display code (P12.01, 0-100)
+    ///
+    /// The manual gives only the range, not what each code displays, so the
+    /// code is passed through as is. The factory default is 1.
+    pub fn set_default_display(&mut self, code: u8) -> Result<()> {
+        if code > 100 {
+            return Err(DsyrsError::InvalidParameter(
+                "Display code must be 0-100".into(),
+            ));
+        }
+        self.write_register(registers::P12_DEFAULT_DISPLAY, code as u16)
+    }
+
+    /// Get front-panel default display code (P12.01)
+    pub fn get_default_display(&mut self) -> Result<u8> {
+        let value = self.read_register(registers::P12_DEFAULT_DISPLAY)?;
+        if value > 100 {
+            log_warn!("Default display code read back out of range: {}", value);
+        }
+        Ok(value.min(u8::MAX as u16) as u8)
+    }
+
+    // ========================================================================
+    // P13 - MULTI-SEGMENT POSITION
+    // ========================================================================
+
+    /// Set multi-segment operation mode (P13.00)
+    pub fn set_multi_seg_mode(&mut self, mode: MultiSegOperationMode) -> Result<()> {
+        self.write_register(registers::P13_OPERATION_MODE, mode.into())
+    }
+
+    /// Set multi-segment start segment (P13.01, 1-16)
+    pub fn set_multi_seg_start(&mut self, segment: u8) -> Result<()> {
+        if !(1..=16).contains(&segment) {
+            return Err(DsyrsError::InvalidSegment(segment));
+        }
+        self.write_register(registers::P13_START_SEGMENT, segment as u16)
+    }
+
+    /// Set multi-segment end segment (P13.02, 1-16)
+    pub fn set_multi_seg_end(&mut self, segment: u8) -> Result<()> {
+        if !(1..=16).contains(&segment) {
+            return Err(DsyrsError::InvalidSegment(segment));
+        }
+        self.write_register(registers::P13_END_SEGMENT, segment as u16)
+    }
+
+    /// Set multi-segment position mode (P13.05)
+    pub fn set_multi_seg_position_mode(&mut self, mode: MultiSegPositionMode) -> Result<()> {
+        self.write_register(registers::P13_POSITION_MODE, mode.into())
+    }
+
+    /// Get multi-segment position mode (P13.05)
+    pub fn get_multi_seg_position_mode(&mut self) -> Result<MultiSegPositionMode> {
+        let value = self.read_register(registers::P13_POSITION_MODE)?;
+        MultiSegPositionMode::try_from(value)
+    }
+
+    /// Configure a segment
+    ///
+    /// If the segment has a `position_mode_override`, it must match the global
+    /// P13.05 mode; the drive has no per-segment position mode.
+    pub fn configure_segment(&mut self, config: &SegmentConfig) -> Result<()> {
+        if let Some(mode) = config.position_mode_override {
+            let global = self.get_multi_seg_position_mode()?;
+            if global != mode {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "Segment {} requires {:?} positioning but P13.05 is {:?}",
+                    config.segment, mode, global
+                )));
+            }
+        }
+        let disp_reg = registers::get_segment_displacement_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+        let speed_reg = registers::get_segment_speed_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+        let accel_reg = registers::get_segment_accel_decel_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+        let wait_reg = registers::get_segment_wait_time_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+
+        // Write displacement as 32-bit value, limited to ±(2^30 - 1)
+        self.write_i30(disp_reg, config.displacement)?;
+        self.write_register(speed_reg, config.speed)?;
+        self.write_register(accel_reg, config.accel_decel_time)?;
+        self.write_register(wait_reg, config.wait_time)
+    }
+
+    /// Write a multi-segment program: P13.00-P13.02, P13.05 and every segment
+    ///
+    /// The program is validated first, so nothing is written if it is
+    /// invalid. Segments with consecutive numbers occupy consecutive registers
+    /// and are written together, one multiple-register write per run.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, MotionProgram, SegmentConfig, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let program = MotionProgram::builder(1, 2)
+    ///     .with_segment(SegmentConfig::new(1)?.with_displacement(10_000).with_speed(1500))
+    ///     .with_segment(SegmentConfig::new(2)?.with_displacement(-10_000).with_speed(1500))
+    ///     .build()?;
+    /// servo.download_program(&program)?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn download_program(&mut self, program: &MotionProgram) -> Result<()> {
+        self.check_estop()?;
+        program.validate()?;
+        self.write_registers(
+            registers::P13_OPERATION_MODE,
+            &[
+                program.operation_mode.into(),
+                program.start as u16,
+                program.end as u16,
+            ],
+        )?;
+        self.write_register(registers::P13_POSITION_MODE, program.position_mode.into())?;
+        for run in program.segment_runs() {
+            let addr = registers::get_segment_displacement_register(run[0].segment)
+                .ok_or(DsyrsError::InvalidSegment(run[0].segment))?;
+            let mut values = Vec::with_capacity(run.len() * 5);
+            for seg in run {
+                values.extend(self.config.word_order.split(seg.displacement as u32));
+                values.extend([seg.speed, seg.accel_decel_time, seg.wait_time]);
+            }
+            self.write_registers(addr, &values)?;
+        }
+        Ok(())
+    }
+
+    /// Read a segment back (displacement, speed, accel/decel and wait time)
+    ///
+    /// The 32-bit displacement is decoded in the configured word order. P13.05
+    /// is global, so `position_mode_override` is left `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let segment = servo.read_segment(3)?;
+    /// println!("segment 3: {} at {} rpm", segment.displacement, segment.speed);
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn read_segment(&mut self, segment: u8) -> Result<SegmentConfig> {
+        let disp_reg = registers::get_segment_displacement_register(segment)
+            .ok_or(DsyrsError::InvalidSegment(segment))?;
+        let speed_reg = registers::get_segment_speed_register(segment)
+            .ok_or(DsyrsError::InvalidSegment(segment))?;
+        let accel_reg = registers::get_segment_accel_decel_register(segment)
+            .ok_or(DsyrsError::InvalidSegment(segment))?;
+        let wait_reg = registers::get_segment_wait_time_register(segment)
+            .ok_or(DsyrsError::InvalidSegment(segment))?;
+
+        Ok(SegmentConfig {
+            segment,
+            displacement: self.read_i32(disp_reg)?,
+            speed: self.read_register(speed_reg)?,
+            accel_decel_time: self.read_register(accel_reg)?,
+            wait_time: self.read_register(wait_reg)?,
+            position_mode_override: None,
+        })
+    }
+
+    /// Read segments `start` to `end` (inclusive, 1-16)
+    pub fn read_all_segments(&mut self, start: u8, end: u8) -> Result<Vec<SegmentConfig>> {
+        if !(1..=16).contains(&start) {
+            return Err(DsyrsError::InvalidSegment(start));
+        }
+        if !(start..=16).contains(&end) {
+            return Err(DsyrsError::InvalidSegment(end));
+        }
+        let mut segments = Vec::with_capacity((end - start + 1) as usize);
+        for segment in start..=end {
+            segments.push(self.read_segment(segment)?);
+        }
+        Ok(segments)
+    }
+
+    // ========================================================================
+    // P14 - MULTI-SPEED
+    // ========================================================================
+
+    /// Set multi-speed operation mode (P14.00)
+    pub fn set_multi_speed_mode(&mut self, mode: MultiSpeedOperationMode) -> Result<()> {
+        self.write_register(registers::P14_OPERATION_MODE, mode.into())
+    }
+
+    /// Set multi-speed end segment (P14.01, 1-16)
+    pub fn set_multi_speed_end_segment(&mut self, segment: u8) -> Result<()> {
+        if !(1..=16).contains(&segment) {
+            return Err(DsyrsError::InvalidSegment(segment));
+        }
+        self.write_register(registers::P14_END_SEGMENT, segment as u16)
+    }
+
+    /// Set multi-speed run time unit (P14.02)
+    pub fn set_multi_speed_time_unit(&mut self, unit: MultiSpeedTimeUnit) -> Result<()> {
+        self.write_register(registers::P14_TIME_UNIT, unit.into())
+    }
+
+    /// Set one of the four multi-speed accel/decel times (P14.03-P14.06, 0-10000 ms)
+    pub fn set_multi_speed_accel_time(&mut self, index: u8, ms: u16) -> Result<()> {
+        let register = registers::get_multi_speed_accel_time_register(index).ok_or_else(|| {
+            DsyrsError::InvalidParameter(format!(
+                "Invalid accel/decel time index: {}. Must be 1-4",
+                index
+            ))
+        })?;
+        if ms > 10000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Multi-speed accel/decel time must be 0-10000 ms".into(),
+            ));
+        }
+        self.write_register(register, ms)
+    }
+
+    /// Configure a multi-speed segment
+    pub fn configure_speed_segment(&mut self, config: &SpeedSegmentConfig) -> Result<()> {
+        config.validate()?;
+        let speed_reg = registers::get_speed_segment_speed_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+        let time_reg = registers::get_speed_segment_time_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+        let accel_reg = registers::get_speed_segment_accel_select_register(config.segment)
+            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
+
+        self.write_register(speed_reg, config.speed as u16)?;
+        self.write_register(time_reg, config.time)?;
+        self.write_register(accel_reg, config.accel_select as u16)
+    }
+
+    // ========================================================================
+    // P16 - SPECIAL FUNCTIONS (FIXED-LENGTH INTERRUPT)
+    // ========================================================================
+
+    /// Set fixed-length acceleration time (P16.04, 0-1000 ms)
+    ///
+    /// Applies only to fixed-length interrupt moves, not to the main position command.
+    pub fn set_fixed_length_accel(&mut self, ms: u16) -> Result<()> {
+        if ms > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length accel time must be 0-1000 ms".into(),
+            ));
+        }
+        self.write_register(registers::P16_FIXED_LENGTH_ACCEL, ms)
+    }
+
+    /// Get fixed-length acceleration time (P16.04, ms)
+    pub fn get_fixed_length_accel(&mut self) -> Result<u16> {
+        self.read_register(registers::P16_FIXED_LENGTH_ACCEL)
     }
 
-    /// Clear emergency stop (P11.13)
-    pub fn clear_emergency_stop(&mut self) -> Result<()> {
-        self.write_register(registers::P11_EMERGENCY_STOP, 0)
+    /// Set fixed-length deceleration time (P16.05, 0-1000 ms)
+    ///
+    /// Applies only to fixed-length interrupt moves, not to the main position command.
+    pub fn set_fixed_length_decel(&mut self, ms: u16) -> Result<()> {
+        if ms > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length decel time must be 0-1000 ms".into(),
+            ));
+        }
+        self.write_register(registers::P16_FIXED_LENGTH_DECEL, ms)
     }
 
-    // ========================================================================
-    // P13 - MULTI-SEGMENT POSITION
-    // ========================================================================
+    /// Get fixed-length deceleration time (P16.05, ms)
+    pub fn get_fixed_length_decel(&mut self) -> Result<u16> {
+        self.read_register(registers::P16_FIXED_LENGTH_DECEL)
+    }
 
-    /// Set multi-segment operation mode (P13.00)
-    pub fn set_multi_seg_mode(&mut self, mode: MultiSegOperationMode) -> Result<()> {
-        self.write_register(registers::P13_OPERATION_MODE, mode.into())
+    /// Enable or disable the fixed-length interrupt function (P16.00)
+    pub fn enable_fixed_length(&mut self, enable: bool) -> Result<()> {
+        self.write_register(registers::P16_FIXED_LENGTH_ENABLE, enable as u16)
     }
 
-    /// Set multi-segment start segment (P13.01, 1-16)
-    pub fn set_multi_seg_start(&mut self, segment: u8) -> Result<()> {
-        if segment < 1 || segment > 16 {
-            return Err(DsyrsError::InvalidSegment(segment));
+    /// Set fixed length 1 displacement and speed (P16.01, 0-(2^30 - 1) units; P16.03, 0-9000 rpm)
+    pub fn configure_fixed_length_1(&mut self, displacement: u32, speed_rpm: u16) -> Result<()> {
+        if displacement >= 1 << 30 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length 1 displacement must be 0-1073741823".into(),
+            ));
         }
-        self.write_register(registers::P13_START_SEGMENT, segment as u16)
+        if speed_rpm > 9000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length speed must be 0-9000 rpm".into(),
+            ));
+        }
+        self.write_i30(registers::P16_FIXED_LENGTH1_DISP, displacement as i32)?;
+        self.write_register(registers::P16_FIXED_LENGTH1_SPEED, speed_rpm)
     }
 
-    /// Set multi-segment end segment (P13.02, 1-16)
-    pub fn set_multi_seg_end(&mut self, segment: u8) -> Result<()> {
-        if segment < 1 || segment > 16 {
-            return Err(DsyrsError::InvalidSegment(segment));
+    /// Set fixed length 2 displacement and speed (P16.37, ±(2^30 - 1) units; P16.39, 0-9000 rpm)
+    pub fn configure_fixed_length_2(&mut self, displacement: i32, speed_rpm: u16) -> Result<()> {
+        if speed_rpm > 9000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length speed must be 0-9000 rpm".into(),
+            ));
         }
-        self.write_register(registers::P13_END_SEGMENT, segment as u16)
+        self.write_i30(registers::P16_FIXED_LENGTH2_DISP, displacement)?;
+        self.write_register(registers::P16_FIXED_LENGTH2_SPEED, speed_rpm)
     }
 
-    /// Set multi-segment position mode (P13.05)
-    pub fn set_multi_seg_position_mode(&mut self, mode: MultiSegPositionMode) -> Result<()> {
-        self.write_register(registers::P13_POSITION_MODE, mode.into())
+    /// Prohibit or allow fixed-length interrupts through the forced FunIN.31 input
+    ///
+    /// Like `enable()`, this forces the DI assigned to the
+    /// InterruptFixedLengthProhibition function (P02.01-P02.03) and turns on DI
+    /// forcing if needed. The prohibition holds until it is lifted here, or
+    /// until `disable()` turns DI forcing back off.
+    pub fn set_fixed_length_prohibited(&mut self, prohibited: bool) -> Result<()> {
+        self.force_di_function(DiFunction::InterruptFixedLengthProhibition, prohibited)
     }
 
-    /// Configure a segment
-    pub fn configure_segment(&mut self, config: &SegmentConfig) -> Result<()> {
-        let disp_reg = registers::get_segment_displacement_register(config.segment)
-            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
-        let speed_reg = registers::get_segment_speed_register(config.segment)
-            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
-        let accel_reg = registers::get_segment_accel_decel_register(config.segment)
-            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
-        let wait_reg = registers::get_segment_wait_time_register(config.segment)
-            .ok_or(DsyrsError::InvalidSegment(config.segment))?;
-
-        // Write displacement as 32-bit value
-        self.write_i32(disp_reg, config.displacement)?;
-        self.write_register(speed_reg, config.speed)?;
-        self.write_register(accel_reg, config.accel_decel_time)?;
-        self.write_register(wait_reg, config.wait_time)
+    /// Reset the fixed-length interrupt state by pulsing the forced FunIN.36 input
+    ///
+    /// The DI assigned to InterruptFixedLengthReset (P02.01-P02.03) is forced
+    /// active, then released. DI forcing is turned back off if the reset turned
+    /// it on.
+    pub fn reset_fixed_length(&mut self) -> Result<()> {
+        let function = DiFunction::InterruptFixedLengthReset;
+        let forcing_before = self.forced_dido_restore;
+        self.force_di_function(function, true)?;
+        let release = self.force_di_function(function, false);
+        let restore = self.restore_forcing(forcing_before);
+        release.and(restore)
+    }
+
+    /// Apply fixed-length interrupt configuration (P16.01-P16.05, P16.37-P16.39)
+    pub fn apply_fixed_length_config(&mut self, config: &FixedLengthConfig) -> Result<()> {
+        config.validate()?;
+        self.write_i30(registers::P16_FIXED_LENGTH1_DISP, config.length1 as i32)?;
+        self.write_registers(
+            registers::P16_FIXED_LENGTH1_SPEED,
+            &[config.speed1, config.accel_time, config.decel_time],
+        )?;
+        self.write_i30(registers::P16_FIXED_LENGTH2_DISP, config.length2)?;
+        self.write_register(registers::P16_FIXED_LENGTH2_SPEED, config.speed2)
+    }
+
+    /// Read fixed-length interrupt configuration (P16.01-P16.05, P16.37-P16.39)
+    pub fn read_fixed_length_config(&mut self) -> Result<FixedLengthConfig> {
+        let first = self.read_registers(registers::P16_FIXED_LENGTH1_DISP, 5)?;
+        let second = self.read_registers(registers::P16_FIXED_LENGTH2_DISP, 3)?;
+        let order = self.config.word_order;
+        Ok(FixedLengthConfig {
+            length1: order.join([first[0], first[1]]),
+            speed1: first[2],
+            accel_time: first[3],
+            decel_time: first[4],
+            length2: order.join([second[0], second[1]]) as i32,
+            speed2: second[2],
+        })
     }
 
     // ========================================================================
@@ -629,6 +3023,12 @@ impl DsyrsSyncClient {
         self.write_register(registers::P16_HOMING_ENABLE_MODE, mode.into())
     }
 
+    /// Get homing enable control mode (P16.08)
+    pub fn get_homing_enable_mode(&mut self) -> Result<HomingEnableMode> {
+        let value = self.read_register(registers::P16_HOMING_ENABLE_MODE)?;
+        HomingEnableMode::try_from(value)
+    }
+
     /// Set homing mode (P16.09)
     pub fn set_homing_mode(&mut self, mode: HomingMode) -> Result<()> {
         self.write_register(registers::P16_HOMING_MODE, mode.into())
@@ -666,7 +3066,546 @@ impl DsyrsSyncClient {
         self.set_homing_low_speed(config.low_speed)?;
         self.set_homing_accel(config.accel_limit)?;
         self.set_homing_timeout(config.timeout)?;
-        self.set_home_offset(config.offset)
+        self.set_home_offset(config.offset)?;
+        // Enable mode last: modes 2/3 may start homing with the parameters above
+        if let Some(mode) = config.enable_mode {
+            self.set_homing_enable_mode(mode)?;
+        }
+        Ok(())
+    }
+
+    /// Wait until homing has completed
+    ///
+    /// The homing completion signal (FunOUT.19) is only available on a DO
+    /// terminal; no documented register reports it over Modbus. Completion is
+    /// detected instead when the motor is stopped (P18.01) with the absolute
+    /// position (P18.07) within the positioning range (P04.24) of the home
+    /// offset (P16.14), the position assigned to the origin when homing ends.
+    ///
+    /// That check only starts once homing has been seen running: the motor
+    /// turned (P18.01 non-zero), or homing was started with P16.08 = 3 and
+    /// P16.08 no longer reads 3. A drive resting at the home position before
+    /// homing starts (e.g. position 0 and offset 0 at power-up) is therefore
+    /// not reported as homed.
+    ///
+    /// Returns `Timeout` once `timeout` elapses. Fails with `OperationFailed`
+    /// as soon as the servo faults, or on the overtravel warning (AL.950),
+    /// raised when a limit switch is reached during homing. Other warnings,
+    /// such as a low encoder battery, do not affect homing and are ignored.
+    pub fn wait_for_homing_complete(
+        &mut self,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<()> {
+        let home = self.read_i32(registers::P16_HOME_OFFSET)? as i64;
+        let range = self.read_register(registers::P04_POSITIONING_RANGE)? as i64;
+        let immediate = u16::from(HomingEnableMode::StartImmediately);
+        let triggered = self.read_register(registers::P16_HOMING_ENABLE_MODE)? == immediate;
+        let deadline = Instant::now() + timeout;
+        let mut started = false;
+        loop {
+            let status = self.read_register(registers::P18_SERVO_STATUS)?;
+            if let Some(fault) = FaultCode::from_status(status) {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Fault {} during homing",
+                    fault
+                )));
+            }
+            if let Some(warning @ WarningCode::Overtravel) = WarningCode::from_status(status) {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Warning {} ({}) during homing",
+                    warning,
+                    warning.description()
+                )));
+            }
+            let position = self.get_position()? as i64;
+            let speed = self.get_speed()?;
+            if !started {
+                started = speed != 0
+                    || (triggered
+                        && self.read_register(registers::P16_HOMING_ENABLE_MODE)? != immediate);
+            }
+            if started && speed == 0 && (position - home).abs() <= range {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(DsyrsError::Timeout);
+            }
+            thread::sleep(poll_interval);
+        }
+    }
+
+    /// Start homing immediately (P16.08 = 3)
+    ///
+    /// Homing runs with the parameters already in P16.09-P16.14, see
+    /// `apply_homing_config()`. The servo must be enabled for the motor to move.
+    pub fn start_homing(&mut self) -> Result<()> {
+        self.check_estop()?;
+        self.set_homing_enable_mode(HomingEnableMode::StartImmediately)
+    }
+
+    /// Cancel homing by turning the homing function off (P16.08 = 0)
+    ///
+    /// Clears the trigger written by `start_homing()`; homing has to be
+    /// started again to complete.
+    pub fn cancel_homing(&mut self) -> Result<()> {
+        self.set_homing_enable_mode(HomingEnableMode::Disabled)
+    }
+
+    /// Apply a homing configuration, start homing and wait for it to complete
+    ///
+    /// The enable mode of `config` is ignored; homing is started with
+    /// `start_homing()`. Two timeouts apply:
+    /// - the drive's homing timeout (`config.timeout`, P16.13) aborts the search
+    ///   on the drive with Er.601 when the home switch is not found;
+    /// - `timeout` bounds the whole call on the host, including the final move
+    ///   to the offset. When it expires, homing is cancelled with
+    ///   `cancel_homing()` so it does not carry on unattended.
+    ///
+    /// Both end with `Timeout`; keep `timeout` longer than P16.13 so the drive
+    /// stops the motor first. Any other fault or warning during homing is
+    /// reported as `OperationFailed`, see `wait_for_homing_complete()`.
+    pub fn run_homing(&mut self, config: &HomingConfig, timeout: Duration) -> Result<()> {
+        if timeout <= Duration::from_millis(config.timeout as u64) {
+            log_warn!(
+                "Homing wait of {:?} is not longer than the drive homing timeout of {} ms",
+                timeout,
+                config.timeout
+            );
+        }
+        let config = HomingConfig {
+            enable_mode: None,
+            ..config.clone()
+        };
+        self.apply_homing_config(&config)?;
+        self.start_homing()?;
+
+        match self.wait_for_homing_complete(POLL_INTERVAL, timeout) {
+            Err(DsyrsError::Timeout) => {
+                self.cancel_homing()?;
+                Err(DsyrsError::Timeout)
+            }
+            Err(DsyrsError::OperationFailed(msg)) => match self.get_fault_code()? {
+                Some(fault) if matches!(ServoFault::from(fault), ServoFault::HomingTimeout(_)) => {
+                    Err(DsyrsError::Timeout)
+                }
+                _ => Err(DsyrsError::OperationFailed(msg)),
+            },
+            result => result,
+        }
+    }
+
+    // ========================================================================
+    // MOTION
+    // ========================================================================
+
+    /// Move to the absolute origin and wait until the servo is in position
+    ///
+    /// Requires an absolute system (P00.06). The target is the home offset
+    /// (P16.14), i.e. the position assigned to the origin when it was set; this
+    /// moves to the known zero and does not re-run homing. The move is run like
+    /// `move_absolute()`.
+    pub fn return_to_origin(&mut self, speed_rpm: u16, timeout: Duration) -> Result<()> {
+        if self.get_absolute_system()? == AbsoluteSystem::Incremental {
+            return Err(DsyrsError::OperationFailed(
+                "Return to origin requires an absolute system (P00.06)".into(),
+            ));
+        }
+        let origin = self.read_i32(registers::P16_HOME_OFFSET)?;
+        self.move_absolute(origin, speed_rpm, timeout)
+    }
+
+    /// Move to an absolute position (P18.07 units) and wait until the servo is in position
+    ///
+    /// P04.00 lists a communication position source (5), but the manual
+    /// documents no register carrying its target. Moves commanded over Modbus
+    /// are therefore run by the multi-segment engine: segment 1 (P13.08-P13.12)
+    /// is programmed with the target, P13.05 is set to absolute, segment 1 is
+    /// run once (P13.00-P13.02) and the position command source is switched to
+    /// multi-segment (P04.00 = 4). As segment 1 runs once per activation of
+    /// the internal multi-segment enable (FunIN.29), every move gives it a new
+    /// rising edge: through its unassigned state when no DI is assigned to it,
+    /// or by pulsing the forced DI while DI forcing is on. Otherwise the wired
+    /// input must provide the edge.
+    ///
+    /// # Side effects
+    /// The following parameters are overwritten and not restored afterwards:
+    /// - segment 1 (P13.08-P13.12), replacing any program written with
+    ///   `download_program()`
+    /// - P13.00 (single run), P13.01/P13.02 (segments 1-1) and P13.05
+    /// - P04.00 (multi-segment source)
+    /// - the unassigned state of FunIN.29 (P02.10 bit 12), left active
+    ///
+    /// Back them up with `dump_parameters()` first if they must be kept.
+    ///
+    /// Requires position control mode (P00.00). Completion is detected when
+    /// P18.07 is within the positioning completion range (P04.24) of the target
+    /// and `is_motion_finished()` holds; otherwise `Timeout` is returned.
+    pub fn move_absolute(
+        &mut self,
+        position: i32,
+        speed_rpm: u16,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.start_segment_move(MultiSegPositionMode::Absolute, position, speed_rpm)?;
+        self.wait_for_in_position(position, timeout)
+    }
+
+    /// Move by `pulses` from the current position and wait until the servo is in position
+    ///
+    /// Runs like `move_absolute()` with P13.05 set to incremental, with the
+    /// same side effects; the expected end position is the current P18.07
+    /// plus `pulses`.
+    pub fn move_relative(&mut self, pulses: i32, speed_rpm: u16, timeout: Duration) -> Result<()> {
+        let start = self.get_position()?;
+        let target = start.checked_add(pulses).ok_or_else(|| {
+            DsyrsError::InvalidParameter(format!(
+                "Relative move of {} from {} overflows the position range",
+                pulses, start
+            ))
+        })?;
+        self.start_segment_move(MultiSegPositionMode::Incremental, pulses, speed_rpm)?;
+        self.wait_for_in_position(target, timeout)
+    }
+
+    /// Send a motion command, checking it matches the current control mode
+    ///
+    /// Reads P00.00 and fails with `InvalidParameter` if the command belongs
+    /// to another mode; otherwise dispatches to `set_speed_command()`,
+    /// `set_torque_command()` or the multi-segment engine used by
+    /// `move_absolute()` / `move_relative()`. Position moves are started at
+    /// the configured maximum speed (capped at 9000 rpm) and not waited for;
+    /// use `wait_for_in_position()` with the expected end position to wait
+    /// for completion.
+    /// Position moves overwrite the parameters listed under `move_absolute()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, MotionCommand, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.command(MotionCommand::PositionRelative(10_000))?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn command(&mut self, command: MotionCommand) -> Result<()> {
+        let mode = self.get_control_mode()?;
+        if command.control_mode() != mode {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "{:?} requires {:?} control mode, drive is in {:?} mode (P00.00)",
+                command,
+                command.control_mode(),
+                mode
+            )));
+        }
+        let speed_rpm = self.config.max_speed.min(9000);
+        match command {
+            MotionCommand::Speed(rpm) => self.set_speed_command(rpm),
+            MotionCommand::Torque(torque) => self.set_torque_command(torque),
+            MotionCommand::PositionRelative(pulses) => {
+                self.start_segment_move(MultiSegPositionMode::Incremental, pulses, speed_rpm)
+            }
+            MotionCommand::PositionAbsolute(position) => {
+                self.start_segment_move(MultiSegPositionMode::Absolute, position, speed_rpm)
+            }
+        }
+    }
+
+    /// Program segment 1 for a single move, select the multi-segment source and start it
+    ///
+    /// Overwrites the parameters listed under `move_absolute()`.
+    fn start_segment_move(
+        &mut self,
+        mode: MultiSegPositionMode,
+        displacement: i32,
+        speed_rpm: u16,
+    ) -> Result<()> {
+        self.check_estop()?;
+        if speed_rpm == 0 || speed_rpm > 9000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Speed must be 1-9000 rpm".into(),
+            ));
+        }
+        if self.get_control_mode()? != ControlMode::Position {
+            return Err(DsyrsError::OperationFailed(
+                "Positioning requires position control mode (P00.00)".into(),
+            ));
+        }
+        let segment = SegmentConfig::new(1)?
+            .with_displacement(displacement)
+            .with_speed(speed_rpm);
+        self.configure_segment(&segment)?;
+        self.set_multi_seg_position_mode(mode)?;
+        self.set_multi_seg_start(1)?;
+        self.set_multi_seg_end(1)?;
+        self.set_multi_seg_mode(MultiSegOperationMode::Single)?;
+        self.set_position_cmd_source(PositionCmdSource::MultiSegment)?;
+        self.retrigger_multi_segment()
+    }
+
+    /// Give the internal multi-segment enable (FunIN.29) a rising edge
+    ///
+    /// In single-run mode (P13.00 = 0) the segments run once per activation,
+    /// so a later move would not start while the enable stays active. When no
+    /// DI is assigned to FunIN.29, its unassigned state (P02.10 bit 12) is
+    /// cleared then set. When a DI is assigned and DI forcing is on (e.g.
+    /// after `enable()`), that DI is forced inactive then active. Otherwise
+    /// the wired input governs and must provide the edge itself.
+    fn retrigger_multi_segment(&mut self) -> Result<()> {
+        let function = DiFunction::InternalMultiSegmentPositionEnable;
+        let functions = self.read_registers(registers::P02_DI1_FUNCTION, 3)?;
+        if !functions.contains(&u16::from(function)) {
+            // P02.10: bit 0 = FunIN.17
+            let bit = (u16::from(function) - 17) as u8;
+            self.set_register_bit(registers::P02_FUNINH_STATE, bit, false)?;
+            return self.set_register_bit(registers::P02_FUNINH_STATE, bit, true);
+        }
+        // P11.10: bit 0 = force DI
+        if self.read_register(registers::P11_FORCED_DIDO)? & 1 == 0 {
+            log_debug!("FunIN.29 is wired to a DI; the move starts on its own edge");
+            return Ok(());
+        }
+        self.force_di_function(function, false)?;
+        self.force_di_function(function, true)
+    }
+
+    /// Check whether motion has finished: command zero and motor stopped
+    ///
+    /// True when the speed command (P18.03) and feedback (P18.01) are both
+    /// zero. No position is compared, so an idle servo that never moved also
+    /// reports true; the COIN output (FunOUT.7) and the position deviation
+    /// cannot be read over Modbus (see `read_forced_do()`). Use
+    /// `wait_for_in_position()` to wait for a target within P04.24.
+    pub fn is_motion_finished(&mut self) -> Result<bool> {
+        let regs = self.read_registers(registers::P18_SPEED_FEEDBACK, 3)?;
+        Ok(regs[0] == 0 && regs[2] == 0)
+    }
+
+    /// Wait until the servo is in position at `target` (P18.07 units)
+    ///
+    /// Polls the absolute position (P18.07) and `is_motion_finished()` until
+    /// the position is within the positioning completion range (P04.24) of
+    /// `target` and motion has finished. Checking against the target
+    /// means a call made before the motion has started keeps waiting instead
+    /// of returning at once. Fails with `OperationFailed` on a fault, or with
+    /// `Timeout`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, MotionCommand, ServoConfig};
+    /// use std::time::Duration;
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// servo.command(MotionCommand::PositionAbsolute(10_000))?;
+    /// servo.wait_for_in_position(10_000, Duration::from_secs(5))?;
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn wait_for_in_position(&mut self, target: i32, timeout: Duration) -> Result<()> {
+        let range = self.read_register(registers::P04_POSITIONING_RANGE)? as i64;
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(fault) = self.get_fault_code()? {
+                return Err(DsyrsError::OperationFailed(format!(
+                    "Fault {} while positioning",
+                    fault
+                )));
+            }
+            let position = self.get_position()?;
+            if (position as i64 - target as i64).abs() <= range && self.is_motion_finished()? {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(DsyrsError::Timeout);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    // ========================================================================
+    // PARAMETER SETS
+    // ========================================================================
+
+    /// Write every parameter of a parameter set to the drive
+    ///
+    /// Parameters are written in address order; 32-bit parameters are written
+    /// as two registers. Call `save_to_eeprom()` afterwards to persist them.
+    ///
+    /// A set holding RS485 link parameters ([`registers::LINK_PARAMS`]) is
+    /// refused with `InvalidParameter` before anything is written, as the
+    /// drive would stop answering partway through; change those with
+    /// `set_comm_address()` or `change_baud_rate()`.
+    pub fn apply_parameter_set(&mut self, set: &ParameterSet) -> Result<()> {
+        if let Some((addr, _)) = set
+            .iter()
+            .find(|(addr, _)| registers::LINK_PARAMS.contains(addr))
+        {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "{} changes the RS485 link and cannot be applied from a parameter set; \
+                 use set_comm_address() or change_baud_rate()",
+                format_param_code(addr)
+            )));
+        }
+        for (addr, value) in set.iter() {
+            if registers::is_32bit_param(addr) {
+                self.write_u32(addr, value)?;
+            } else {
+                self.write_register(addr, value as u16)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Read every writable parameter as a register map (backup, cloning)
+    ///
+    /// Covers [`registers::WRITABLE_REGISTERS`]; 32-bit parameters appear as
+    /// two entries, the high word at the parameter address and the low word at
+    /// address + 1. Only contiguous documented registers are batched.
+    pub fn dump_parameters(&mut self) -> Result<BTreeMap<u16, u16>> {
+        let mut addrs = Vec::with_capacity(registers::WRITABLE_REGISTERS.len() + 32);
+        for &addr in registers::WRITABLE_REGISTERS {
+            addrs.push(addr);
+            if registers::is_32bit_param(addr) {
+                addrs.push(addr + 1);
+            }
+        }
+        let values = self.read_params_with_gap(&addrs, 0)?;
+        Ok(addrs.into_iter().zip(values).collect())
+    }
+
+    /// Compare the drive against a baseline register map, such as a `dump_parameters()` backup
+    ///
+    /// Reads the baseline addresses that are writable parameters
+    /// ([`registers::WRITABLE_REGISTERS`], plus the low word of 32-bit ones)
+    /// and returns `(addr, expected, actual)` for each register that differs,
+    /// in address order. Other addresses in the baseline are ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let baseline = servo.dump_parameters()?;
+    /// // ...
+    /// for (addr, expected, actual) in servo.diff_parameters(&baseline)? {
+    ///     println!("{:#06X}: {} -> {}", addr, expected, actual);
+    /// }
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn diff_parameters(
+        &mut self,
+        baseline: &BTreeMap<u16, u16>,
+    ) -> Result<Vec<(u16, u16, u16)>> {
+        let addrs: Vec<u16> = baseline
+            .keys()
+            .copied()
+            .filter(|&addr| {
+                registers::is_config_param(addr)
+                    || (addr > 0
+                        && registers::is_32bit_param(addr - 1)
+                        && registers::is_config_param(addr - 1))
+            })
+            .collect();
+        let values = self.read_params_with_gap(&addrs, 0)?;
+        Ok(addrs
+            .into_iter()
+            .zip(values)
+            .filter_map(|(addr, actual)| {
+                let expected = baseline[&addr];
+                (expected != actual).then_some((addr, expected, actual))
+            })
+            .collect())
+    }
+
+    /// Write back a register map produced by `dump_parameters()`
+    ///
+    /// Read-only and unknown addresses (P01, P12 versions, P18 monitors) are
+    /// skipped. 32-bit parameters are written as one high/low pair and fail if
+    /// the low word is missing. Communication parameters (P10) are written
+    /// last; remove them from the map when cloning onto a bus where each drive
+    /// keeps its own address. Call `save_to_eeprom()` afterwards to persist.
+    ///
+    /// The absolute encoder origin ([`registers::ENCODER_ORIGIN_PARAMS`]) is
+    /// not restored, as it only applies to the motor it was set on; use
+    /// `restore_parameters_with_origin()` to restore a backup onto that motor.
+    pub fn restore_parameters(&mut self, params: &BTreeMap<u16, u16>) -> Result<()> {
+        let params: BTreeMap<u16, u16> = params
+            .iter()
+            .filter(|(addr, _)| !registers::ENCODER_ORIGIN_PARAMS.contains(addr))
+            .map(|(&addr, &value)| (addr, value))
+            .collect();
+        self.restore_parameters_with_origin(&params)
+    }
+
+    /// Write back a `dump_parameters()` map including the absolute encoder origin
+    ///
+    /// Like `restore_parameters()`, but also writes P16.28-P16.30. Only use it
+    /// with a backup of the same motor and encoder, e.g. after a factory reset.
+    pub fn restore_parameters_with_origin(&mut self, params: &BTreeMap<u16, u16>) -> Result<()> {
+        let is_comm = |addr: u16| addr >> 8 == 10;
+        let ordered = params
+            .iter()
+            .filter(|(&addr, _)| !is_comm(addr))
+            .chain(params.iter().filter(|(&addr, _)| is_comm(addr)));
+
+        for (&addr, &value) in ordered {
+            if registers::is_32bit_param(addr) && registers::is_config_param(addr) {
+                let low = params.get(&(addr + 1)).ok_or_else(|| {
+                    DsyrsError::InvalidParameter(format!(
+                        "Missing low word of 32-bit parameter {}",
+                        format_param_code(addr)
+                    ))
+                })?;
+                self.write_registers(addr, &[value, *low])?;
+            } else if registers::is_config_param(addr) {
+                self.write_register(addr, value)?;
+            } else if addr > 0
+                && registers::is_32bit_param(addr - 1)
+                && registers::is_config_param(addr - 1)
+            {
+                // Low word, written with its high word
+            } else {
+                log_debug!(
+                    "Skipping read-only or unknown register {}",
+                    format_param_code(addr)
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate an offline configuration model and write it to the drive
+    ///
+    /// Writes basic settings, gains, I/O map, homing and the multi-segment
+    /// program, then the communication settings last so a changed address or
+    /// baud rate does not cut off the remaining writes. This client keeps its
+    /// current slave ID. Call `save_to_eeprom()` afterwards to persist.
+    pub fn apply_model(&mut self, model: &ServoConfigModel) -> Result<()> {
+        model.validate()?;
+
+        self.set_control_mode(model.servo.control_mode)?;
+        self.set_direction(model.servo.direction)?;
+        self.set_max_speed(model.servo.max_speed)?;
+
+        if let Some(gains) = &model.gains {
+            self.apply_gain_params(gains)?;
+        }
+        for di in &model.di_map {
+            self.set_di_function(di.input, di.function)?;
+            self.set_di_logic(di.input, di.logic)?;
+        }
+        for dout in &model.do_map {
+            self.set_do_function(dout.output, dout.function)?;
+            self.set_do_logic(dout.output, dout.logic)?;
+        }
+        if let Some(homing) = &model.homing {
+            self.apply_homing_config(homing)?;
+        }
+        if let Some(program) = &model.multi_segment {
+            self.download_program(program)?;
+        }
+        if let Some(comm) = &model.comm {
+            self.apply_comm_config(comm)?;
+        }
+        Ok(())
     }
 
     // ========================================================================
@@ -679,54 +3618,182 @@ impl DsyrsSyncClient {
         Ok(ServoState::from(data[0]))
     }
 
-    /// Get motor speed feedback (P18.01, rpm)
+    /// Get the decoded servo status word (P18.00)
+    pub fn get_status_word(&mut self) -> Result<ServoStatusWord> {
+        let status = self.read_register(registers::P18_SERVO_STATUS)?;
+        Ok(ServoStatusWord::from(status))
+    }
+
+    /// Get the active fault code (P18.00), `None` when the servo is not in error
+    ///
+    /// `ServoFault::from(code)` decodes the fault category. Warnings (Alarm
+    /// state) are not faults and are reported by `get_warning_code()`.
+    pub fn get_fault_code(&mut self) -> Result<Option<FaultCode>> {
+        let status = self.read_register(registers::P18_SERVO_STATUS)?;
+        Ok(FaultCode::from_status(status))
+    }
+
+    /// Get the active warning (P18.00), `None` when no warning is present
+    ///
+    /// Warnings do not stop the drive; see [`WarningCode`].
+    pub fn get_warning_code(&mut self) -> Result<Option<WarningCode>> {
+        let status = self.read_register(registers::P18_SERVO_STATUS)?;
+        Ok(WarningCode::from_status(status))
+    }
+
+    /// Get motor speed feedback (P18.01, rpm, signed: negative in reverse)
     pub fn get_speed(&mut self) -> Result<i16> {
         let data = self.read_registers(registers::P18_SPEED_FEEDBACK, 1)?;
         Ok(data[0] as i16)
     }
 
-    /// Get average load rate (P18.02, unit: 0.1%)
+    /// Get average load rate (P18.02, unit: 0.1%, unsigned)
     pub fn get_load_rate(&mut self) -> Result<f32> {
         let data = self.read_registers(registers::P18_LOAD_RATE, 1)?;
         Ok(data[0] as f32 * 0.1)
     }
 
-    /// Get speed command (P18.03, rpm)
+    /// Get speed command (P18.03, rpm, signed: negative in reverse)
     pub fn get_speed_command(&mut self) -> Result<i16> {
         let data = self.read_registers(registers::P18_SPEED_COMMAND, 1)?;
         Ok(data[0] as i16)
     }
 
-    /// Get internal torque (P18.04, unit: 0.1% of rated)
+    /// Get internal torque (P18.04, unit: 0.1% of rated, signed)
     pub fn get_torque(&mut self) -> Result<f32> {
         let data = self.read_registers(registers::P18_INTERNAL_TORQUE, 1)?;
         Ok(data[0] as i16 as f32 * 0.1)
     }
 
-    /// Get phase current RMS (P18.05, unit: 0.01 A)
+    /// Get phase current RMS (P18.05, unit: 0.01 A, unsigned)
     pub fn get_current(&mut self) -> Result<f32> {
         let data = self.read_registers(registers::P18_PHASE_CURRENT, 1)?;
         Ok(data[0] as f32 * 0.01)
     }
 
-    /// Get DC bus voltage (P18.06, unit: 0.1 V)
+    /// Get DC bus voltage (P18.06, unit: 0.1 V, unsigned)
     pub fn get_bus_voltage(&mut self) -> Result<f32> {
         let data = self.read_registers(registers::P18_BUS_VOLTAGE, 1)?;
         Ok(data[0] as f32 * 0.1)
     }
 
-    /// Get absolute position (P18.07)
+    /// Get motor speed feedback (P18.01) as [`Rpm`]
+    pub fn get_speed_typed(&mut self) -> Result<Rpm> {
+        Ok(Rpm::from_raw(
+            self.read_register(registers::P18_SPEED_FEEDBACK)?,
+        ))
+    }
+
+    /// Get internal torque (P18.04) as [`TorquePercent`]
+    pub fn get_torque_typed(&mut self) -> Result<TorquePercent> {
+        Ok(TorquePercent::from_raw(
+            self.read_register(registers::P18_INTERNAL_TORQUE)?,
+        ))
+    }
+
+    /// Get phase current RMS (P18.05) as [`Amps`]
+    pub fn get_current_typed(&mut self) -> Result<Amps> {
+        Ok(Amps::from_raw(
+            self.read_register(registers::P18_PHASE_CURRENT)?,
+        ))
+    }
+
+    /// Get DC bus voltage (P18.06) as [`Volts`]
+    pub fn get_bus_voltage_typed(&mut self) -> Result<Volts> {
+        Ok(Volts::from_raw(
+            self.read_register(registers::P18_BUS_VOLTAGE)?,
+        ))
+    }
+
+    /// Get absolute position (P18.07-P18.08, signed 32-bit)
     pub fn get_position(&mut self) -> Result<i32> {
         self.read_i32(registers::P18_ABSOLUTE_POSITION)
     }
 
-    /// Get electrical angle (P18.09, unit: 0.1°)
+    /// Get the absolute position (P18.07) as encoder turns plus single-turn offset
+    ///
+    /// Requires an absolute encoder: `config.encoder_type`, or P01.18 when it
+    /// is not configured, must be an absolute type. See [`MultiTurnPosition`]
+    /// for the conversion and the rollover of the rotary absolute system.
+    pub fn get_absolute_position_multiturn(&mut self) -> Result<MultiTurnPosition> {
+        let encoder = match self.config.encoder_type {
+            Some(encoder) => encoder,
+            None => self.get_encoder_type()?,
+        };
+        if !encoder.is_absolute() {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Multi-turn position needs an absolute encoder, configured {:?}",
+                encoder
+            )));
+        }
+        let resolution = self.get_encoder_resolution()?;
+        let gear = self.read_gear_ratio()?;
+        let position = self.get_position()?;
+        MultiTurnPosition::from_position(position, gear, resolution)
+    }
+
+    /// Get the absolute position (P18.07) in user units
+    ///
+    /// Converted with the mechanics given by `ServoConfig::with_mechanical()`;
+    /// fails with `InvalidParameter` when none were configured. The gear and
+    /// resolution are taken from the configuration, not read back.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, GearRatio, ServoConfig};
+    ///
+    /// // 5 mm of travel per motor revolution
+    /// let gear = GearRatio::new(131_072, 10_000)?;
+    /// let config = ServoConfig::new(1).with_mechanical(5.0, gear, 131_072);
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, config)?;
+    /// println!("position: {} mm", servo.get_position_user()?);
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn get_position_user(&mut self) -> Result<f64> {
+        let converter = self.config.mechanical.ok_or_else(|| {
+            DsyrsError::InvalidParameter(
+                "No mechanics configured, see ServoConfig::with_mechanical()".into(),
+            )
+        })?;
+        converter.validate()?;
+        Ok(converter.pulses_to_user(self.get_position()?))
+    }
+
+    /// Get electrical angle (P18.09, unit: 0.1°, unsigned)
     pub fn get_electrical_angle(&mut self) -> Result<f32> {
         let data = self.read_registers(registers::P18_ELECTRICAL_ANGLE, 1)?;
         Ok(data[0] as f32 * 0.1)
     }
 
+    /// Get motor speed feedback as a percentage of the motor maximum speed (P18.01 / P01.08)
+    ///
+    /// Negative values indicate reverse rotation.
+    pub fn speed_percent_of_max(&mut self) -> Result<f32> {
+        let max_speed = self.get_motor_max_speed()?;
+        if max_speed == 0 {
+            return Err(DsyrsError::OperationFailed(
+                "Motor max speed (P01.08) reads 0 rpm".into(),
+            ));
+        }
+        let speed = self.get_speed()?;
+        Ok(speed as f32 * 100.0 / max_speed as f32)
+    }
+
     /// Get complete servo status
+    ///
+    /// Reads each value in a separate request, so fields are sampled at
+    /// different instants; prefer `get_status_fast()` for an atomic snapshot.
+    /// Both decode the same signedness as the individual getters.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    ///
+    /// let mut servo = DsyrsSyncClient::connect("/dev/ttyUSB0", 115200, ServoConfig::new(1))?;
+    /// let status = servo.get_status()?;
+    /// println!("{:?} at {} rpm", status.state, status.speed);
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
     pub fn get_status(&mut self) -> Result<ServoStatus> {
         Ok(ServoStatus {
             state: self.get_servo_state()?,
@@ -740,6 +3807,16 @@ impl DsyrsSyncClient {
         })
     }
 
+    /// Get the complete servo status in a single transaction (P18.00-P18.09)
+    ///
+    /// Unlike `get_status()`, every field is sampled at the same instant and
+    /// only one request is sent on the bus.
+    pub fn get_status_fast(&mut self) -> Result<ServoStatus> {
+        let regs =
+            self.read_registers(registers::P18_SERVO_STATUS, ServoStatus::BLOCK_LEN as u16)?;
+        ServoStatus::from_registers_with_order(&regs, self.config.word_order)
+    }
+
     // ========================================================================
     // VERSION INFORMATION
     // ========================================================================
@@ -761,4 +3838,193 @@ impl DsyrsSyncClient {
         let data = self.read_registers(registers::P12_PRODUCT_CODE, 1)?;
         Ok(data[0])
     }
+
+    /// Get the firmware versions and product code in one transaction (P12.11-P12.14)
+    pub fn get_firmware_info(&mut self) -> Result<FirmwareInfo> {
+        let regs = self.read_registers(
+            registers::P12_NONSTANDARD_VERSION,
+            FirmwareInfo::BLOCK_LEN as u16,
+        )?;
+        FirmwareInfo::from_registers(&regs)
+    }
+
+    // ========================================================================
+    // CONFIGURATION DIAGNOSTICS
+    // ========================================================================
+
+    /// Check that the command source matches the control mode for host control
+    ///
+    /// Reads the control mode (P00.00) and the matching command source, and
+    /// returns `OperationFailed` with a description of the fix when commands
+    /// written over Modbus would be ignored:
+    /// - Position: P04.00 must be MultiSegment, which `move_absolute()` and
+    ///   `download_program()` select, or Communication
+    /// - Speed: the selected speed command (P05.02) must come from the digit value P05.03
+    /// - Torque: P06.02 must select A and P06.00 must be the digit value P06.05
+    pub fn validate_command_routing(&mut self) -> Result<()> {
+        let mode = self.get_control_mode()?;
+        self.check_command_routing(mode)
+    }
+
+    /// Pre-flight check before any motion
+    ///
+    /// Reads P18.00 (fault and enable state), P00.00 (control mode) and the
+    /// command source registers checked by `validate_command_routing()`.
+    /// Overtravel is taken from the AL.950 warning in P18.00, as the limit
+    /// inputs cannot be read over Modbus.
+    /// Communication errors are returned as `Err`; failed checks are listed
+    /// in the report.
+    pub fn ready_for_motion(&mut self) -> Result<ReadinessReport> {
+        let status = self.read_register(registers::P18_SERVO_STATUS)?;
+        let state = ServoState::from(status);
+        let control_mode = self.get_control_mode()?;
+        let command_routing_issue = match self.check_command_routing(control_mode) {
+            Ok(()) => None,
+            Err(DsyrsError::OperationFailed(issue)) => Some(issue),
+            Err(e) => return Err(e),
+        };
+        Ok(ReadinessReport {
+            state,
+            fault: FaultCode::from_status(status),
+            servo_enabled: state == ServoState::Running,
+            control_mode,
+            control_mode_matches: control_mode == self.config.control_mode,
+            command_routing_issue,
+            overtravel_clear: !ServoStatusWord::from(status).overtravel,
+        })
+    }
+
+    /// Check the command source registers for the given control mode
+    fn check_command_routing(&mut self, mode: ControlMode) -> Result<()> {
+        match mode {
+            ControlMode::Position => {
+                let source = self.get_position_cmd_source()?;
+                if !matches!(
+                    source,
+                    PositionCmdSource::MultiSegment | PositionCmdSource::Communication
+                ) {
+                    return Err(DsyrsError::OperationFailed(format!(
+                        "Position mode but command source (P04.00) is {:?}; \
+                         set it to MultiSegment for host positioning (move_absolute())",
+                        source
+                    )));
+                }
+            }
+            ControlMode::Speed => {
+                let select = self.read_register(registers::P05_SPEED_CMD_SELECT)?;
+                let (source_reg, name) = match SpeedCmdSelect::try_from(select) {
+                    Ok(SpeedCmdSelect::A) => (registers::P05_SPEED_CMD_SOURCE, "main A (P05.00)"),
+                    Ok(SpeedCmdSelect::B) => {
+                        (registers::P05_AUX_SPEED_SOURCE, "auxiliary B (P05.01)")
+                    }
+                    _ => {
+                        return Err(DsyrsError::OperationFailed(format!(
+                            "Speed mode but command selection (P05.02) is {}; \
+                             set it to A for host speed commands",
+                            select
+                        )));
+                    }
+                };
+                let source = self.read_register(source_reg)?;
+                if source != u16::from(SpeedCmdSource::DigitValue) {
+                    return Err(DsyrsError::OperationFailed(format!(
+                        "Speed mode but {} source is {}; \
+                         set it to DigitValue (P05.03) for host speed commands",
+                        name, source
+                    )));
+                }
+            }
+            ControlMode::Torque => {
+                let select = self.read_register(registers::P06_TORQUE_CMD_SELECT)?;
+                if select != 0 {
+                    return Err(DsyrsError::OperationFailed(format!(
+                        "Torque mode but command selection (P06.02) is {}; \
+                         set it to 0 (A) for host torque commands",
+                        select
+                    )));
+                }
+                let source = self.read_register(registers::P06_TORQUE_CMD_SOURCE)?;
+                if source != 0 {
+                    return Err(DsyrsError::OperationFailed(format!(
+                        "Torque mode but command source (P06.00) is {}; \
+                         set it to 0 (digit value P06.05) for host torque commands",
+                        source
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// DO forcing held by `DsyrsSyncClient::force_outputs()`
+///
+/// Derefs to the client, so the forced levels can be changed with
+/// `force_do_bit()` while it is held. P11.10 is restored by `release()` or,
+/// best effort, when the guard is dropped.
+pub struct SyncForcedIoGuard<'a, T: SyncModbusTransport> {
+    client: &'a mut DsyrsSyncClient<T>,
+    /// P11.10 before forcing, `None` once released
+    previous: Option<u16>,
+}
+
+impl<T: SyncModbusTransport> SyncForcedIoGuard<'_, T> {
+    /// Restore P11.10 to its value before `force_outputs()`
+    pub fn release(mut self) -> Result<()> {
+        match self.previous.take() {
+            Some(previous) => self
+                .client
+                .write_register(registers::P11_FORCED_DIDO, previous),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: SyncModbusTransport> std::ops::Deref for SyncForcedIoGuard<'_, T> {
+    type Target = DsyrsSyncClient<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.client
+    }
+}
+
+impl<T: SyncModbusTransport> std::ops::DerefMut for SyncForcedIoGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.client
+    }
+}
+
+impl<T: SyncModbusTransport> Drop for SyncForcedIoGuard<'_, T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            if let Err(e) = self
+                .client
+                .write_register(registers::P11_FORCED_DIDO, previous)
+            {
+                log_warn!("Restoring P11.10 to {} on drop failed: {}", previous, e);
+            }
+        }
+    }
+}
+
+/// Mask of a register bit, checking it is in 0-15
+fn bit_mask(bit: u8) -> Result<u16> {
+    if bit > 15 {
+        return Err(DsyrsError::InvalidParameter(format!(
+            "Invalid register bit: {}. Must be 0-15",
+            bit
+        )));
+    }
+    Ok(1 << bit)
+}
+
+/// Check a torque limit (P06.08-P06.11) against its 0-5000 range
+fn check_torque_limit(limit: u16) -> Result<()> {
+    if limit > 5000 {
+        return Err(DsyrsError::InvalidParameter(format!(
+            "Torque limit {} must be 0-5000 (0.1% of rated)",
+            limit
+        )));
+    }
+    Ok(())
 }
@@ -0,0 +1,125 @@
+//! Modbus transport abstraction
+//!
+//! Both clients are generic over the transport carrying their register
+//! requests. The tokio-modbus contexts implement these traits, so
+//! `DsyrsClient::new(ctx, config)` and `DsyrsSyncClient::new(ctx, config)`
+//! keep working with a real RS485 connection, while tests can plug in the
+//! `MockTransport` shipped behind the `test-util` feature.
+//!
+//! Only the requests used by the clients are part of the traits: reading
+//! holding registers, writing one or several holding registers and selecting
+//! the slave. Results use the tokio-modbus nesting, where the outer error is a
+//! transport or protocol failure and the inner one a Modbus exception.
+
+use std::future::Future;
+use std::time::Duration;
+use tokio_modbus::prelude::*;
+
+/// Asynchronous Modbus transport used by [`DsyrsClient`](crate::DsyrsClient)
+pub trait ModbusTransport: Send {
+    /// Select the slave addressed by the following requests
+    fn set_slave(&mut self, slave: Slave);
+
+    /// Read `count` holding registers starting at `addr` (function 0x03)
+    fn read_holding_registers(
+        &mut self,
+        addr: u16,
+        count: u16,
+    ) -> impl Future<Output = tokio_modbus::Result<Vec<u16>>> + Send;
+
+    /// Write a single holding register (function 0x06)
+    fn write_single_register(
+        &mut self,
+        addr: u16,
+        value: u16,
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send;
+
+    /// Write consecutive holding registers starting at `addr` (function 0x10)
+    fn write_multiple_registers<'a>(
+        &'a mut self,
+        addr: u16,
+        values: &'a [u16],
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send + 'a;
+}
+
+/// Blocking Modbus transport used by [`DsyrsSyncClient`](crate::DsyrsSyncClient)
+pub trait SyncModbusTransport {
+    /// Select the slave addressed by the following requests
+    fn set_slave(&mut self, slave: Slave);
+
+    /// Read `count` holding registers starting at `addr` (function 0x03)
+    fn read_holding_registers(&mut self, addr: u16, count: u16) -> tokio_modbus::Result<Vec<u16>>;
+
+    /// Write a single holding register (function 0x06)
+    fn write_single_register(&mut self, addr: u16, value: u16) -> tokio_modbus::Result<()>;
+
+    /// Write consecutive holding registers starting at `addr` (function 0x10)
+    fn write_multiple_registers(&mut self, addr: u16, values: &[u16]) -> tokio_modbus::Result<()>;
+
+    /// Response timeout, `None` if requests wait indefinitely
+    ///
+    /// Used to shorten the wait for broadcast writes, which get no response.
+    /// Transports without a timeout keep the default.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Set the response timeout
+    fn set_timeout(&mut self, _timeout: Option<Duration>) {}
+}
+
+impl ModbusTransport for client::Context {
+    fn set_slave(&mut self, slave: Slave) {
+        SlaveContext::set_slave(self, slave);
+    }
+
+    fn read_holding_registers(
+        &mut self,
+        addr: u16,
+        count: u16,
+    ) -> impl Future<Output = tokio_modbus::Result<Vec<u16>>> + Send {
+        Reader::read_holding_registers(self, addr, count)
+    }
+
+    fn write_single_register(
+        &mut self,
+        addr: u16,
+        value: u16,
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send {
+        Writer::write_single_register(self, addr, value)
+    }
+
+    fn write_multiple_registers<'a>(
+        &'a mut self,
+        addr: u16,
+        values: &'a [u16],
+    ) -> impl Future<Output = tokio_modbus::Result<()>> + Send + 'a {
+        Writer::write_multiple_registers(self, addr, values)
+    }
+}
+
+impl SyncModbusTransport for client::sync::Context {
+    fn set_slave(&mut self, slave: Slave) {
+        SlaveContext::set_slave(self, slave);
+    }
+
+    fn read_holding_registers(&mut self, addr: u16, count: u16) -> tokio_modbus::Result<Vec<u16>> {
+        client::sync::Reader::read_holding_registers(self, addr, count)
+    }
+
+    fn write_single_register(&mut self, addr: u16, value: u16) -> tokio_modbus::Result<()> {
+        client::sync::Writer::write_single_register(self, addr, value)
+    }
+
+    fn write_multiple_registers(&mut self, addr: u16, values: &[u16]) -> tokio_modbus::Result<()> {
+        client::sync::Writer::write_multiple_registers(self, addr, values)
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        client::sync::Context::timeout(self)
+    }
+
+    fn set_timeout(&mut self, timeout: Option<Duration>) {
+        client::sync::Context::set_timeout(self, timeout);
+    }
+}
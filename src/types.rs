@@ -3,6 +3,8 @@
 //! Contains error types, enums, and configuration structs based on
 //! DSY-RS Series Low Voltage Servo Drive User Manual - Chapter 7 Parameters.
 
+use crate::units::{Amps, Rpm, TorquePercent, Volts};
+use std::time::Duration;
 use thiserror::Error;
 use tokio_modbus::ExceptionCode;
 
@@ -39,11 +41,112 @@ pub enum DsyrsError {
     #[error("Timeout waiting for operation")]
     Timeout,
 
+    #[error("Emergency stop active, clear it before commanding motion")]
+    EmergencyStopActive,
+
     #[error("I/O error: {0}")]
     IoError(String),
 
     #[error("Serial port error: {0}")]
     SerialError(String),
+
+    #[error("Modbus request failed after {retries} retries: {last}")]
+    RetriesExhausted { retries: u8, last: Box<DsyrsError> },
+}
+
+impl DsyrsError {
+    /// Whether the error is a transient communication failure worth retrying
+    ///
+    /// True for communication failures: I/O, transport and protocol errors
+    /// (CRC errors, garbled frames), serial port errors and `Timeout`,
+    /// including exhausted retries of those. The async client reports an
+    /// elapsed `ServoConfig::with_timeout()` limit as `Timeout`, where the sync
+    /// client gets a timed-out I/O error from its transport. False for answers
+    /// and decisions that will not change by retrying: Modbus exceptions,
+    /// invalid parameters and failed operations.
+    ///
+    /// This drives the automatic [`RetryPolicy`] and the reconnection of
+    /// [`ReconnectingClient`](crate::ReconnectingClient).
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::{DsyrsError, ExceptionCode};
+    /// use std::io;
+    ///
+    /// let io_error = || io::Error::new(io::ErrorKind::TimedOut, "no response");
+    /// assert!(DsyrsError::Modbus(io_error()).is_transient());
+    /// assert!(DsyrsError::ModbusProtocol(tokio_modbus::Error::Transport(io_error())).is_transient());
+    /// assert!(DsyrsError::Timeout.is_transient());
+    /// assert!(DsyrsError::IoError("broken pipe".into()).is_transient());
+    /// assert!(DsyrsError::SerialError("port closed".into()).is_transient());
+    /// assert!(DsyrsError::RetriesExhausted {
+    ///     retries: 3,
+    ///     last: Box::new(DsyrsError::Modbus(io_error())),
+    /// }
+    /// .is_transient());
+    ///
+    /// assert!(!DsyrsError::ModbusException(ExceptionCode::IllegalDataAddress).is_transient());
+    /// assert!(!DsyrsError::InvalidParameter("speed".into()).is_transient());
+    /// assert!(!DsyrsError::InvalidSegment(17).is_transient());
+    /// assert!(!DsyrsError::InvalidDigitalInput(4).is_transient());
+    /// assert!(!DsyrsError::InvalidDigitalOutput(3).is_transient());
+    /// assert!(!DsyrsError::OperationFailed("fault".into()).is_transient());
+    /// assert!(!DsyrsError::ServoNotReady.is_transient());
+    /// assert!(!DsyrsError::EmergencyStopActive.is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            DsyrsError::Modbus(_)
+            | DsyrsError::ModbusProtocol(_)
+            | DsyrsError::Timeout
+            | DsyrsError::IoError(_)
+            | DsyrsError::SerialError(_) => true,
+            DsyrsError::RetriesExhausted { last, .. } => last.is_transient(),
+            DsyrsError::ModbusException(_)
+            | DsyrsError::InvalidParameter(_)
+            | DsyrsError::InvalidSegment(_)
+            | DsyrsError::InvalidDigitalInput(_)
+            | DsyrsError::InvalidDigitalOutput(_)
+            | DsyrsError::OperationFailed(_)
+            | DsyrsError::ServoNotReady
+            | DsyrsError::EmergencyStopActive => false,
+        }
+    }
+
+    /// Modbus exception answered by the drive, if this is one
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::{DsyrsError, ExceptionCode};
+    ///
+    /// let err = DsyrsError::ModbusException(ExceptionCode::IllegalDataValue);
+    /// assert_eq!(err.exception_code(), Some(ExceptionCode::IllegalDataValue));
+    /// assert_eq!(DsyrsError::Timeout.exception_code(), None);
+    /// ```
+    pub fn exception_code(&self) -> Option<ExceptionCode> {
+        match self {
+            DsyrsError::ModbusException(code) => Some(*code),
+            DsyrsError::RetriesExhausted { last, .. } => last.exception_code(),
+            _ => None,
+        }
+    }
+
+    /// Whether the drive rejected the register address (IllegalDataAddress)
+    ///
+    /// Older firmware answers this way for parameters it does not support,
+    /// which makes it usable to detect whether a feature is available.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::{DsyrsError, ExceptionCode};
+    ///
+    /// assert!(DsyrsError::ModbusException(ExceptionCode::IllegalDataAddress).is_illegal_address());
+    /// assert!(!DsyrsError::ModbusException(ExceptionCode::IllegalDataValue).is_illegal_address());
+    /// assert!(!DsyrsError::ServoNotReady.is_illegal_address());
+    /// ```
+    pub fn is_illegal_address(&self) -> bool {
+        self.exception_code() == Some(ExceptionCode::IllegalDataAddress)
+    }
 }
 
 pub type Result<T> = std::result::Result<T, DsyrsError>;
@@ -53,7 +156,18 @@ pub type Result<T> = std::result::Result<T, DsyrsError>;
 // ============================================================================
 
 /// Control mode selection (P00.00)
+///
+/// # Example
+/// ```
+/// use dsyrs::ControlMode;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(ControlMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(ControlMode::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum ControlMode {
     /// Position control mode
@@ -86,8 +200,54 @@ impl TryFrom<u16> for ControlMode {
     }
 }
 
+/// Motion command for the current control mode, dispatched by `command()`
+///
+/// # Example
+/// ```
+/// use dsyrs::{ControlMode, MotionCommand};
+///
+/// assert_eq!(MotionCommand::Speed(500).control_mode(), ControlMode::Speed);
+/// assert_eq!(MotionCommand::Torque(-100).control_mode(), ControlMode::Torque);
+/// assert_eq!(MotionCommand::PositionRelative(1000).control_mode(), ControlMode::Position);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotionCommand {
+    /// Speed command in rpm (P05.03), speed mode
+    Speed(i16),
+    /// Torque command in 0.1% of rated torque (P06.05), torque mode
+    Torque(i16),
+    /// Move by a number of pulses from the current position, position mode
+    PositionRelative(i32),
+    /// Move to an absolute position (P18.07 units), position mode
+    PositionAbsolute(i32),
+}
+
+impl MotionCommand {
+    /// Control mode (P00.00) the command applies to
+    pub fn control_mode(&self) -> ControlMode {
+        match self {
+            MotionCommand::Speed(_) => ControlMode::Speed,
+            MotionCommand::Torque(_) => ControlMode::Torque,
+            MotionCommand::PositionRelative(_) | MotionCommand::PositionAbsolute(_) => {
+                ControlMode::Position
+            }
+        }
+    }
+}
+
 /// Motor rotation direction (P00.01)
+///
+/// # Example
+/// ```
+/// use dsyrs::Direction;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(Direction::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(Direction::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum Direction {
     /// Counter-clockwise is forward
@@ -103,8 +263,33 @@ impl From<Direction> for u16 {
     }
 }
 
+impl TryFrom<u16> for Direction {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(Direction::CcwForward),
+            1 => Ok(Direction::CwForward),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid direction: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Absolute value system selection (P00.06)
+///
+/// # Example
+/// ```
+/// use dsyrs::AbsoluteSystem;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(AbsoluteSystem::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(AbsoluteSystem::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum AbsoluteSystem {
     /// Incremental position
@@ -122,8 +307,34 @@ impl From<AbsoluteSystem> for u16 {
     }
 }
 
+impl TryFrom<u16> for AbsoluteSystem {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(AbsoluteSystem::Incremental),
+            1 => Ok(AbsoluteSystem::AbsoluteLinear),
+            2 => Ok(AbsoluteSystem::AbsoluteRotation),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid absolute system: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Servo OFF stop mode (P00.10)
+///
+/// # Example
+/// ```
+/// use dsyrs::ServoOffStopMode;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(ServoOffStopMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(ServoOffStopMode::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum ServoOffStopMode {
     /// Freewheel stop
@@ -139,8 +350,33 @@ impl From<ServoOffStopMode> for u16 {
     }
 }
 
+impl TryFrom<u16> for ServoOffStopMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(ServoOffStopMode::Freewheel),
+            1 => Ok(ServoOffStopMode::ZeroSpeed),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid servo OFF stop mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Overtravel stop mode (P00.13)
+///
+/// # Example
+/// ```
+/// use dsyrs::OvertravelStopMode;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(OvertravelStopMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(OvertravelStopMode::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum OvertravelStopMode {
     /// Freewheel
@@ -158,8 +394,34 @@ impl From<OvertravelStopMode> for u16 {
     }
 }
 
+impl TryFrom<u16> for OvertravelStopMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(OvertravelStopMode::Freewheel),
+            1 => Ok(OvertravelStopMode::DecelThenLock),
+            2 => Ok(OvertravelStopMode::DecelThenFreewheel),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid overtravel stop mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Energy consumption resistor setting (P00.18)
+///
+/// # Example
+/// ```
+/// use dsyrs::EnergyResistor;
+///
+/// for raw in 0..=3 {
+///     assert_eq!(u16::from(EnergyResistor::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(EnergyResistor::try_from(4).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum EnergyResistor {
     /// Built-in resistor
@@ -173,18 +435,55 @@ pub enum EnergyResistor {
     None = 3,
 }
 
+impl EnergyResistor {
+    /// Whether an external resistor is selected, which needs P00.19-P00.21 set
+    pub fn is_external(self) -> bool {
+        matches!(
+            self,
+            EnergyResistor::ExternalNatural | EnergyResistor::ExternalForced
+        )
+    }
+}
+
 impl From<EnergyResistor> for u16 {
     fn from(res: EnergyResistor) -> Self {
         res as u16
     }
 }
 
+impl TryFrom<u16> for EnergyResistor {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(EnergyResistor::BuiltIn),
+            1 => Ok(EnergyResistor::ExternalNatural),
+            2 => Ok(EnergyResistor::ExternalForced),
+            3 => Ok(EnergyResistor::None),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid energy resistor setting: {}",
+                value
+            ))),
+        }
+    }
+}
+
 // ============================================================================
 // P01 - Servo Motor Parameter Enums
 // ============================================================================
 
 /// Encoder selection (P01.18)
+///
+/// # Example
+/// ```
+/// use dsyrs::EncoderType;
+///
+/// for raw in 0..=4 {
+///     assert_eq!(u16::from(EncoderType::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(EncoderType::try_from(5).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum EncoderType {
     /// 2500-line encoder
@@ -206,13 +505,51 @@ impl From<EncoderType> for u16 {
     }
 }
 
+impl TryFrom<u16> for EncoderType {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(EncoderType::Line2500),
+            1 => Ok(EncoderType::Bit17Incremental),
+            2 => Ok(EncoderType::Bit17Absolute),
+            3 => Ok(EncoderType::Bit23Incremental),
+            4 => Ok(EncoderType::Bit23Absolute),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid encoder type: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl EncoderType {
+    /// Check if the encoder keeps its multi-turn position across power cycles
+    pub fn is_absolute(&self) -> bool {
+        matches!(
+            self,
+            EncoderType::Bit17Absolute | EncoderType::Bit23Absolute
+        )
+    }
+}
+
 // ============================================================================
 // P02 - Digital I/O Parameter Enums
 // ============================================================================
 
 /// Digital input function selection (P02.01-P02.03)
 /// Values 1-45 correspond to FunIN.1-45
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///
+/// # Example
+/// ```
+/// use dsyrs::DiFunction;
+///
+/// for raw in 0..=41 {
+///     assert_eq!(u16::from(DiFunction::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(DiFunction::try_from(42).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum DiFunction {
     /// No function assigned
@@ -300,16 +637,160 @@ pub enum DiFunction {
     SpeedModeA1SW1 = 40,
     /// Speed Mode A1 command direction switching 2 (reserved) (FunIN.41)
     SpeedModeA1SW2 = 41,
+    /// Function code read from the drive that this library does not know
+    ///
+    /// Equality compares the code on the wire, so `Unknown(13)` equals
+    /// `ForwardOvertravel`.
+    Unknown(u16),
+}
+
+impl PartialEq for DiFunction {
+    fn eq(&self, other: &Self) -> bool {
+        u16::from(*self) == u16::from(*other)
+    }
 }
 
 impl From<DiFunction> for u16 {
     fn from(func: DiFunction) -> Self {
-        func as u16
+        match func {
+            DiFunction::None => 0,
+            DiFunction::ServoEnable => 1,
+            DiFunction::AlarmResetSignal => 2,
+            DiFunction::ProportionalActionSwitch => 3,
+            DiFunction::MainAuxiliaryCommandSwitch => 4,
+            DiFunction::PulseDeviationClear => 5,
+            DiFunction::MultiSegCommandSwitch1 => 6,
+            DiFunction::MultiSegCommandSwitch2 => 7,
+            DiFunction::MultiSegCommandSwitch3 => 8,
+            DiFunction::MultiSegCommandSwitch4 => 9,
+            DiFunction::PModeSwitch => 10,
+            DiFunction::ZeroFixedEnable => 11,
+            DiFunction::PulseProhibition => 12,
+            DiFunction::ForwardOvertravel => 13,
+            DiFunction::BackwardOvertravel => 14,
+            DiFunction::ForwardExternalTorqueLimit => 15,
+            DiFunction::BackwardExternalTorqueLimit => 16,
+            DiFunction::ForwardJog2 => 17,
+            DiFunction::BackwardJog => 18,
+            DiFunction::PositionStepInputDI => 19,
+            DiFunction::HandwheelMagnification1 => 20,
+            DiFunction::HandwheelMagnification2 => 21,
+            DiFunction::HandwheelEnable => 22,
+            DiFunction::ElectronicGearSelection => 23,
+            DiFunction::PositionInstructionReverse => 24,
+            DiFunction::SpeedCommandReverse => 25,
+            DiFunction::TorqueCommandReverse => 26,
+            DiFunction::HandwheelSignalA => 27,
+            DiFunction::HandwheelSignalB => 28,
+            DiFunction::InternalMultiSegmentPositionEnable => 29,
+            DiFunction::InterruptFixedLengthCompletionExtConfirm => 30,
+            DiFunction::InterruptFixedLengthProhibition => 31,
+            DiFunction::HomeSwitchSignal => 32,
+            DiFunction::HomingEnableSignal => 33,
+            DiFunction::EmergencyStop => 34,
+            DiFunction::PositionLoopConstantSpeedRunning => 35,
+            DiFunction::InterruptFixedLengthReset => 36,
+            DiFunction::InterruptFixedLengthOperationPause => 37,
+            DiFunction::MultiSegmentTorqueCommandSwitch1 => 38,
+            DiFunction::MultiStepTorqueCommandSwitch1 => 39,
+            DiFunction::SpeedModeA1SW1 => 40,
+            DiFunction::SpeedModeA1SW2 => 41,
+            DiFunction::Unknown(code) => code,
+        }
+    }
+}
+
+impl DiFunction {
+    /// Convert a value read from P02.01-P02.03, keeping unknown codes as `Unknown`
+    ///
+    /// Unlike `try_from()`, which rejects codes outside FunIN.0-41, this never
+    /// fails, so assignments made with newer firmware or the front panel can
+    /// still be displayed. Known codes always map to their named variant.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::DiFunction;
+    ///
+    /// for raw in 0..=60 {
+    ///     assert_eq!(u16::from(DiFunction::from_raw(raw)), raw);
+    /// }
+    /// assert!(matches!(DiFunction::from_raw(13), DiFunction::ForwardOvertravel));
+    /// assert_eq!(DiFunction::from_raw(45), DiFunction::Unknown(45));
+    /// assert_eq!(DiFunction::Unknown(13), DiFunction::ForwardOvertravel);
+    /// assert_ne!(DiFunction::Unknown(14), DiFunction::ForwardOvertravel);
+    /// ```
+    pub fn from_raw(value: u16) -> Self {
+        Self::try_from(value).unwrap_or(DiFunction::Unknown(value))
+    }
+}
+
+impl TryFrom<u16> for DiFunction {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(DiFunction::None),
+            1 => Ok(DiFunction::ServoEnable),
+            2 => Ok(DiFunction::AlarmResetSignal),
+            3 => Ok(DiFunction::ProportionalActionSwitch),
+            4 => Ok(DiFunction::MainAuxiliaryCommandSwitch),
+            5 => Ok(DiFunction::PulseDeviationClear),
+            6 => Ok(DiFunction::MultiSegCommandSwitch1),
+            7 => Ok(DiFunction::MultiSegCommandSwitch2),
+            8 => Ok(DiFunction::MultiSegCommandSwitch3),
+            9 => Ok(DiFunction::MultiSegCommandSwitch4),
+            10 => Ok(DiFunction::PModeSwitch),
+            11 => Ok(DiFunction::ZeroFixedEnable),
+            12 => Ok(DiFunction::PulseProhibition),
+            13 => Ok(DiFunction::ForwardOvertravel),
+            14 => Ok(DiFunction::BackwardOvertravel),
+            15 => Ok(DiFunction::ForwardExternalTorqueLimit),
+            16 => Ok(DiFunction::BackwardExternalTorqueLimit),
+            17 => Ok(DiFunction::ForwardJog2),
+            18 => Ok(DiFunction::BackwardJog),
+            19 => Ok(DiFunction::PositionStepInputDI),
+            20 => Ok(DiFunction::HandwheelMagnification1),
+            21 => Ok(DiFunction::HandwheelMagnification2),
+            22 => Ok(DiFunction::HandwheelEnable),
+            23 => Ok(DiFunction::ElectronicGearSelection),
+            24 => Ok(DiFunction::PositionInstructionReverse),
+            25 => Ok(DiFunction::SpeedCommandReverse),
+            26 => Ok(DiFunction::TorqueCommandReverse),
+            27 => Ok(DiFunction::HandwheelSignalA),
+            28 => Ok(DiFunction::HandwheelSignalB),
+            29 => Ok(DiFunction::InternalMultiSegmentPositionEnable),
+            30 => Ok(DiFunction::InterruptFixedLengthCompletionExtConfirm),
+            31 => Ok(DiFunction::InterruptFixedLengthProhibition),
+            32 => Ok(DiFunction::HomeSwitchSignal),
+            33 => Ok(DiFunction::HomingEnableSignal),
+            34 => Ok(DiFunction::EmergencyStop),
+            35 => Ok(DiFunction::PositionLoopConstantSpeedRunning),
+            36 => Ok(DiFunction::InterruptFixedLengthReset),
+            37 => Ok(DiFunction::InterruptFixedLengthOperationPause),
+            38 => Ok(DiFunction::MultiSegmentTorqueCommandSwitch1),
+            39 => Ok(DiFunction::MultiStepTorqueCommandSwitch1),
+            40 => Ok(DiFunction::SpeedModeA1SW1),
+            41 => Ok(DiFunction::SpeedModeA1SW2),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DI function: {}",
+                value
+            ))),
+        }
     }
 }
 
 /// Digital input logic selection (P02.11-P02.13)
+///
+/// # Example
+/// ```
+/// use dsyrs::DiLogic;
+///
+/// for raw in 0..=4 {
+///     assert_eq!(u16::from(DiLogic::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(DiLogic::try_from(5).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum DiLogic {
     /// Low level active
@@ -331,9 +812,37 @@ impl From<DiLogic> for u16 {
     }
 }
 
+impl TryFrom<u16> for DiLogic {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(DiLogic::LowActive),
+            1 => Ok(DiLogic::HighActive),
+            2 => Ok(DiLogic::RisingEdge),
+            3 => Ok(DiLogic::FallingEdge),
+            4 => Ok(DiLogic::BothEdges),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DI logic: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Digital output function selection (P02.21-P02.22)
 /// Values 1-25 correspond to FunOUT.1-25
+///
+/// # Example
+/// ```
+/// use dsyrs::DoFunction;
+///
+/// for raw in 0..=24 {
+///     assert_eq!(u16::from(DoFunction::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(DoFunction::try_from(25).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum DoFunction {
     /// No function assigned
@@ -395,8 +904,56 @@ impl From<DoFunction> for u16 {
     }
 }
 
+impl TryFrom<u16> for DoFunction {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(DoFunction::None),
+            1 => Ok(DoFunction::ServoReady),
+            2 => Ok(DoFunction::FaultOutputSignal),
+            3 => Ok(DoFunction::WarningOutputSignal),
+            4 => Ok(DoFunction::MotorRotationOutputSignal),
+            5 => Ok(DoFunction::ZeroSpeedSignal),
+            6 => Ok(DoFunction::SpeedConsistent),
+            7 => Ok(DoFunction::PositionCompleted),
+            8 => Ok(DoFunction::PositioningApproachSignal),
+            9 => Ok(DoFunction::TorqueLimitSignal),
+            10 => Ok(DoFunction::SpeedLimitSignal),
+            11 => Ok(DoFunction::BrakeReleaseSignalOutput),
+            12 => Ok(DoFunction::TorqueFeedbackReachesRange),
+            13 => Ok(DoFunction::SpeedFeedbackReachesRange),
+            14 => Ok(DoFunction::AngleRecognitionCompleted),
+            15 => Ok(DoFunction::OutputAlarmCode1),
+            16 => Ok(DoFunction::OutputAlarmCode2),
+            17 => Ok(DoFunction::OutputAlarmCode3),
+            18 => Ok(DoFunction::InterruptFixedLengthCompletionSignal),
+            19 => Ok(DoFunction::HomingCompletionSignal),
+            20 => Ok(DoFunction::Reserved20),
+            21 => Ok(DoFunction::MultiSegmentPositionCompletion1),
+            22 => Ok(DoFunction::MultiSegmentPositionCompletion2),
+            23 => Ok(DoFunction::MultiSegmentPositionCompletion3),
+            24 => Ok(DoFunction::MultiSegmentPositionCompletion4),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DO function: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Digital output logic (P02.31-P02.32)
+///
+/// # Example
+/// ```
+/// use dsyrs::DoLogic;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(DoLogic::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(DoLogic::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum DoLogic {
     /// Normally open (conduct when active)
@@ -412,12 +969,37 @@ impl From<DoLogic> for u16 {
     }
 }
 
+impl TryFrom<u16> for DoLogic {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(DoLogic::NormallyOpen),
+            1 => Ok(DoLogic::NormallyClosed),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid DO logic: {}",
+                value
+            ))),
+        }
+    }
+}
+
 // ============================================================================
 // P04 - Position Control Parameter Enums
 // ============================================================================
 
 /// Position command source (P04.00)
+///
+/// # Example
+/// ```
+/// use dsyrs::PositionCmdSource;
+///
+/// for raw in [0, 1, 2, 4, 5] {
+///     assert_eq!(u16::from(PositionCmdSource::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(PositionCmdSource::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum PositionCmdSource {
     /// Low-speed pulse input
@@ -439,8 +1021,36 @@ impl From<PositionCmdSource> for u16 {
     }
 }
 
+impl TryFrom<u16> for PositionCmdSource {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(PositionCmdSource::LowSpeedPulse),
+            1 => Ok(PositionCmdSource::HighSpeedPulse),
+            2 => Ok(PositionCmdSource::StepAmount),
+            4 => Ok(PositionCmdSource::MultiSegment),
+            5 => Ok(PositionCmdSource::Communication),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid position command source: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Pulse shape (P04.21)
+///
+/// # Example
+/// ```
+/// use dsyrs::PulseShape;
+///
+/// for raw in 0..=5 {
+///     assert_eq!(u16::from(PulseShape::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(PulseShape::try_from(6).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum PulseShape {
     /// Pulse + Direction, positive logic
@@ -464,8 +1074,37 @@ impl From<PulseShape> for u16 {
     }
 }
 
+impl TryFrom<u16> for PulseShape {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(PulseShape::PulseDirPos),
+            1 => Ok(PulseShape::DirPulseNeg),
+            2 => Ok(PulseShape::QuadPos),
+            3 => Ok(PulseShape::QuadNeg),
+            4 => Ok(PulseShape::CcwCwPos),
+            5 => Ok(PulseShape::CcwCwNeg),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid pulse shape: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Position deviation clear mode (P04.22)
+///
+/// # Example
+/// ```
+/// use dsyrs::DeviationClearMode;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(DeviationClearMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(DeviationClearMode::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum DeviationClearMode {
     /// Clear on fault or servo OFF
@@ -483,353 +1122,1712 @@ impl From<DeviationClearMode> for u16 {
     }
 }
 
-// ============================================================================
-// P10 - Communication Parameter Enums
-// ============================================================================
+impl TryFrom<u16> for DeviationClearMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(DeviationClearMode::OnFaultOrOff),
+            1 => Ok(DeviationClearMode::OnFault),
+            2 => Ok(DeviationClearMode::ByDi),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid deviation clear mode: {}",
+                value
+            ))),
+        }
+    }
+}
 
-/// Modbus baud rate setting (P10.02)
+/// COIN (positioning completed, FunOUT.7) output condition (P04.23)
+///
+/// # Example
+/// ```
+/// use dsyrs::CoinCondition;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(CoinCondition::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(CoinCondition::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum BaudRate {
-    /// 2400 bps
-    Baud2400 = 0,
-    /// 4800 bps
-    Baud4800 = 1,
-    /// 9600 bps
-    Baud9600 = 2,
-    /// 19200 bps
-    Baud19200 = 3,
-    /// 38400 bps
-    Baud38400 = 4,
-    /// 57600 bps
-    Baud57600 = 5,
-    /// 115200 bps
+pub enum CoinCondition {
+    /// Deviation within the completion range (P04.24)
     #[default]
-    Baud115200 = 6,
+    DeviationInRange = 0,
+    /// Deviation within range and filtered position command at zero
+    FilteredCommandZero = 1,
+    /// Deviation within range and position command at zero
+    CommandZero = 2,
 }
 
-impl From<BaudRate> for u16 {
-    fn from(br: BaudRate) -> Self {
-        br as u16
+impl From<CoinCondition> for u16 {
+    fn from(condition: CoinCondition) -> Self {
+        condition as u16
     }
 }
 
-impl BaudRate {
-    /// Get the actual baud rate value
-    pub fn to_bps(self) -> u32 {
-        match self {
-            BaudRate::Baud2400 => 2400,
-            BaudRate::Baud4800 => 4800,
-            BaudRate::Baud9600 => 9600,
-            BaudRate::Baud19200 => 19200,
-            BaudRate::Baud38400 => 38400,
-            BaudRate::Baud57600 => 57600,
-            BaudRate::Baud115200 => 115200,
+impl TryFrom<u16> for CoinCondition {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(CoinCondition::DeviationInRange),
+            1 => Ok(CoinCondition::FilteredCommandZero),
+            2 => Ok(CoinCondition::CommandZero),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid COIN condition: {}",
+                value
+            ))),
         }
     }
 }
 
-/// Modbus data format (P10.03)
+// ============================================================================
+// P05 - Speed Control Parameter Enums
+// ============================================================================
+
+/// Main speed command A source (P05.00)
+///
+/// # Example
+/// ```
+/// use dsyrs::SpeedCmdSource;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(SpeedCmdSource::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(SpeedCmdSource::try_from(3).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum DataFormat {
-    /// No parity, 2 stop bits
+pub enum SpeedCmdSource {
+    /// Digit value (P05.03)
     #[default]
-    NoParity2Stop = 0,
-    /// Even parity, 1 stop bit
-    EvenParity1Stop = 1,
-    /// Odd parity, 1 stop bit
-    OddParity1Stop = 2,
-    /// No parity, 1 stop bit
-    NoParity1Stop = 3,
+    DigitValue = 0,
+    /// Reserved
+    Reserved1 = 1,
+    /// Reserved
+    Reserved2 = 2,
 }
 
-impl From<DataFormat> for u16 {
-    fn from(fmt: DataFormat) -> Self {
-        fmt as u16
+impl From<SpeedCmdSource> for u16 {
+    fn from(src: SpeedCmdSource) -> Self {
+        src as u16
     }
 }
 
-/// RS485 address source (P10.06)
+impl TryFrom<u16> for SpeedCmdSource {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(SpeedCmdSource::DigitValue),
+            1 => Ok(SpeedCmdSource::Reserved1),
+            2 => Ok(SpeedCmdSource::Reserved2),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid speed command source: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Auxiliary speed command B source (P05.01)
+///
+/// # Example
+/// ```
+/// use dsyrs::AuxSpeedSource;
+///
+/// for raw in 0..=3 {
+///     assert_eq!(u16::from(AuxSpeedSource::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(AuxSpeedSource::try_from(4).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum AddressSource {
-    /// Use DIP switch setting
+pub enum AuxSpeedSource {
+    /// Digit value (P05.03)
+    DigitValue = 0,
+    /// Reserved
+    Reserved1 = 1,
+    /// Reserved
+    Reserved2 = 2,
+    /// Multi-speed command (P14)
     #[default]
-    DipSwitch = 0,
-    /// Use host setting (P10.00)
-    HostSetting = 1,
+    MultiSpeedCommand = 3,
 }
 
-impl From<AddressSource> for u16 {
-    fn from(src: AddressSource) -> Self {
+impl From<AuxSpeedSource> for u16 {
+    fn from(src: AuxSpeedSource) -> Self {
         src as u16
     }
 }
 
-// ============================================================================
-// P11 - Auxiliary Function Parameter Enums
-// ============================================================================
+impl TryFrom<u16> for AuxSpeedSource {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(AuxSpeedSource::DigitValue),
+            1 => Ok(AuxSpeedSource::Reserved1),
+            2 => Ok(AuxSpeedSource::Reserved2),
+            3 => Ok(AuxSpeedSource::MultiSpeedCommand),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid auxiliary speed source: {}",
+                value
+            ))),
+        }
+    }
+}
 
-/// System initialization command (P11.09)
+/// Speed command selection (P05.02)
+///
+/// Value 1 is not documented and is rejected.
+///
+/// # Example
+/// ```
+/// use dsyrs::SpeedCmdSelect;
+///
+/// for raw in [0, 2, 3] {
+///     assert_eq!(u16::from(SpeedCmdSelect::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(SpeedCmdSelect::try_from(1).is_err());
+/// assert!(SpeedCmdSelect::try_from(4).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum SystemInit {
-    /// No action
+pub enum SpeedCmdSelect {
+    /// Main speed command A (P05.00)
     #[default]
-    None = 0,
-    /// Factory reset (except P01 & P17)
-    FactoryReset = 1,
-    /// Clear fault record
-    ClearFaultRecord = 2,
+    A = 0,
+    /// Auxiliary speed command B (P05.01)
+    B = 2,
+    /// A/B switching by the MainAuxiliaryCommandSwitch input (FunIN.4)
+    ABSwitch = 3,
 }
 
-impl From<SystemInit> for u16 {
-    fn from(init: SystemInit) -> Self {
-        init as u16
+impl From<SpeedCmdSelect> for u16 {
+    fn from(select: SpeedCmdSelect) -> Self {
+        select as u16
     }
 }
 
-/// Absolute encoder reset command (P11.06)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-#[repr(u16)]
-pub enum EncoderReset {
-    /// No action
-    #[default]
-    None = 0,
-    /// Clear warnings/errors
-    ClearWarnings = 1,
-    /// Reset multi-turn data
-    ResetMultiTurn = 2,
-}
-
-impl From<EncoderReset> for u16 {
-    fn from(reset: EncoderReset) -> Self {
-        reset as u16
+impl TryFrom<u16> for SpeedCmdSelect {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(SpeedCmdSelect::A),
+            2 => Ok(SpeedCmdSelect::B),
+            3 => Ok(SpeedCmdSelect::ABSwitch),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid speed command selection: {}",
+                value
+            ))),
+        }
     }
 }
 
 // ============================================================================
-// P13 - Multi-Segment Position Parameter Enums
+// P06 - Torque Control Parameter Enums
 // ============================================================================
 
-/// Multi-segment operation mode (P13.00)
+/// Torque command selection (P06.02)
+///
+/// # Example
+/// ```
+/// use dsyrs::TorqueCmdSelect;
+///
+/// for raw in 0..=3 {
+///     assert_eq!(u16::from(TorqueCmdSelect::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(TorqueCmdSelect::try_from(4).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum MultiSegOperationMode {
-    /// Single execution
-    Single = 0,
-    /// Cycle execution
+pub enum TorqueCmdSelect {
+    /// Main torque command A
     #[default]
-    Cycle = 1,
-    /// DI switch selection
-    DiSwitch = 2,
+    A = 0,
+    /// Auxiliary torque command B
+    B = 1,
+    /// A + B
+    APlusB = 2,
+    /// A/B switching
+    ABSwitch = 3,
 }
 
-impl From<MultiSegOperationMode> for u16 {
-    fn from(mode: MultiSegOperationMode) -> Self {
-        mode as u16
+impl From<TorqueCmdSelect> for u16 {
+    fn from(select: TorqueCmdSelect) -> Self {
+        select as u16
     }
 }
 
-/// Multi-segment position mode (P13.05)
+impl TryFrom<u16> for TorqueCmdSelect {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(TorqueCmdSelect::A),
+            1 => Ok(TorqueCmdSelect::B),
+            2 => Ok(TorqueCmdSelect::APlusB),
+            3 => Ok(TorqueCmdSelect::ABSwitch),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid torque command selection: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Torque limit source (P06.06)
+///
+/// # Example
+/// ```
+/// use dsyrs::TorqueLimitSource;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(TorqueLimitSource::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(TorqueLimitSource::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum MultiSegPositionMode {
-    /// Incremental positioning
+pub enum TorqueLimitSource {
+    /// Internal limits (P06.08/P06.09)
     #[default]
-    Incremental = 0,
-    /// Absolute positioning
-    Absolute = 1,
+    Internal = 0,
+    /// External limits (P06.10/P06.11), selected by P_CL/N_CL inputs
+    External = 1,
 }
 
-impl From<MultiSegPositionMode> for u16 {
-    fn from(mode: MultiSegPositionMode) -> Self {
-        mode as u16
+impl From<TorqueLimitSource> for u16 {
+    fn from(src: TorqueLimitSource) -> Self {
+        src as u16
     }
 }
 
-/// Wait time unit (P13.04)
+impl TryFrom<u16> for TorqueLimitSource {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(TorqueLimitSource::Internal),
+            1 => Ok(TorqueLimitSource::External),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid torque limit source: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Speed limit source in torque control (P06.13)
+///
+/// # Example
+/// ```
+/// use dsyrs::TorqueSpeedLimitSource;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(TorqueSpeedLimitSource::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(TorqueSpeedLimitSource::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum WaitTimeUnit {
-    /// Milliseconds
+pub enum TorqueSpeedLimitSource {
+    /// Internal limits (P06.15/P06.16)
     #[default]
-    Milliseconds = 0,
-    /// Seconds
-    Seconds = 1,
+    Internal = 0,
+    /// Reserved
+    Reserved = 1,
 }
 
-impl From<WaitTimeUnit> for u16 {
-    fn from(unit: WaitTimeUnit) -> Self {
-        unit as u16
+impl From<TorqueSpeedLimitSource> for u16 {
+    fn from(src: TorqueSpeedLimitSource) -> Self {
+        src as u16
+    }
+}
+
+impl TryFrom<u16> for TorqueSpeedLimitSource {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(TorqueSpeedLimitSource::Internal),
+            1 => Ok(TorqueSpeedLimitSource::Reserved),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid torque speed limit source: {}",
+                value
+            ))),
+        }
     }
 }
 
 // ============================================================================
-// P16 - Special Function Parameter Enums
+// P07 - Gain Parameter Enums
 // ============================================================================
 
-/// Homing mode (P16.09)
+/// Gain switching action (P07.10)
+///
+/// # Example
+/// ```
+/// use dsyrs::GainSwitchAction;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(GainSwitchAction::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(GainSwitchAction::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum HomingMode {
-    /// Mode 0: Forward + limit switch + Z pulse
+pub enum GainSwitchAction {
+    /// PI/P switching, gain set 1 fixed
     #[default]
-    Mode0 = 0,
-    /// Mode 1: Reverse + limit switch + Z pulse
-    Mode1 = 1,
-    /// Mode 2: Forward + home switch + Z pulse
-    Mode2 = 2,
-    /// Mode 3: Reverse + home switch + Z pulse
-    Mode3 = 3,
-    /// Mode 4: Forward + limit switch
-    Mode4 = 4,
-    /// Mode 5: Reverse + limit switch
-    Mode5 = 5,
-    /// Mode 6: Forward + home switch
-    Mode6 = 6,
-    /// Mode 7: Reverse + home switch
-    Mode7 = 7,
-    /// Mode 8: Z pulse only (forward)
-    Mode8 = 8,
-    /// Mode 9: Z pulse only (reverse)
-    Mode9 = 9,
-    /// Mode 10: Current position as home
-    Mode10 = 10,
-    /// Mode 11-17: Additional modes (see manual)
-    Mode11 = 11,
-    Mode12 = 12,
-    Mode13 = 13,
-    Mode14 = 14,
-    Mode15 = 15,
-    Mode16 = 16,
-    Mode17 = 17,
+    PiPSwitch = 0,
+    /// Gain1/Gain2 switching, according to the mode in P07.11
+    Gain1Gain2 = 1,
 }
 
-impl From<HomingMode> for u16 {
-    fn from(mode: HomingMode) -> Self {
-        mode as u16
+impl From<GainSwitchAction> for u16 {
+    fn from(action: GainSwitchAction) -> Self {
+        action as u16
     }
 }
 
-/// Homing enable control mode (P16.08)
+impl TryFrom<u16> for GainSwitchAction {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(GainSwitchAction::PiPSwitch),
+            1 => Ok(GainSwitchAction::Gain1Gain2),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid gain switch action: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Gain set selected by a host-commanded switch (P07.11 fixed modes)
+///
+/// # Example
+/// ```
+/// use dsyrs::GainGroup;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(GainGroup::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(GainGroup::try_from(2).is_err());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
-pub enum HomingEnableMode {
-    /// Mode 0: Turn off the Homing function
+pub enum GainGroup {
+    /// Gain set 1 (P07.00-P07.03)
     #[default]
-    Disabled = 0,
-    /// Mode 1: Enable the Homing function by inputting the HomingStart signal through DI
-    EnableViaDI = 1,
-    /// Mode 2: Start return to Home immediately after power-on
-    StartAfterPowerOn = 2,
-    /// Mode 3: Start return to Home immediately
-    StartImmediately = 3,
-    /// Mode 4: Take the current position as the Home
-    CurrentPositionAsHome = 4,
-    /// Mode 5: Set the Home through DI trigger
-    SetHomeThroughDI = 5,
-    /// Mode 6: Host computer homing
-    HostComputerHoming = 6,
+    Gain1 = 0,
+    /// Gain set 2 (P07.05-P07.06)
+    Gain2 = 1,
 }
 
-impl From<HomingEnableMode> for u16 {
-    fn from(mode: HomingEnableMode) -> Self {
-        mode as u16
+impl From<GainGroup> for u16 {
+    fn from(group: GainGroup) -> Self {
+        group as u16
     }
 }
 
-// ============================================================================
-// P18 - Status Enums
-// ============================================================================
-
-/// Servo status (P18.00)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ServoState {
-    /// Servo ready
-    Ready,
-    /// Servo running
-    Running,
-    /// Servo error
-    Error,
-    /// Servo alarm
-    Alarm,
-    /// Unknown state
-    Unknown(u16),
-}
-
-impl From<u16> for ServoState {
-    fn from(value: u16) -> Self {
-        // Status interpretation depends on actual hardware implementation
-        // This is a simplified mapping
-        match value & 0x0F {
-            0 => ServoState::Ready,
-            1 => ServoState::Running,
-            2 => ServoState::Error,
-            3 => ServoState::Alarm,
-            _ => ServoState::Unknown(value),
+impl TryFrom<u16> for GainGroup {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(GainGroup::Gain1),
+            1 => Ok(GainGroup::Gain2),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid gain group: {}",
+                value
+            ))),
         }
     }
 }
 
 // ============================================================================
-// Configuration Structures
+// P08 - Advanced Adjustment Parameter Enums
 // ============================================================================
 
-/// Servo drive configuration
-#[derive(Debug, Clone)]
-pub struct ServoConfig {
-    /// Modbus slave ID (1-247)
-    pub slave_id: u8,
-    /// Control mode
-    pub control_mode: ControlMode,
-    /// Rotation direction
-    pub direction: Direction,
-    /// Maximum speed (rpm)
-    pub max_speed: u16,
-    /// Motor model code (P01.00) - read from servo if None
-    pub motor_model_code: Option<u16>,
-    /// Rated current (A) (P01.04) - read from servo if None
-    pub rated_current: Option<f32>,
-    /// Encoder type (P01.18) - read from servo if None
-    pub encoder_type: Option<EncoderType>,
-    /// Encoder resolution (P01.20) - read from servo if None
-    pub encoder_resolution: Option<u32>,
+/// Inertia identification mode (P08.23)
+///
+/// # Example
+/// ```
+/// use dsyrs::InertiaIdMode;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(InertiaIdMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(InertiaIdMode::try_from(2).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum InertiaIdMode {
+    /// Offline, triangle wave in both directions (+/−)
+    #[default]
+    OfflineTriangle = 0,
+    /// Offline, JOG motion
+    OfflineJog = 1,
 }
 
-impl ServoConfig {
-    /// Create a new servo configuration with default values
-    pub fn new(slave_id: u8) -> Self {
-        Self {
-            slave_id,
-            control_mode: ControlMode::Position,
-            direction: Direction::CcwForward,
-            max_speed: 4500,
-            motor_model_code: None,
-            rated_current: None,
-            encoder_type: None,
-            encoder_resolution: None,
-        }
-    }
-
-    /// Set control mode
-    pub fn with_control_mode(mut self, mode: ControlMode) -> Self {
-        self.control_mode = mode;
-        self
+impl From<InertiaIdMode> for u16 {
+    fn from(mode: InertiaIdMode) -> Self {
+        mode as u16
     }
+}
 
-    /// Set direction
-    pub fn with_direction(mut self, direction: Direction) -> Self {
-        self.direction = direction;
-        self
+impl TryFrom<u16> for InertiaIdMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(InertiaIdMode::OfflineTriangle),
+            1 => Ok(InertiaIdMode::OfflineJog),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid inertia identification mode: {}",
+                value
+            ))),
+        }
     }
+}
 
-    /// Set maximum speed
-    pub fn with_max_speed(mut self, rpm: u16) -> Self {
-        self.max_speed = rpm;
-        self
-    }
+// ============================================================================
+// P10 - Communication Parameter Enums
+// ============================================================================
+
+/// Modbus baud rate setting (P10.02)
+///
+/// # Example
+/// ```
+/// use dsyrs::BaudRate;
+///
+/// for raw in 0..=6 {
+///     assert_eq!(u16::from(BaudRate::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(BaudRate::try_from(7).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum BaudRate {
+    /// 2400 bps
+    Baud2400 = 0,
+    /// 4800 bps
+    Baud4800 = 1,
+    /// 9600 bps
+    Baud9600 = 2,
+    /// 19200 bps
+    Baud19200 = 3,
+    /// 38400 bps
+    Baud38400 = 4,
+    /// 57600 bps
+    Baud57600 = 5,
+    /// 115200 bps
+    #[default]
+    Baud115200 = 6,
+}
+
+impl From<BaudRate> for u16 {
+    fn from(br: BaudRate) -> Self {
+        br as u16
+    }
+}
+
+impl TryFrom<u16> for BaudRate {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(BaudRate::Baud2400),
+            1 => Ok(BaudRate::Baud4800),
+            2 => Ok(BaudRate::Baud9600),
+            3 => Ok(BaudRate::Baud19200),
+            4 => Ok(BaudRate::Baud38400),
+            5 => Ok(BaudRate::Baud57600),
+            6 => Ok(BaudRate::Baud115200),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid baud rate: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl BaudRate {
+    /// Get the actual baud rate value
+    pub fn to_bps(self) -> u32 {
+        match self {
+            BaudRate::Baud2400 => 2400,
+            BaudRate::Baud4800 => 4800,
+            BaudRate::Baud9600 => 9600,
+            BaudRate::Baud19200 => 19200,
+            BaudRate::Baud38400 => 38400,
+            BaudRate::Baud57600 => 57600,
+            BaudRate::Baud115200 => 115200,
+        }
+    }
+}
+
+/// Modbus data format (P10.03)
+///
+/// # Example
+/// ```
+/// use dsyrs::DataFormat;
+///
+/// for raw in 0..=3 {
+///     assert_eq!(u16::from(DataFormat::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(DataFormat::try_from(4).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum DataFormat {
+    /// No parity, 2 stop bits
+    #[default]
+    NoParity2Stop = 0,
+    /// Even parity, 1 stop bit
+    EvenParity1Stop = 1,
+    /// Odd parity, 1 stop bit
+    OddParity1Stop = 2,
+    /// No parity, 1 stop bit
+    NoParity1Stop = 3,
+}
+
+impl From<DataFormat> for u16 {
+    fn from(fmt: DataFormat) -> Self {
+        fmt as u16
+    }
+}
+
+impl TryFrom<u16> for DataFormat {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(DataFormat::NoParity2Stop),
+            1 => Ok(DataFormat::EvenParity1Stop),
+            2 => Ok(DataFormat::OddParity1Stop),
+            3 => Ok(DataFormat::NoParity1Stop),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid data format: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// RS485 address source (P10.06)
+///
+/// # Example
+/// ```
+/// use dsyrs::AddressSource;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(AddressSource::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(AddressSource::try_from(2).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum AddressSource {
+    /// Use DIP switch setting
+    #[default]
+    DipSwitch = 0,
+    /// Use host setting (P10.00)
+    HostSetting = 1,
+}
+
+impl From<AddressSource> for u16 {
+    fn from(src: AddressSource) -> Self {
+        src as u16
+    }
+}
+
+impl TryFrom<u16> for AddressSource {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(AddressSource::DipSwitch),
+            1 => Ok(AddressSource::HostSetting),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid address source: {}",
+                value
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// P11 - Auxiliary Function Parameter Enums
+// ============================================================================
+
+/// System initialization command (P11.09)
+///
+/// # Example
+/// ```
+/// use dsyrs::SystemInit;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(SystemInit::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(SystemInit::try_from(3).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum SystemInit {
+    /// No action
+    #[default]
+    None = 0,
+    /// Factory reset (except P01 & P17)
+    FactoryReset = 1,
+    /// Clear fault record
+    ClearFaultRecord = 2,
+}
+
+impl From<SystemInit> for u16 {
+    fn from(init: SystemInit) -> Self {
+        init as u16
+    }
+}
+
+impl TryFrom<u16> for SystemInit {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(SystemInit::None),
+            1 => Ok(SystemInit::FactoryReset),
+            2 => Ok(SystemInit::ClearFaultRecord),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid system initialization command: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Absolute encoder reset command (P11.06)
+///
+/// # Example
+/// ```
+/// use dsyrs::EncoderReset;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(EncoderReset::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(EncoderReset::try_from(3).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum EncoderReset {
+    /// No action
+    #[default]
+    None = 0,
+    /// Clear warnings/errors
+    ClearWarnings = 1,
+    /// Reset multi-turn data
+    ResetMultiTurn = 2,
+}
+
+impl From<EncoderReset> for u16 {
+    fn from(reset: EncoderReset) -> Self {
+        reset as u16
+    }
+}
+
+impl TryFrom<u16> for EncoderReset {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(EncoderReset::None),
+            1 => Ok(EncoderReset::ClearWarnings),
+            2 => Ok(EncoderReset::ResetMultiTurn),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid encoder reset command: {}",
+                value
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// P13 - Multi-Segment Position Parameter Enums
+// ============================================================================
+
+/// Multi-segment operation mode (P13.00)
+///
+/// # Example
+/// ```
+/// use dsyrs::MultiSegOperationMode;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(MultiSegOperationMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(MultiSegOperationMode::try_from(3).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum MultiSegOperationMode {
+    /// Single execution
+    Single = 0,
+    /// Cycle execution
+    #[default]
+    Cycle = 1,
+    /// DI switch selection
+    DiSwitch = 2,
+}
+
+impl From<MultiSegOperationMode> for u16 {
+    fn from(mode: MultiSegOperationMode) -> Self {
+        mode as u16
+    }
+}
+
+impl TryFrom<u16> for MultiSegOperationMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(MultiSegOperationMode::Single),
+            1 => Ok(MultiSegOperationMode::Cycle),
+            2 => Ok(MultiSegOperationMode::DiSwitch),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid multi-segment operation mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Multi-segment position mode (P13.05)
+///
+/// # Example
+/// ```
+/// use dsyrs::MultiSegPositionMode;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(MultiSegPositionMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(MultiSegPositionMode::try_from(2).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum MultiSegPositionMode {
+    /// Incremental positioning
+    #[default]
+    Incremental = 0,
+    /// Absolute positioning
+    Absolute = 1,
+}
+
+impl From<MultiSegPositionMode> for u16 {
+    fn from(mode: MultiSegPositionMode) -> Self {
+        mode as u16
+    }
+}
+
+impl TryFrom<u16> for MultiSegPositionMode {
+    type Error = DsyrsError;
+
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(MultiSegPositionMode::Incremental),
+            1 => Ok(MultiSegPositionMode::Absolute),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid multi-segment position mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Wait time unit (P13.04)
+///
+/// # Example
+/// ```
+/// use dsyrs::WaitTimeUnit;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(WaitTimeUnit::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(WaitTimeUnit::try_from(2).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum WaitTimeUnit {
+    /// Milliseconds
+    #[default]
+    Milliseconds = 0,
+    /// Seconds
+    Seconds = 1,
+}
+
+impl From<WaitTimeUnit> for u16 {
+    fn from(unit: WaitTimeUnit) -> Self {
+        unit as u16
+    }
+}
+
+impl TryFrom<u16> for WaitTimeUnit {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(WaitTimeUnit::Milliseconds),
+            1 => Ok(WaitTimeUnit::Seconds),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid wait time unit: {}",
+                value
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// P14 - Multi-Speed Parameter Enums
+// ============================================================================
+
+/// Multi-speed operation mode (P14.00)
+///
+/// # Example
+/// ```
+/// use dsyrs::MultiSpeedOperationMode;
+///
+/// for raw in 0..=2 {
+///     assert_eq!(u16::from(MultiSpeedOperationMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(MultiSpeedOperationMode::try_from(3).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum MultiSpeedOperationMode {
+    /// Single run, stops after the end segment
+    Single = 0,
+    /// Cycle through segments 1 to the end segment
+    #[default]
+    Cycle = 1,
+    /// Segment selected by DI
+    DiSwitch = 2,
+}
+
+impl From<MultiSpeedOperationMode> for u16 {
+    fn from(mode: MultiSpeedOperationMode) -> Self {
+        mode as u16
+    }
+}
+
+impl TryFrom<u16> for MultiSpeedOperationMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(MultiSpeedOperationMode::Single),
+            1 => Ok(MultiSpeedOperationMode::Cycle),
+            2 => Ok(MultiSpeedOperationMode::DiSwitch),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid multi-speed operation mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Multi-speed run time unit (P14.02)
+///
+/// # Example
+/// ```
+/// use dsyrs::MultiSpeedTimeUnit;
+///
+/// for raw in 0..=1 {
+///     assert_eq!(u16::from(MultiSpeedTimeUnit::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(MultiSpeedTimeUnit::try_from(2).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum MultiSpeedTimeUnit {
+    /// Seconds (run time in 0.1 s)
+    #[default]
+    Seconds = 0,
+    /// Minutes (run time in 0.1 min)
+    Minutes = 1,
+}
+
+impl From<MultiSpeedTimeUnit> for u16 {
+    fn from(unit: MultiSpeedTimeUnit) -> Self {
+        unit as u16
+    }
+}
+
+impl TryFrom<u16> for MultiSpeedTimeUnit {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(MultiSpeedTimeUnit::Seconds),
+            1 => Ok(MultiSpeedTimeUnit::Minutes),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid multi-speed time unit: {}",
+                value
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// P16 - Special Function Parameter Enums
+// ============================================================================
+
+/// Homing mode (P16.09)
+///
+/// # Example
+/// ```
+/// use dsyrs::HomingMode;
+///
+/// for raw in 0..=17 {
+///     assert_eq!(u16::from(HomingMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(HomingMode::try_from(18).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum HomingMode {
+    /// Mode 0: Forward + limit switch + Z pulse
+    #[default]
+    Mode0 = 0,
+    /// Mode 1: Reverse + limit switch + Z pulse
+    Mode1 = 1,
+    /// Mode 2: Forward + home switch + Z pulse
+    Mode2 = 2,
+    /// Mode 3: Reverse + home switch + Z pulse
+    Mode3 = 3,
+    /// Mode 4: Forward + limit switch
+    Mode4 = 4,
+    /// Mode 5: Reverse + limit switch
+    Mode5 = 5,
+    /// Mode 6: Forward + home switch
+    Mode6 = 6,
+    /// Mode 7: Reverse + home switch
+    Mode7 = 7,
+    /// Mode 8: Z pulse only (forward)
+    Mode8 = 8,
+    /// Mode 9: Z pulse only (reverse)
+    Mode9 = 9,
+    /// Mode 10: Current position as home
+    Mode10 = 10,
+    /// Mode 11-17: Additional modes (see manual)
+    Mode11 = 11,
+    Mode12 = 12,
+    Mode13 = 13,
+    Mode14 = 14,
+    Mode15 = 15,
+    Mode16 = 16,
+    Mode17 = 17,
+}
+
+impl From<HomingMode> for u16 {
+    fn from(mode: HomingMode) -> Self {
+        mode as u16
+    }
+}
+
+impl TryFrom<u16> for HomingMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(HomingMode::Mode0),
+            1 => Ok(HomingMode::Mode1),
+            2 => Ok(HomingMode::Mode2),
+            3 => Ok(HomingMode::Mode3),
+            4 => Ok(HomingMode::Mode4),
+            5 => Ok(HomingMode::Mode5),
+            6 => Ok(HomingMode::Mode6),
+            7 => Ok(HomingMode::Mode7),
+            8 => Ok(HomingMode::Mode8),
+            9 => Ok(HomingMode::Mode9),
+            10 => Ok(HomingMode::Mode10),
+            11 => Ok(HomingMode::Mode11),
+            12 => Ok(HomingMode::Mode12),
+            13 => Ok(HomingMode::Mode13),
+            14 => Ok(HomingMode::Mode14),
+            15 => Ok(HomingMode::Mode15),
+            16 => Ok(HomingMode::Mode16),
+            17 => Ok(HomingMode::Mode17),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid homing mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Homing enable control mode (P16.08)
+///
+/// # Example
+/// ```
+/// use dsyrs::HomingEnableMode;
+///
+/// for raw in 0..=6 {
+///     assert_eq!(u16::from(HomingEnableMode::try_from(raw).unwrap()), raw);
+/// }
+/// assert!(HomingEnableMode::try_from(7).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u16)]
+pub enum HomingEnableMode {
+    /// Mode 0: Turn off the Homing function
+    #[default]
+    Disabled = 0,
+    /// Mode 1: Enable the Homing function by inputting the HomingStart signal through DI
+    EnableViaDI = 1,
+    /// Mode 2: Start return to Home immediately after power-on
+    StartAfterPowerOn = 2,
+    /// Mode 3: Start return to Home immediately
+    StartImmediately = 3,
+    /// Mode 4: Take the current position as the Home
+    CurrentPositionAsHome = 4,
+    /// Mode 5: Set the Home through DI trigger
+    SetHomeThroughDI = 5,
+    /// Mode 6: Host computer homing
+    HostComputerHoming = 6,
+}
+
+impl From<HomingEnableMode> for u16 {
+    fn from(mode: HomingEnableMode) -> Self {
+        mode as u16
+    }
+}
+
+impl TryFrom<u16> for HomingEnableMode {
+    type Error = DsyrsError;
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(HomingEnableMode::Disabled),
+            1 => Ok(HomingEnableMode::EnableViaDI),
+            2 => Ok(HomingEnableMode::StartAfterPowerOn),
+            3 => Ok(HomingEnableMode::StartImmediately),
+            4 => Ok(HomingEnableMode::CurrentPositionAsHome),
+            5 => Ok(HomingEnableMode::SetHomeThroughDI),
+            6 => Ok(HomingEnableMode::HostComputerHoming),
+            _ => Err(DsyrsError::InvalidParameter(format!(
+                "Invalid homing enable mode: {}",
+                value
+            ))),
+        }
+    }
+}
+
+// ============================================================================
+// P18 - Status Enums
+// ============================================================================
+
+/// Servo status (P18.00)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServoState {
+    /// Servo ready
+    Ready,
+    /// Servo running
+    Running,
+    /// Servo error
+    Error,
+    /// Servo alarm
+    Alarm,
+    /// Unknown state
+    Unknown(u16),
+}
+
+impl From<u16> for ServoState {
+    fn from(value: u16) -> Self {
+        // Status interpretation depends on actual hardware implementation
+        // This is a simplified mapping
+        match value & 0x0F {
+            0 => ServoState::Ready,
+            1 => ServoState::Running,
+            2 => ServoState::Error,
+            3 => ServoState::Alarm,
+            _ => ServoState::Unknown(value),
+        }
+    }
+}
+
+/// Decoded P18.00 servo status word
+///
+/// The parameter chapter only lists the P18.00 states (Ready / Run / Err / AL).
+/// Like [`ServoState`], this assumes the state in bits 0-3 and the active
+/// fault or warning code in bits 4-15:
+///
+/// | Bits | Content |
+/// |------|---------|
+/// | 0-3  | 0 = Ready, 1 = Run, 2 = Err, 3 = AL |
+/// | 4-15 | Fault (Err) or warning (AL) code, 0 otherwise |
+///
+/// The states are exclusive, so at most one of `ready`, `enabled`, `fault` and
+/// `warning` is set. Run means the servo is enabled, whether or not the motor
+/// is moving; Ready means powered and disabled. Motion and position reached
+/// are not part of P18.00: compare the speed feedback (P18.01) and absolute
+/// position (P18.07) against the positioning range (P04.24) for those.
+///
+/// Nor does the parameter chapter list the Er and AL codes themselves.
+/// [`ServoFault`] and [`WarningCode`] assume the common Inovance-style
+/// numbering used by this drive family and keep any other code as `Unknown`.
+///
+/// # Example
+/// ```
+/// use dsyrs::{ServoState, ServoStatusWord};
+///
+/// // Enabled (Run), idle or moving
+/// let word = ServoStatusWord::from(0x0001);
+/// assert!(word.enabled && !word.ready && !word.fault);
+/// assert_eq!(word.state(), ServoState::Running);
+///
+/// // Ready but disabled
+/// let word = ServoStatusWord::from(0x0000);
+/// assert!(word.ready && !word.enabled);
+///
+/// // Fault Er.410
+/// let word = ServoStatusWord::from(0x4102);
+/// assert!(word.fault && !word.warning);
+/// assert_eq!(word.code, Some(0x410));
+///
+/// // Warning AL.950
+/// let word = ServoStatusWord::from(0x9503);
+/// assert!(word.warning && !word.fault && word.overtravel);
+/// assert_eq!(word.code, Some(0x950));
+/// assert_eq!(word.state(), ServoState::Alarm);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ServoStatusWord {
+    /// Raw P18.00 value
+    pub raw: u16,
+    /// Powered and disabled (Ready)
+    pub ready: bool,
+    /// Servo enabled (Run)
+    pub enabled: bool,
+    /// Latched fault (Err)
+    pub fault: bool,
+    /// Active warning (AL)
+    pub warning: bool,
+    /// Fault or warning code, `None` in Ready and Run
+    pub code: Option<u16>,
+    /// Overtravel warning (AL.950) active; the tripped side is not reported
+    pub overtravel: bool,
+}
+
+impl ServoStatusWord {
+    /// Summary state derived from the status word
+    pub fn state(&self) -> ServoState {
+        ServoState::from(self.raw)
+    }
+}
+
+impl From<u16> for ServoStatusWord {
+    fn from(raw: u16) -> Self {
+        let state = ServoState::from(raw);
+        let fault = state == ServoState::Error;
+        let warning = state == ServoState::Alarm;
+        Self {
+            raw,
+            ready: state == ServoState::Ready,
+            enabled: state == ServoState::Running,
+            fault,
+            warning,
+            code: (fault || warning).then_some(raw >> 4),
+            overtravel: warning && raw >> 4 == 0x950,
+        }
+    }
+}
+
+impl From<ServoStatusWord> for ServoState {
+    fn from(word: ServoStatusWord) -> Self {
+        word.state()
+    }
+}
+
+/// Fault code reported in P18.00 while the servo is in Error state
+///
+/// Faults latch and stop the drive until reset (P11.01). Displayed as the
+/// drive panel shows it (e.g. `Er.410`). Non-latching warnings (Alarm state)
+/// are reported separately as [`WarningCode`].
+///
+/// # Example
+/// ```
+/// use dsyrs::FaultCode;
+///
+/// assert_eq!(FaultCode::from_status(0x4102), Some(FaultCode(0x410)));
+/// assert_eq!(FaultCode(0x410).to_string(), "Er.410");
+/// assert_eq!(FaultCode::from_status(0x0001), None);
+/// assert_eq!(FaultCode::from_status(0x7303), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FaultCode(pub u16);
+
+impl FaultCode {
+    /// Decode the fault code from the P18.00 status word
+    ///
+    /// Returns `None` unless the state is Error. Like `ServoState`, this
+    /// assumes the state in the low nibble and the code in the upper 12 bits.
+    pub fn from_status(value: u16) -> Option<Self> {
+        match ServoState::from(value) {
+            ServoState::Error => Some(FaultCode(value >> 4)),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FaultCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Er.{:03X}", self.0)
+    }
+}
+
+/// Warning reported in P18.00 while the servo is in Alarm state
+///
+/// Warnings do not latch or stop the drive. The named variants follow the
+/// assumed code numbering described on [`ServoStatusWord`].
+///
+/// # Example
+/// ```
+/// use dsyrs::WarningCode;
+///
+/// assert_eq!(WarningCode::from_status(0x7303), Some(WarningCode::EncoderBatteryLow));
+/// assert_eq!(WarningCode::from_status(0x1233), Some(WarningCode::Unknown(0x123)));
+/// assert_eq!(WarningCode::from_status(0x4102), None);
+/// assert_eq!(WarningCode::MotorOverload.to_string(), "AL.909");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningCode {
+    /// Absolute encoder battery low (AL.730)
+    EncoderBatteryLow,
+    /// Motor overload approaching (AL.909)
+    MotorOverload,
+    /// Braking resistor overload (AL.920)
+    BrakingResistorOverload,
+    /// Parameter change takes effect after power cycle (AL.941)
+    PowerCycleRequired,
+    /// EEPROM written too frequently (AL.942)
+    FrequentEepromWrites,
+    /// Overtravel limit reached (AL.950)
+    Overtravel,
+    /// Input power phase loss (AL.990)
+    PhaseLoss,
+    /// Other warning code
+    Unknown(u16),
+}
+
+impl WarningCode {
+    /// Decode the warning from the P18.00 status word
+    ///
+    /// Returns `None` unless the state is Alarm. Uses the same layout as
+    /// [`FaultCode::from_status`].
+    pub fn from_status(value: u16) -> Option<Self> {
+        match ServoState::from(value) {
+            ServoState::Alarm => Some(WarningCode::from_code(value >> 4)),
+            _ => None,
+        }
+    }
+
+    /// Map a raw warning number to a warning
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            0x730 => WarningCode::EncoderBatteryLow,
+            0x909 => WarningCode::MotorOverload,
+            0x920 => WarningCode::BrakingResistorOverload,
+            0x941 => WarningCode::PowerCycleRequired,
+            0x942 => WarningCode::FrequentEepromWrites,
+            0x950 => WarningCode::Overtravel,
+            0x990 => WarningCode::PhaseLoss,
+            other => WarningCode::Unknown(other),
+        }
+    }
+
+    /// Absolute encoder warning (AL.730-AL.73F), acknowledged through P11.06
+    pub fn is_encoder(&self) -> bool {
+        (0x730..=0x73F).contains(&self.code())
+    }
+
+    /// Raw warning number
+    pub fn code(&self) -> u16 {
+        match self {
+            WarningCode::EncoderBatteryLow => 0x730,
+            WarningCode::MotorOverload => 0x909,
+            WarningCode::BrakingResistorOverload => 0x920,
+            WarningCode::PowerCycleRequired => 0x941,
+            WarningCode::FrequentEepromWrites => 0x942,
+            WarningCode::Overtravel => 0x950,
+            WarningCode::PhaseLoss => 0x990,
+            WarningCode::Unknown(code) => *code,
+        }
+    }
+
+    /// Human-readable description
+    pub fn description(&self) -> &'static str {
+        match self {
+            WarningCode::EncoderBatteryLow => "Absolute encoder battery low",
+            WarningCode::MotorOverload => "Motor overload approaching",
+            WarningCode::BrakingResistorOverload => "Braking resistor overload",
+            WarningCode::PowerCycleRequired => "Parameter change requires power cycle",
+            WarningCode::FrequentEepromWrites => "EEPROM written too frequently",
+            WarningCode::Overtravel => "Overtravel limit reached",
+            WarningCode::PhaseLoss => "Input power phase loss",
+            WarningCode::Unknown(_) => "Unknown warning",
+        }
+    }
+}
+
+impl std::fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AL.{:03X}", self.code())
+    }
+}
+
+/// Decoded servo fault, keeping the raw Er code for logging
+///
+/// The categories follow the assumed code numbering described on
+/// [`ServoStatusWord`]: Er.1xx parameters, Er.2xx overcurrent, Er.4xx power
+/// supply, Er.5xx overspeed, Er.601 homing timeout, Er.6xx overload/overheat,
+/// Er.7xx encoder and Er.B00 position deviation.
+///
+/// # Example
+/// ```
+/// use dsyrs::{FaultCode, ServoFault};
+///
+/// assert_eq!(ServoFault::from(0x101), ServoFault::Parameter(0x101));
+/// assert_eq!(ServoFault::from(0x201), ServoFault::Overcurrent(0x201));
+/// assert_eq!(ServoFault::from(0x400), ServoFault::Overvoltage(0x400));
+/// assert_eq!(ServoFault::from(0x410), ServoFault::Undervoltage(0x410));
+/// assert_eq!(ServoFault::from(0x430), ServoFault::Undervoltage(0x430));
+/// assert_eq!(ServoFault::from(0x420), ServoFault::PhaseLoss(0x420));
+/// assert_eq!(ServoFault::from(0x500), ServoFault::Overspeed(0x500));
+/// assert_eq!(ServoFault::from(0x601), ServoFault::HomingTimeout(0x601));
+/// assert_eq!(ServoFault::from(0x620), ServoFault::Overload(0x620));
+/// assert_eq!(ServoFault::from(0x650), ServoFault::Overheat(0x650));
+/// assert_eq!(ServoFault::from(0x740), ServoFault::Encoder(0x740));
+/// assert_eq!(ServoFault::from(0xB00), ServoFault::PositionDeviationExcessive(0xB00));
+/// assert_eq!(ServoFault::from(0xE12), ServoFault::Unknown(0xE12));
+///
+/// let fault = ServoFault::from(FaultCode(0x410));
+/// assert_eq!(fault.code(), 0x410);
+/// assert_eq!(fault.to_string(), "Er.410");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServoFault {
+    /// Parameter or system error (Er.1xx)
+    Parameter(u16),
+    /// Overcurrent or output short circuit (Er.2xx)
+    Overcurrent(u16),
+    /// Main circuit overvoltage (Er.400)
+    Overvoltage(u16),
+    /// Main circuit or control power undervoltage (Er.410, Er.430)
+    Undervoltage(u16),
+    /// Input power phase loss (Er.420)
+    PhaseLoss(u16),
+    /// Overspeed (Er.5xx)
+    Overspeed(u16),
+    /// Home not found within the homing timeout P16.13 (Er.601)
+    HomingTimeout(u16),
+    /// Drive or motor overload, locked rotor (Er.610-Er.630)
+    Overload(u16),
+    /// Heatsink overheat (Er.650)
+    Overheat(u16),
+    /// Encoder fault (Er.7xx)
+    Encoder(u16),
+    /// Position deviation excessive (Er.B00, threshold P09.09)
+    PositionDeviationExcessive(u16),
+    /// Other fault code
+    Unknown(u16),
+}
+
+impl From<u16> for ServoFault {
+    fn from(code: u16) -> Self {
+        match code {
+            0x100..=0x1FF => ServoFault::Parameter(code),
+            0x200..=0x2FF => ServoFault::Overcurrent(code),
+            0x400 => ServoFault::Overvoltage(code),
+            0x410 | 0x430 => ServoFault::Undervoltage(code),
+            0x420 => ServoFault::PhaseLoss(code),
+            0x500..=0x5FF => ServoFault::Overspeed(code),
+            0x601 => ServoFault::HomingTimeout(code),
+            0x610..=0x630 => ServoFault::Overload(code),
+            0x650 => ServoFault::Overheat(code),
+            0x700..=0x7FF => ServoFault::Encoder(code),
+            0xB00 => ServoFault::PositionDeviationExcessive(code),
+            _ => ServoFault::Unknown(code),
+        }
+    }
+}
+
+impl From<FaultCode> for ServoFault {
+    fn from(fault: FaultCode) -> Self {
+        ServoFault::from(fault.0)
+    }
+}
+
+impl ServoFault {
+    /// Raw Er code
+    pub fn code(&self) -> u16 {
+        match *self {
+            ServoFault::Parameter(code)
+            | ServoFault::Overcurrent(code)
+            | ServoFault::Overvoltage(code)
+            | ServoFault::Undervoltage(code)
+            | ServoFault::PhaseLoss(code)
+            | ServoFault::Overspeed(code)
+            | ServoFault::HomingTimeout(code)
+            | ServoFault::Overload(code)
+            | ServoFault::Overheat(code)
+            | ServoFault::Encoder(code)
+            | ServoFault::PositionDeviationExcessive(code)
+            | ServoFault::Unknown(code) => code,
+        }
+    }
+
+    /// Human-readable description
+    pub fn description(&self) -> &'static str {
+        match self {
+            ServoFault::Parameter(_) => "Parameter or system error",
+            ServoFault::Overcurrent(_) => "Overcurrent",
+            ServoFault::Overvoltage(_) => "Overvoltage",
+            ServoFault::Undervoltage(_) => "Undervoltage",
+            ServoFault::PhaseLoss(_) => "Input power phase loss",
+            ServoFault::Overspeed(_) => "Overspeed",
+            ServoFault::HomingTimeout(_) => "Homing timeout",
+            ServoFault::Overload(_) => "Overload",
+            ServoFault::Overheat(_) => "Overheat",
+            ServoFault::Encoder(_) => "Encoder fault",
+            ServoFault::PositionDeviationExcessive(_) => "Position deviation excessive",
+            ServoFault::Unknown(_) => "Unknown fault",
+        }
+    }
+}
+
+impl std::fmt::Display for ServoFault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Er.{:03X}", self.code())
+    }
+}
+
+// ============================================================================
+// Configuration Structures
+// ============================================================================
+
+/// Word order of 32-bit parameters spread over two registers
+///
+/// The parameter chapter stores 32-bit values (gear ratios, positions, ...)
+/// in two consecutive registers with the high word at the parameter address.
+/// Some firmware revisions and gateways swap the words; select `LowFirst` for
+/// those (see `read_u32_both()` to find out which order a drive uses).
+///
+/// ```
+/// use dsyrs::WordOrder;
+///
+/// assert_eq!(WordOrder::HighFirst.split(0x12345678), [0x1234, 0x5678]);
+/// assert_eq!(WordOrder::LowFirst.split(0x12345678), [0x5678, 0x1234]);
+/// for order in [WordOrder::HighFirst, WordOrder::LowFirst] {
+///     assert_eq!(order.join(order.split(0x12345678)), 0x12345678);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WordOrder {
+    /// High word at the parameter address, low word at address + 1
+    #[default]
+    HighFirst,
+    /// Low word at the parameter address, high word at address + 1
+    LowFirst,
+}
+
+impl WordOrder {
+    /// Split a 32-bit value into the two registers, in address order
+    pub fn split(self, value: u32) -> [u16; 2] {
+        let high = (value >> 16) as u16;
+        let low = (value & 0xFFFF) as u16;
+        match self {
+            WordOrder::HighFirst => [high, low],
+            WordOrder::LowFirst => [low, high],
+        }
+    }
+
+    /// Join two registers, in address order, into a 32-bit value
+    pub fn join(self, words: [u16; 2]) -> u32 {
+        let (high, low) = match self {
+            WordOrder::HighFirst => (words[0], words[1]),
+            WordOrder::LowFirst => (words[1], words[0]),
+        };
+        ((high as u32) << 16) | (low as u32)
+    }
+}
+
+/// Retry policy for transient Modbus errors
+///
+/// Applies to the low-level register reads and writes. Only transient errors
+/// are retried (see [`DsyrsError::is_transient`]); the delay doubles after
+/// each attempt, starting at `backoff`.
+///
+/// ```
+/// use dsyrs::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(3, Duration::from_millis(10));
+/// assert_eq!(policy.delay(0), Duration::from_millis(10));
+/// assert_eq!(policy.delay(2), Duration::from_millis(40));
+/// assert_eq!(RetryPolicy::default().max_retries, 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt (0 = no retry)
+    pub max_retries: u8,
+    /// Delay before the first retry
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            backoff: Duration::from_millis(50),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a retry policy
+    pub fn new(max_retries: u8, backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            backoff,
+        }
+    }
+
+    /// Delay before retry number `retry` (0-based)
+    pub fn delay(&self, retry: u8) -> Duration {
+        self.backoff
+            .saturating_mul(1u32.checked_shl(retry as u32).unwrap_or(u32::MAX))
+    }
+}
+
+/// Servo drive configuration
+///
+/// With the `serde` feature, configurations can be stored in JSON or TOML
+/// files. Enums use their variant names; optional and newer fields
+/// (`retry_policy`, `word_order`, `request_delay`, `mechanical`) may be omitted.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use dsyrs::{ControlMode, Direction, EncoderType, ServoConfig};
+///
+/// let config: ServoConfig = serde_json::from_str(
+///     r#"{
+///         "slave_id": 2,
+///         "control_mode": "Speed",
+///         "direction": "CwForward",
+///         "max_speed": 3000,
+///         "encoder_type": "Bit23Absolute"
+///     }"#,
+/// )
+/// .unwrap();
+/// assert_eq!(config.slave_id, 2);
+/// assert_eq!(config.control_mode, ControlMode::Speed);
+/// assert_eq!(config.direction, Direction::CwForward);
+/// assert_eq!(config.encoder_type, Some(EncoderType::Bit23Absolute));
+/// assert_eq!(config.rated_current, None);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServoConfig {
+    /// Modbus slave ID (1-247)
+    pub slave_id: u8,
+    /// Control mode
+    pub control_mode: ControlMode,
+    /// Rotation direction
+    pub direction: Direction,
+    /// Maximum speed (rpm)
+    pub max_speed: u16,
+    /// Motor model code (P01.00) - read from servo if None
+    pub motor_model_code: Option<u16>,
+    /// Rated current (A) (P01.04) - read from servo if None
+    pub rated_current: Option<f32>,
+    /// Encoder type (P01.18) - read from servo if None
+    pub encoder_type: Option<EncoderType>,
+    /// Encoder resolution (P01.20) - read from servo if None
+    pub encoder_resolution: Option<u32>,
+    /// Retry policy for transient Modbus errors (no retry by default)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub retry_policy: RetryPolicy,
+    /// Word order of 32-bit parameters (high word first by default)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub word_order: WordOrder,
+    /// Time limit for each Modbus transaction (none by default), see `with_timeout()`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub request_timeout: Option<Duration>,
+    /// Pause after each Modbus transaction (1 ms by default), see `with_request_delay()`
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "ServoConfig::default_request_delay")
+    )]
+    pub request_delay: Option<Duration>,
+    /// Mechanics for user-unit positions (none by default), see `with_mechanical()`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mechanical: Option<UnitConverter>,
+}
+
+impl ServoConfig {
+    /// Create a new servo configuration with default values
+    ///
+    /// Nothing is validated; prefer [`ServoConfig::builder`], which rejects an
+    /// out-of-range slave ID or maximum speed before anything is written.
+    pub fn new(slave_id: u8) -> Self {
+        Self {
+            slave_id,
+            control_mode: ControlMode::Position,
+            direction: Direction::CcwForward,
+            max_speed: 4500,
+            motor_model_code: None,
+            rated_current: None,
+            encoder_type: None,
+            encoder_resolution: None,
+            retry_policy: RetryPolicy::default(),
+            word_order: WordOrder::default(),
+            request_timeout: None,
+            request_delay: Self::default_request_delay(),
+            mechanical: None,
+        }
+    }
+
+    /// Default pause after each Modbus transaction (1 ms)
+    fn default_request_delay() -> Option<Duration> {
+        Some(Duration::from_millis(1))
+    }
+
+    /// Set control mode
+    pub fn with_control_mode(mut self, mode: ControlMode) -> Self {
+        self.control_mode = mode;
+        self
+    }
+
+    /// Set direction
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set maximum speed
+    pub fn with_max_speed(mut self, rpm: u16) -> Self {
+        self.max_speed = rpm;
+        self
+    }
 
     /// Set motor model code
     pub fn with_motor_model_code(mut self, code: u16) -> Self {
@@ -837,212 +2835,1765 @@ impl ServoConfig {
         self
     }
 
-    /// Set rated current
-    pub fn with_rated_current(mut self, current: f32) -> Self {
-        self.rated_current = Some(current);
+    /// Set rated current
+    pub fn with_rated_current(mut self, current: f32) -> Self {
+        self.rated_current = Some(current);
+        self
+    }
+
+    /// Set encoder type
+    pub fn with_encoder_type(mut self, encoder: EncoderType) -> Self {
+        self.encoder_type = Some(encoder);
+        self
+    }
+
+    /// Set encoder resolution
+    pub fn with_encoder_resolution(mut self, resolution: u32) -> Self {
+        self.encoder_resolution = Some(resolution);
+        self
+    }
+
+    /// Set retry policy for transient Modbus errors
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set word order of 32-bit parameters
+    pub fn with_word_order(mut self, order: WordOrder) -> Self {
+        self.word_order = order;
+        self
+    }
+
+    /// Bound each Modbus transaction of the async client
+    ///
+    /// [`DsyrsClient`](crate::DsyrsClient) fails a request that gets no
+    /// response within `limit` with [`DsyrsError::Timeout`], so a wedged
+    /// transaction cannot hang a call such as `get_status()`. The limit covers
+    /// one attempt: a [`RetryPolicy`] retries a timed-out attempt like any
+    /// other transient error. The blocking
+    /// [`DsyrsSyncClient`](crate::DsyrsSyncClient) relies on the timeout of
+    /// its transport instead; its `connect()` sets that timeout to this limit.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::mock::MockTransport;
+    /// use dsyrs::{DsyrsClient, DsyrsError, ServoConfig};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mock = MockTransport::new();
+    /// let config = ServoConfig::new(1).with_timeout(Duration::from_millis(50));
+    /// let mut servo = DsyrsClient::new(mock.clone(), config);
+    ///
+    /// // The drive never answers
+    /// mock.stall_next(1);
+    /// let start = Instant::now();
+    /// assert!(matches!(servo.get_speed().await, Err(DsyrsError::Timeout)));
+    /// assert!(start.elapsed() < Duration::from_millis(500));
+    /// # }
+    /// ```
+    pub fn with_timeout(mut self, limit: Duration) -> Self {
+        self.request_timeout = Some(limit);
+        self
+    }
+
+    /// Set the pause after each Modbus transaction, `None` for no pause
+    ///
+    /// Longer buses and busier ones may need a wider gap between frames. The
+    /// delay only applies with the `modbus-delay` feature, and is then taken
+    /// after every completed request, broadcasts included. The async client
+    /// waits with `tokio::time::sleep`, the sync client blocks its thread with
+    /// `thread::sleep`.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::mock::MockTransport;
+    /// use dsyrs::{DsyrsSyncClient, ServoConfig};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let config = ServoConfig::new(1).with_request_delay(Some(Duration::from_millis(20)));
+    /// let mut servo = DsyrsSyncClient::new(MockTransport::new(), config);
+    /// let start = Instant::now();
+    /// for _ in 0..5 {
+    ///     servo.get_speed()?;
+    /// }
+    /// assert!(start.elapsed() >= Duration::from_millis(100));
+    ///
+    /// let config = ServoConfig::new(1).with_request_delay(None);
+    /// let mut servo = DsyrsSyncClient::new(MockTransport::new(), config);
+    /// let start = Instant::now();
+    /// for _ in 0..5 {
+    ///     servo.get_speed()?;
+    /// }
+    /// assert!(start.elapsed() < Duration::from_millis(100));
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn with_request_delay(mut self, delay: Option<Duration>) -> Self {
+        self.request_delay = delay;
+        self
+    }
+
+    /// Describe the mechanics, for positions in user units (mm, degrees, ...)
+    ///
+    /// `units_per_rev` is the user travel per motor revolution; `gear` and
+    /// `encoder_resolution` must match P04.07/P04.09 and P01.20. See
+    /// [`UnitConverter`] and `get_position_user()`.
+    pub fn with_mechanical(
+        mut self,
+        units_per_rev: f64,
+        gear: GearRatio,
+        encoder_resolution: u32,
+    ) -> Self {
+        self.mechanical = Some(UnitConverter {
+            encoder_resolution,
+            gear_num: gear.numerator,
+            gear_denom: gear.denominator,
+            units_per_rev,
+        });
+        self
+    }
+
+    /// Start a validated configuration, see [`ServoConfigBuilder`]
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::{ControlMode, DsyrsError, ServoConfig};
+    ///
+    /// let config = ServoConfig::builder()
+    ///     .with_slave_id(3)
+    ///     .with_control_mode(ControlMode::Speed)
+    ///     .with_max_speed(3000)
+    ///     .build()?;
+    /// assert_eq!(config.slave_id, 3);
+    /// assert_eq!(config.max_speed, 3000);
+    ///
+    /// // Slave ID unset (0), broadcast-only or reserved
+    /// assert!(ServoConfig::builder().build().is_err());
+    /// assert!(ServoConfig::builder().with_slave_id(248).build().is_err());
+    /// assert!(ServoConfig::builder().with_slave_id(247).build().is_ok());
+    ///
+    /// // Above the drive's 10000 rpm limit
+    /// let result = ServoConfig::builder()
+    ///     .with_slave_id(1)
+    ///     .with_max_speed(10001)
+    ///     .build();
+    /// assert!(matches!(result, Err(DsyrsError::InvalidParameter(_))));
+    /// # Ok::<(), DsyrsError>(())
+    /// ```
+    pub fn builder() -> ServoConfigBuilder {
+        ServoConfigBuilder {
+            config: Self::new(0),
+        }
+    }
+}
+
+/// Builder for a validated [`ServoConfig`]
+///
+/// Takes the same settings as the `ServoConfig::with_*()` methods, plus the
+/// slave ID, which must be set. `build()` checks the slave ID (1-247) and the
+/// maximum speed (at most 10000 rpm, P00.07) that `init()` would otherwise
+/// write and the drive silently clamp.
+#[derive(Debug, Clone)]
+pub struct ServoConfigBuilder {
+    config: ServoConfig,
+}
+
+impl ServoConfigBuilder {
+    /// Highest maximum speed accepted by P00.07 (rpm)
+    pub const MAX_SPEED_LIMIT: u16 = 10000;
+
+    /// Set Modbus slave ID (1-247)
+    pub fn with_slave_id(mut self, slave_id: u8) -> Self {
+        self.config.slave_id = slave_id;
+        self
+    }
+
+    /// Set control mode
+    pub fn with_control_mode(mut self, mode: ControlMode) -> Self {
+        self.config = self.config.with_control_mode(mode);
+        self
+    }
+
+    /// Set direction
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.config = self.config.with_direction(direction);
+        self
+    }
+
+    /// Set maximum speed (rpm, at most 10000)
+    pub fn with_max_speed(mut self, rpm: u16) -> Self {
+        self.config = self.config.with_max_speed(rpm);
+        self
+    }
+
+    /// Set motor model code
+    pub fn with_motor_model_code(mut self, code: u16) -> Self {
+        self.config = self.config.with_motor_model_code(code);
+        self
+    }
+
+    /// Set rated current
+    pub fn with_rated_current(mut self, current: f32) -> Self {
+        self.config = self.config.with_rated_current(current);
+        self
+    }
+
+    /// Set encoder type
+    pub fn with_encoder_type(mut self, encoder: EncoderType) -> Self {
+        self.config = self.config.with_encoder_type(encoder);
+        self
+    }
+
+    /// Set encoder resolution
+    pub fn with_encoder_resolution(mut self, resolution: u32) -> Self {
+        self.config = self.config.with_encoder_resolution(resolution);
+        self
+    }
+
+    /// Set retry policy for transient Modbus errors
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.config = self.config.with_retry_policy(policy);
+        self
+    }
+
+    /// Set word order of 32-bit parameters
+    pub fn with_word_order(mut self, order: WordOrder) -> Self {
+        self.config = self.config.with_word_order(order);
+        self
+    }
+
+    /// Bound each Modbus transaction, see [`ServoConfig::with_timeout`]
+    pub fn with_timeout(mut self, limit: Duration) -> Self {
+        self.config = self.config.with_timeout(limit);
+        self
+    }
+
+    /// Set the pause after each transaction, see [`ServoConfig::with_request_delay`]
+    pub fn with_request_delay(mut self, delay: Option<Duration>) -> Self {
+        self.config = self.config.with_request_delay(delay);
+        self
+    }
+
+    /// Describe the mechanics, see [`ServoConfig::with_mechanical`]
+    pub fn with_mechanical(
+        mut self,
+        units_per_rev: f64,
+        gear: GearRatio,
+        encoder_resolution: u32,
+    ) -> Self {
+        self.config = self
+            .config
+            .with_mechanical(units_per_rev, gear, encoder_resolution);
+        self
+    }
+
+    /// Validate and return the configuration
+    pub fn build(self) -> Result<ServoConfig> {
+        if !(1..=247).contains(&self.config.slave_id) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Slave ID must be 1-247, got {}",
+                self.config.slave_id
+            )));
+        }
+        if self.config.max_speed > Self::MAX_SPEED_LIMIT {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Max speed must be at most {} rpm, got {}",
+                Self::MAX_SPEED_LIMIT,
+                self.config.max_speed
+            )));
+        }
+        if let Some(mechanical) = &self.config.mechanical {
+            mechanical.validate()?;
+        }
+        Ok(self.config)
+    }
+}
+
+/// Multi-segment position configuration
+///
+/// The drive has no per-segment position mode: P13.05 selects incremental or
+/// absolute for every segment. `position_mode_override` records the mode a
+/// segment requires; it is checked against P13.05 rather than written per
+/// segment, and segments requiring different modes cannot be combined (see
+/// [`SegmentConfig::common_position_mode`]).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SegmentConfig {
+    /// Segment number (1-16)
+    pub segment: u8,
+    /// Displacement (32-bit signed, within ±(2^30 - 1))
+    pub displacement: i32,
+    /// Maximum speed (rpm)
+    pub speed: u16,
+    /// Acceleration/deceleration time (ms)
+    pub accel_decel_time: u16,
+    /// Wait time after motion
+    pub wait_time: u16,
+    /// Position mode this segment requires, `None` to accept the global P13.05 mode
+    pub position_mode_override: Option<MultiSegPositionMode>,
+}
+
+impl SegmentConfig {
+    /// Create a new segment configuration
+    pub fn new(segment: u8) -> Result<Self> {
+        if !(1..=16).contains(&segment) {
+            return Err(DsyrsError::InvalidSegment(segment));
+        }
+        Ok(Self {
+            segment,
+            displacement: 0,
+            speed: 200,
+            accel_decel_time: 50,
+            wait_time: 0,
+            position_mode_override: None,
+        })
+    }
+
+    /// Set displacement
+    pub fn with_displacement(mut self, displacement: i32) -> Self {
+        self.displacement = displacement;
+        self
+    }
+
+    /// Set speed
+    pub fn with_speed(mut self, rpm: u16) -> Self {
+        self.speed = rpm;
+        self
+    }
+
+    /// Set acceleration/deceleration time
+    pub fn with_accel_decel(mut self, ms: u16) -> Self {
+        self.accel_decel_time = ms;
+        self
+    }
+
+    /// Set wait time
+    pub fn with_wait_time(mut self, time: u16) -> Self {
+        self.wait_time = time;
+        self
+    }
+
+    /// Require a position mode for this segment (checked against global P13.05)
+    pub fn with_position_mode(mut self, mode: MultiSegPositionMode) -> Self {
+        self.position_mode_override = Some(mode);
+        self
+    }
+
+    /// Resolve the single P13.05 mode required by a set of segments
+    ///
+    /// Returns `None` if no segment has an override, or an error if segments
+    /// require different modes, since P13.05 applies to all segments.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::{MultiSegPositionMode, SegmentConfig};
+    ///
+    /// let a = SegmentConfig::new(1)?.with_position_mode(MultiSegPositionMode::Absolute);
+    /// let b = SegmentConfig::new(2)?;
+    /// assert_eq!(
+    ///     SegmentConfig::common_position_mode(&[a.clone(), b])?,
+    ///     Some(MultiSegPositionMode::Absolute)
+    /// );
+    ///
+    /// let c = SegmentConfig::new(3)?.with_position_mode(MultiSegPositionMode::Incremental);
+    /// assert!(SegmentConfig::common_position_mode(&[a, c]).is_err());
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn common_position_mode(
+        segments: &[SegmentConfig],
+    ) -> Result<Option<MultiSegPositionMode>> {
+        let mut common: Option<(u8, MultiSegPositionMode)> = None;
+        for seg in segments {
+            let Some(mode) = seg.position_mode_override else {
+                continue;
+            };
+            match common {
+                Some((first, first_mode)) if first_mode != mode => {
+                    return Err(DsyrsError::InvalidParameter(format!(
+                        "Segments {} ({:?}) and {} ({:?}) require different position modes, \
+                         but P13.05 applies to all segments",
+                        first, first_mode, seg.segment, mode
+                    )));
+                }
+                Some(_) => {}
+                None => common = Some((seg.segment, mode)),
+            }
+        }
+        Ok(common.map(|(_, mode)| mode))
+    }
+}
+
+/// Multi-segment position program (P13), downloaded in one go by `download_program()`
+///
+/// Holds the operation mode (P13.00), the start/end window (P13.01/P13.02),
+/// the position mode (P13.05) and the segments to write. Segments of the
+/// window that are not in the program keep their current drive settings.
+/// Build one with [`MotionProgram::builder`], which validates it.
+///
+/// # Example
+/// ```
+/// use dsyrs::{DsyrsError, MotionProgram, MultiSegOperationMode, SegmentConfig};
+///
+/// let program = MotionProgram::builder(1, 3)
+///     .with_operation_mode(MultiSegOperationMode::Single)
+///     .with_segment(SegmentConfig::new(1)?.with_displacement(10_000))
+///     .with_segment(SegmentConfig::new(2)?.with_displacement(-5_000))
+///     .build()?;
+/// assert_eq!(program.segments.len(), 2);
+///
+/// // Start after end
+/// assert!(MotionProgram::builder(4, 2).build().is_err());
+/// // Segment outside the window, or given twice
+/// let outside = MotionProgram::builder(1, 3).with_segment(SegmentConfig::new(5)?);
+/// assert!(matches!(outside.build(), Err(DsyrsError::InvalidParameter(_))));
+/// let twice = MotionProgram::builder(1, 3)
+///     .with_segment(SegmentConfig::new(2)?)
+///     .with_segment(SegmentConfig::new(2)?);
+/// assert!(twice.build().is_err());
+/// # Ok::<(), DsyrsError>(())
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotionProgram {
+    /// Operation mode (P13.00)
+    pub operation_mode: MultiSegOperationMode,
+    /// First segment run (P13.01, 1-16)
+    pub start: u8,
+    /// Last segment run (P13.02, start-16)
+    pub end: u8,
+    /// Position mode of every segment (P13.05)
+    pub position_mode: MultiSegPositionMode,
+    /// Segments to write, each within `start..=end`
+    pub segments: Vec<SegmentConfig>,
+}
+
+impl MotionProgram {
+    /// Start a program running segments `start` to `end`, see [`MotionProgramBuilder`]
+    pub fn builder(start: u8, end: u8) -> MotionProgramBuilder {
+        MotionProgramBuilder {
+            program: Self {
+                operation_mode: MultiSegOperationMode::default(),
+                start,
+                end,
+                position_mode: MultiSegPositionMode::default(),
+                segments: Vec::new(),
+            },
+        }
+    }
+
+    /// Check the window, the segment numbers, displacements, speeds and position modes
+    ///
+    /// The window must satisfy 1 ≤ start ≤ end ≤ 16, each segment must lie
+    /// within it and appear once, displacements must be within ±(2^30 - 1),
+    /// speeds within 0-9000 rpm, and segment position mode overrides must
+    /// match `position_mode`.
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=16).contains(&self.start) {
+            return Err(DsyrsError::InvalidSegment(self.start));
+        }
+        if !(1..=16).contains(&self.end) {
+            return Err(DsyrsError::InvalidSegment(self.end));
+        }
+        if self.start > self.end {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Start segment {} is after end segment {}",
+                self.start, self.end
+            )));
+        }
+        let mut seen = 0u32;
+        for seg in &self.segments {
+            if !(self.start..=self.end).contains(&seg.segment) {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "Segment {} is outside the program window {}-{}",
+                    seg.segment, self.start, self.end
+                )));
+            }
+            if seen & (1 << seg.segment) != 0 {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "Segment {} is given more than once",
+                    seg.segment
+                )));
+            }
+            seen |= 1 << seg.segment;
+            if seg.displacement.unsigned_abs() >= 1 << 30 {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "Segment {} displacement {} must be within ±(2^30 - 1)",
+                    seg.segment, seg.displacement
+                )));
+            }
+            if seg.speed > 9000 {
+                return Err(DsyrsError::InvalidParameter(format!(
+                    "Segment {} speed {} must be 0-9000 rpm",
+                    seg.segment, seg.speed
+                )));
+            }
+        }
+        match SegmentConfig::common_position_mode(&self.segments)? {
+            Some(mode) if mode != self.position_mode => Err(DsyrsError::InvalidParameter(format!(
+                "Segments require {:?} positioning but the program uses {:?}",
+                mode, self.position_mode
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Segments sorted by number and grouped into runs of consecutive numbers
+    pub(crate) fn segment_runs(&self) -> Vec<Vec<&SegmentConfig>> {
+        let mut sorted: Vec<&SegmentConfig> = self.segments.iter().collect();
+        sorted.sort_by_key(|seg| seg.segment);
+        let mut runs: Vec<Vec<&SegmentConfig>> = Vec::new();
+        for seg in sorted {
+            match runs.last_mut() {
+                Some(run) if run.last().map(|last| last.segment + 1) == Some(seg.segment) => {
+                    run.push(seg)
+                }
+                _ => runs.push(vec![seg]),
+            }
+        }
+        runs
+    }
+}
+
+/// Builder for a validated [`MotionProgram`]
+#[derive(Debug, Clone)]
+pub struct MotionProgramBuilder {
+    program: MotionProgram,
+}
+
+impl MotionProgramBuilder {
+    /// Set operation mode (P13.00)
+    pub fn with_operation_mode(mut self, mode: MultiSegOperationMode) -> Self {
+        self.program.operation_mode = mode;
+        self
+    }
+
+    /// Set position mode of every segment (P13.05)
+    pub fn with_position_mode(mut self, mode: MultiSegPositionMode) -> Self {
+        self.program.position_mode = mode;
+        self
+    }
+
+    /// Add a segment
+    pub fn with_segment(mut self, segment: SegmentConfig) -> Self {
+        self.program.segments.push(segment);
+        self
+    }
+
+    /// Validate and return the program, see [`MotionProgram::validate`]
+    pub fn build(self) -> Result<MotionProgram> {
+        self.program.validate()?;
+        Ok(self.program)
+    }
+}
+
+/// Multi-speed segment configuration (P14.07-P14.54)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeedSegmentConfig {
+    /// Segment number (1-16)
+    pub segment: u8,
+    /// Segment speed (-9000 to 9000 rpm)
+    pub speed: i16,
+    /// Run time (0.1 s or 0.1 min, see P14.02)
+    pub time: u16,
+    /// Accel/decel time selection (1-4, P14.03-P14.06)
+    pub accel_select: u8,
+}
+
+impl SpeedSegmentConfig {
+    /// Create a new speed segment configuration
+    pub fn new(segment: u8) -> Result<Self> {
+        if !(1..=16).contains(&segment) {
+            return Err(DsyrsError::InvalidSegment(segment));
+        }
+        Ok(Self {
+            segment,
+            speed: 0,
+            time: 0,
+            accel_select: 1,
+        })
+    }
+
+    /// Set speed
+    pub fn with_speed(mut self, rpm: i16) -> Self {
+        self.speed = rpm;
+        self
+    }
+
+    /// Set run time
+    pub fn with_time(mut self, time: u16) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Set accel/decel time selection
+    pub fn with_accel_select(mut self, index: u8) -> Self {
+        self.accel_select = index;
+        self
+    }
+
+    /// Check the segment number, speed and accel/decel selection
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=16).contains(&self.segment) {
+            return Err(DsyrsError::InvalidSegment(self.segment));
+        }
+        if !(-9000..=9000).contains(&self.speed) {
+            return Err(DsyrsError::InvalidParameter(
+                "Segment speed must be -9000 to 9000 rpm".into(),
+            ));
+        }
+        if !(1..=4).contains(&self.accel_select) {
+            return Err(DsyrsError::InvalidParameter(
+                "Accel/decel selection must be 1-4".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Homing configuration
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HomingConfig {
+    /// Homing mode
+    pub mode: HomingMode,
+    /// High speed for searching (rpm)
+    pub high_speed: u16,
+    /// Low speed for precise positioning (rpm)
+    pub low_speed: u16,
+    /// Acceleration limit (ms)
+    pub accel_limit: u16,
+    /// Timeout (ms)
+    pub timeout: u16,
+    /// Home offset
+    pub offset: i32,
+    /// Homing enable mode (P16.08), left unchanged when `None`
+    pub enable_mode: Option<HomingEnableMode>,
+}
+
+impl Default for HomingConfig {
+    fn default() -> Self {
+        Self {
+            mode: HomingMode::Mode0,
+            high_speed: 100,
+            low_speed: 10,
+            accel_limit: 1000,
+            timeout: 10000,
+            offset: 0,
+            enable_mode: None,
+        }
+    }
+}
+
+impl HomingConfig {
+    /// Set homing mode
+    pub fn with_mode(mut self, mode: HomingMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set high speed
+    pub fn with_high_speed(mut self, rpm: u16) -> Self {
+        self.high_speed = rpm;
         self
     }
 
-    /// Set encoder type
-    pub fn with_encoder_type(mut self, encoder: EncoderType) -> Self {
-        self.encoder_type = Some(encoder);
+    /// Set low speed
+    pub fn with_low_speed(mut self, rpm: u16) -> Self {
+        self.low_speed = rpm;
         self
     }
 
-    /// Set encoder resolution
-    pub fn with_encoder_resolution(mut self, resolution: u32) -> Self {
-        self.encoder_resolution = Some(resolution);
+    /// Set acceleration limit
+    pub fn with_accel_limit(mut self, ms: u16) -> Self {
+        self.accel_limit = ms;
+        self
+    }
+
+    /// Set timeout
+    pub fn with_timeout(mut self, ms: u16) -> Self {
+        self.timeout = ms;
+        self
+    }
+
+    /// Set home offset
+    pub fn with_offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set homing enable mode
+    pub fn with_enable_mode(mut self, mode: HomingEnableMode) -> Self {
+        self.enable_mode = Some(mode);
         self
     }
 }
 
-/// Multi-segment position configuration
-#[derive(Debug, Clone)]
-pub struct SegmentConfig {
-    /// Segment number (1-16)
-    pub segment: u8,
-    /// Displacement (32-bit signed)
-    pub displacement: i32,
-    /// Maximum speed (rpm)
-    pub speed: u16,
-    /// Acceleration/deceleration time (ms)
-    pub accel_decel_time: u16,
-    /// Wait time after motion
-    pub wait_time: u16,
+/// Holding brake and stop configuration (P00.10, P00.13-P00.17)
+///
+/// When the servo is switched off while running, the brake output turns OFF
+/// (brake engages) as soon as the speed drops below `speed_threshold` or the
+/// `off_delay` elapses, whichever comes first. On a vertical axis, a threshold
+/// that is too high or a delay that is too short lets the brake clamp a moving
+/// load, while a threshold of 0 relies on the delay alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrakeConfig {
+    /// Stop mode on servo OFF (P00.10)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub servo_off_stop_mode: ServoOffStopMode,
+    /// Stop mode on overtravel (P00.13)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overtravel_stop_mode: OvertravelStopMode,
+    /// Brake output ON delay after servo ON command (P00.14, 0-10000 ms)
+    pub on_delay: u16,
+    /// Brake output OFF delay after servo OFF (P00.15, 10-10000 ms)
+    pub off_delay: u16,
+    /// Speed below which the brake output turns OFF while running (P00.16, 0-1000 rpm)
+    pub speed_threshold: u16,
+    /// Delay between servo OFF and brake OFF on a No.1 fault (P00.17, 0-10000 ms)
+    pub fault_delay: u16,
 }
 
-impl SegmentConfig {
-    /// Create a new segment configuration
-    pub fn new(segment: u8) -> Result<Self> {
-        if segment < 1 || segment > 16 {
-            return Err(DsyrsError::InvalidSegment(segment));
+impl Default for BrakeConfig {
+    fn default() -> Self {
+        Self {
+            servo_off_stop_mode: ServoOffStopMode::default(),
+            overtravel_stop_mode: OvertravelStopMode::default(),
+            on_delay: 200,
+            off_delay: 200,
+            speed_threshold: 50,
+            fault_delay: 500,
         }
-        Ok(Self {
-            segment,
-            displacement: 0,
-            speed: 200,
-            accel_decel_time: 50,
-            wait_time: 0,
-        })
     }
+}
 
-    /// Set displacement
-    pub fn with_displacement(mut self, displacement: i32) -> Self {
-        self.displacement = displacement;
+impl BrakeConfig {
+    /// Speed above which engaging the brake is reported as a likely misconfiguration
+    pub const HIGH_SPEED_WARNING: u16 = 300;
+
+    /// Set servo OFF stop mode
+    pub fn with_servo_off_stop_mode(mut self, mode: ServoOffStopMode) -> Self {
+        self.servo_off_stop_mode = mode;
         self
     }
 
-    /// Set speed
+    /// Set overtravel stop mode
+    pub fn with_overtravel_stop_mode(mut self, mode: OvertravelStopMode) -> Self {
+        self.overtravel_stop_mode = mode;
+        self
+    }
+
+    /// Set brake ON delay
+    pub fn with_on_delay(mut self, ms: u16) -> Self {
+        self.on_delay = ms;
+        self
+    }
+
+    /// Set brake OFF delay
+    pub fn with_off_delay(mut self, ms: u16) -> Self {
+        self.off_delay = ms;
+        self
+    }
+
+    /// Set brake speed threshold
+    pub fn with_speed_threshold(mut self, rpm: u16) -> Self {
+        self.speed_threshold = rpm;
+        self
+    }
+
+    /// Set fault brake delay
+    pub fn with_fault_delay(mut self, ms: u16) -> Self {
+        self.fault_delay = ms;
+        self
+    }
+
+    /// Check every value against its documented range
+    pub fn validate(&self) -> Result<()> {
+        if self.on_delay > 10000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Brake ON delay must be 0-10000 ms".into(),
+            ));
+        }
+        if !(10..=10000).contains(&self.off_delay) {
+            return Err(DsyrsError::InvalidParameter(
+                "Brake OFF delay must be 10-10000 ms".into(),
+            ));
+        }
+        if self.speed_threshold > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Brake speed threshold must be 0-1000 rpm".into(),
+            ));
+        }
+        if self.fault_delay > 10000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fault brake delay must be 0-10000 ms".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// List settings that are in range but likely to engage the brake on a moving motor
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::BrakeConfig;
+    ///
+    /// assert!(BrakeConfig::default().warnings().is_empty());
+    /// assert_eq!(BrakeConfig::default().with_speed_threshold(0).warnings().len(), 1);
+    /// assert_eq!(BrakeConfig::default().with_speed_threshold(800).warnings().len(), 1);
+    /// ```
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.speed_threshold == 0 {
+            warnings.push(format!(
+                "Brake speed threshold is 0: the brake engages only after the {} ms OFF delay, \
+                 even if the motor is still turning",
+                self.off_delay
+            ));
+        } else if self.speed_threshold > Self::HIGH_SPEED_WARNING {
+            warnings.push(format!(
+                "Brake engages below {} rpm: the holding brake may clamp a moving load",
+                self.speed_threshold
+            ));
+        }
+        warnings
+    }
+}
+
+/// Energy consumption (braking) resistor configuration (P00.18-P00.22)
+///
+/// With an external resistor, the drive protects it from overheating using
+/// its power, resistance and heating time constant: values that do not match
+/// the fitted resistor can let it burn out, so they must be set whenever
+/// [`EnergyResistor::ExternalNatural`] or [`EnergyResistor::ExternalForced`]
+/// is selected. They are ignored for the built-in resistor or none.
+/// Written with `apply_braking_resistor_config()`.
+///
+/// # Example
+/// ```
+/// use dsyrs::{BrakingResistorConfig, EnergyResistor};
+///
+/// assert!(BrakingResistorConfig::new(EnergyResistor::BuiltIn, 380).validate().is_ok());
+///
+/// // External resistor without its ratings
+/// let external = BrakingResistorConfig::new(EnergyResistor::ExternalForced, 380);
+/// assert!(external.validate().is_err());
+/// assert!(external.with_external_resistor(200, 50, 5000).validate().is_ok());
+/// assert!(external.with_external_resistor(200, 1001, 5000).validate().is_err());
+/// assert!(external.with_external_resistor(200, 50, 999).validate().is_err());
+///
+/// assert!(BrakingResistorConfig::new(EnergyResistor::BuiltIn, 1001).validate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrakingResistorConfig {
+    /// Energy consumption resistor setting (P00.18)
+    pub resistor: EnergyResistor,
+    /// External resistor power capacity (P00.19, 1-65535 W)
+    pub power_w: u16,
+    /// External resistance value (P00.20, 1-1000 Ω)
+    pub resistance_ohm: u16,
+    /// External resistance heating time constant (P00.21, 1000-65535 ms)
+    pub time_constant_ms: u16,
+    /// Braking start voltage (P00.22, 0-1000 V)
+    pub brake_voltage: u16,
+}
+
+impl BrakingResistorConfig {
+    /// Create a configuration without external resistor ratings
+    pub fn new(resistor: EnergyResistor, brake_voltage: u16) -> Self {
+        Self {
+            resistor,
+            power_w: 0,
+            resistance_ohm: 0,
+            time_constant_ms: 0,
+            brake_voltage,
+        }
+    }
+
+    /// Set the external resistor ratings (P00.19-P00.21)
+    pub fn with_external_resistor(
+        mut self,
+        power_w: u16,
+        resistance_ohm: u16,
+        time_constant_ms: u16,
+    ) -> Self {
+        self.power_w = power_w;
+        self.resistance_ohm = resistance_ohm;
+        self.time_constant_ms = time_constant_ms;
+        self
+    }
+
+    /// Check the braking voltage, and the external ratings if an external resistor is selected
+    pub fn validate(&self) -> Result<()> {
+        if self.brake_voltage > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Braking start voltage must be 0-1000 V".into(),
+            ));
+        }
+        if self.resistor.is_external() {
+            Self::check_external_resistor(
+                self.power_w,
+                self.resistance_ohm,
+                self.time_constant_ms,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Check external resistor ratings (P00.19-P00.21) against their documented ranges
+    pub(crate) fn check_external_resistor(
+        power_w: u16,
+        resistance_ohm: u16,
+        time_constant_ms: u16,
+    ) -> Result<()> {
+        if power_w == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "External resistor power must be 1-65535 W".into(),
+            ));
+        }
+        if !(1..=1000).contains(&resistance_ohm) {
+            return Err(DsyrsError::InvalidParameter(
+                "External resistance must be 1-1000 Ω".into(),
+            ));
+        }
+        if time_constant_ms < 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "External resistance heating time constant must be 1000-65535 ms".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Fixed-length interrupt configuration (P16.01-P16.05, P16.37-P16.39)
+///
+/// The accel/decel times apply only to fixed-length (interrupt) moves; the
+/// main position command keeps its own ramps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedLengthConfig {
+    /// Fixed length 1 displacement (P16.01, 0-(2^30 - 1) units)
+    pub length1: u32,
+    /// Fixed length 1 speed (P16.03, 0-9000 rpm)
+    pub speed1: u16,
+    /// Fixed length acceleration time (P16.04, 0-1000 ms)
+    pub accel_time: u16,
+    /// Fixed length deceleration time (P16.05, 0-1000 ms)
+    pub decel_time: u16,
+    /// Fixed length 2 displacement (P16.37, ±(2^30 - 1) units)
+    pub length2: i32,
+    /// Fixed length 2 speed (P16.39, 0-9000 rpm)
+    pub speed2: u16,
+}
+
+impl Default for FixedLengthConfig {
+    fn default() -> Self {
+        Self {
+            length1: 10000,
+            speed1: 200,
+            accel_time: 200,
+            decel_time: 200,
+            length2: 0,
+            speed2: 200,
+        }
+    }
+}
+
+impl FixedLengthConfig {
+    /// Set fixed length 1 displacement and speed
+    pub fn with_length1(mut self, displacement: u32, rpm: u16) -> Self {
+        self.length1 = displacement;
+        self.speed1 = rpm;
+        self
+    }
+
+    /// Set fixed length 2 displacement and speed
+    pub fn with_length2(mut self, displacement: i32, rpm: u16) -> Self {
+        self.length2 = displacement;
+        self.speed2 = rpm;
+        self
+    }
+
+    /// Set acceleration time
+    pub fn with_accel(mut self, ms: u16) -> Self {
+        self.accel_time = ms;
+        self
+    }
+
+    /// Set deceleration time
+    pub fn with_decel(mut self, ms: u16) -> Self {
+        self.decel_time = ms;
+        self
+    }
+
+    /// Check every value against its documented range
+    pub fn validate(&self) -> Result<()> {
+        const MAX_LENGTH: u32 = (1 << 30) - 1;
+        if self.length1 > MAX_LENGTH {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length 1 displacement must be 0-1073741823".into(),
+            ));
+        }
+        if self.length2.unsigned_abs() > MAX_LENGTH {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length 2 displacement must be within ±1073741823".into(),
+            ));
+        }
+        if self.speed1 > 9000 || self.speed2 > 9000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length speed must be 0-9000 rpm".into(),
+            ));
+        }
+        if self.accel_time > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length accel time must be 0-1000 ms".into(),
+            ));
+        }
+        if self.decel_time > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Fixed length decel time must be 0-1000 ms".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Jog direction, selecting the ForwardJog (FunIN.17) or BackwardJog (FunIN.18) input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JogDirection {
+    /// Forward jog (FunIN.17)
+    Forward,
+    /// Backward jog (FunIN.18)
+    Backward,
+}
+
+impl From<JogDirection> for DiFunction {
+    fn from(direction: JogDirection) -> Self {
+        match direction {
+            JogDirection::Forward => DiFunction::ForwardJog2,
+            JogDirection::Backward => DiFunction::BackwardJog,
+        }
+    }
+}
+
+/// Jog configuration
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JogConfig {
+    /// Jog speed (rpm)
+    pub speed: u16,
+    /// Acceleration time (ms)
+    pub accel_time: u16,
+    /// Deceleration time (ms)
+    pub decel_time: u16,
+}
+
+impl Default for JogConfig {
+    fn default() -> Self {
+        Self {
+            speed: 200,
+            accel_time: 50,
+            decel_time: 50,
+        }
+    }
+}
+
+impl JogConfig {
+    /// Set jog speed
     pub fn with_speed(mut self, rpm: u16) -> Self {
         self.speed = rpm;
         self
     }
 
-    /// Set acceleration/deceleration time
-    pub fn with_accel_decel(mut self, ms: u16) -> Self {
-        self.accel_decel_time = ms;
+    /// Set acceleration time
+    pub fn with_accel(mut self, ms: u16) -> Self {
+        self.accel_time = ms;
         self
     }
 
-    /// Set wait time
-    pub fn with_wait_time(mut self, time: u16) -> Self {
-        self.wait_time = time;
+    /// Set deceleration time
+    pub fn with_decel(mut self, ms: u16) -> Self {
+        self.decel_time = ms;
         self
     }
 }
 
-/// Homing configuration
-#[derive(Debug, Clone)]
-pub struct HomingConfig {
-    /// Homing mode
-    pub mode: HomingMode,
-    /// High speed for searching (rpm)
-    pub high_speed: u16,
-    /// Low speed for precise positioning (rpm)
-    pub low_speed: u16,
-    /// Acceleration limit (ms)
-    pub accel_limit: u16,
-    /// Timeout (ms)
-    pub timeout: u16,
-    /// Home offset
-    pub offset: i32,
+/// Speed output signal thresholds (P05.15-P05.18, P05.20)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeedSignalConfig {
+    /// Zero fixed speed value (P05.15, 0-6000 rpm)
+    pub zero_fixed_speed: u16,
+    /// Motor running signal speed threshold (P05.16, 0-1000 rpm)
+    pub running_threshold: u16,
+    /// Speed uniform signal width (P05.17, 0-100 rpm)
+    pub uniform_width: u16,
+    /// Speed reached value (P05.18, 0-6000 rpm)
+    pub reached_value: u16,
+    /// Zero-speed judgment threshold (P05.20, 0-6000 rpm)
+    pub zero_speed_threshold: u16,
+}
+
+impl Default for SpeedSignalConfig {
+    fn default() -> Self {
+        Self {
+            zero_fixed_speed: 10,
+            running_threshold: 20,
+            uniform_width: 10,
+            reached_value: 1000,
+            zero_speed_threshold: 10,
+        }
+    }
+}
+
+impl SpeedSignalConfig {
+    /// Set zero fixed speed value
+    pub fn with_zero_fixed_speed(mut self, rpm: u16) -> Self {
+        self.zero_fixed_speed = rpm;
+        self
+    }
+
+    /// Set motor running signal threshold
+    pub fn with_running_threshold(mut self, rpm: u16) -> Self {
+        self.running_threshold = rpm;
+        self
+    }
+
+    /// Set speed uniform signal width
+    pub fn with_uniform_width(mut self, rpm: u16) -> Self {
+        self.uniform_width = rpm;
+        self
+    }
+
+    /// Set speed reached value
+    pub fn with_reached_value(mut self, rpm: u16) -> Self {
+        self.reached_value = rpm;
+        self
+    }
+
+    /// Set zero-speed judgment threshold
+    pub fn with_zero_speed_threshold(mut self, rpm: u16) -> Self {
+        self.zero_speed_threshold = rpm;
+        self
+    }
+
+    /// Level of the zero-speed signal (FunOUT.5) for a speed feedback (P18.01)
+    ///
+    /// The signal is active while the speed magnitude, in either direction,
+    /// is within the zero-speed threshold (P05.20).
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::SpeedSignalConfig;
+    ///
+    /// let config = SpeedSignalConfig::default().with_zero_speed_threshold(10);
+    /// assert!(config.is_zero_speed(0));
+    /// assert!(config.is_zero_speed(10));
+    /// assert!(config.is_zero_speed(-10));
+    /// assert!(!config.is_zero_speed(11));
+    /// assert!(!config.is_zero_speed(-11));
+    /// assert!(!config.is_zero_speed(i16::MIN));
+    /// ```
+    pub fn is_zero_speed(&self, speed_rpm: i16) -> bool {
+        speed_rpm.unsigned_abs() <= self.zero_speed_threshold
+    }
+
+    /// Check every threshold against its documented range
+    pub fn validate(&self) -> Result<()> {
+        if self.zero_fixed_speed > 6000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Zero fixed speed must be 0-6000 rpm".into(),
+            ));
+        }
+        if self.running_threshold > 1000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Running threshold must be 0-1000 rpm".into(),
+            ));
+        }
+        if self.uniform_width > 100 {
+            return Err(DsyrsError::InvalidParameter(
+                "Speed uniform width must be 0-100 rpm".into(),
+            ));
+        }
+        if self.reached_value > 6000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Speed reached value must be 0-6000 rpm".into(),
+            ));
+        }
+        if self.zero_speed_threshold > 6000 {
+            return Err(DsyrsError::InvalidParameter(
+                "Zero-speed threshold must be 0-6000 rpm".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Speed step response recorded by `measure_acceleration()`
+///
+/// # Example
+/// ```
+/// use dsyrs::AccelProfile;
+/// use std::time::Duration;
+///
+/// let profile = AccelProfile {
+///     time_to_target: Duration::from_millis(500),
+///     overshoot_rpm: 30,
+///     accel_time_ms: 100,
+///     samples: vec![(Duration::ZERO, 0), (Duration::from_millis(500), 1000)],
+/// };
+/// assert_eq!(profile.realized_rpm_per_second(), Some(2000.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccelProfile {
+    /// Time from the speed command until the speed first came within tolerance
+    pub time_to_target: Duration,
+    /// Largest speed past the target, in the direction of the step (0 if none)
+    pub overshoot_rpm: i16,
+    /// Configured acceleration time (P05.05, ms), for comparison
+    pub accel_time_ms: u16,
+    /// Speed feedback samples (P18.01) as (time since the command, rpm)
+    pub samples: Vec<(Duration, i16)>,
+}
+
+impl AccelProfile {
+    /// Average acceleration from the first sample to reaching the target (rpm/s)
+    ///
+    /// `None` without samples or if the target was reached immediately.
+    pub fn realized_rpm_per_second(&self) -> Option<f32> {
+        let &(_, first) = self.samples.first()?;
+        let reached = self
+            .samples
+            .iter()
+            .find(|(t, _)| *t >= self.time_to_target)?
+            .1;
+        let secs = self.time_to_target.as_secs_f32();
+        if secs == 0.0 {
+            return None;
+        }
+        Some((reached as f32 - first as f32).abs() / secs)
+    }
 }
 
-impl Default for HomingConfig {
+/// Protection thresholds (P09.05, P09.06, P09.08, P09.09)
+///
+/// The position deviation threshold default depends on the motor, so it is
+/// only written when set.
+///
+/// ```
+/// use dsyrs::ProtectionConfig;
+///
+/// let config = ProtectionConfig::default()
+///     .with_overload_warning(80)
+///     .with_position_deviation_threshold(100_000);
+/// assert!(config.validate().is_ok());
+/// assert!(config.clone().with_motor_overload_factor(301).validate().is_err());
+/// assert!(config.clone().with_overspeed_point(49).validate().is_err());
+/// assert!(config.with_position_deviation_threshold(0).validate().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtectionConfig {
+    /// Overload warning value (P09.05, 1-100 %)
+    pub overload_warning: u8,
+    /// Motor overload factor (P09.06, 10-300 %)
+    pub motor_overload_factor: u16,
+    /// Overspeed fault point (P09.08, 50-120 % of max speed)
+    pub overspeed_point: u8,
+    /// Position deviation excessive threshold (P09.09, 1-1073741824 pulses)
+    pub position_deviation_threshold: Option<u32>,
+}
+
+impl Default for ProtectionConfig {
     fn default() -> Self {
         Self {
-            mode: HomingMode::Mode0,
-            high_speed: 100,
-            low_speed: 10,
-            accel_limit: 1000,
-            timeout: 10000,
-            offset: 0,
+            overload_warning: 90,
+            motor_overload_factor: 100,
+            overspeed_point: 120,
+            position_deviation_threshold: None,
         }
     }
 }
 
-impl HomingConfig {
-    /// Set homing mode
-    pub fn with_mode(mut self, mode: HomingMode) -> Self {
-        self.mode = mode;
+impl ProtectionConfig {
+    /// Set overload warning value
+    pub fn with_overload_warning(mut self, percent: u8) -> Self {
+        self.overload_warning = percent;
         self
     }
 
-    /// Set high speed
-    pub fn with_high_speed(mut self, rpm: u16) -> Self {
-        self.high_speed = rpm;
+    /// Set motor overload factor
+    pub fn with_motor_overload_factor(mut self, percent: u16) -> Self {
+        self.motor_overload_factor = percent;
         self
     }
 
-    /// Set low speed
-    pub fn with_low_speed(mut self, rpm: u16) -> Self {
-        self.low_speed = rpm;
+    /// Set overspeed fault point
+    pub fn with_overspeed_point(mut self, percent: u8) -> Self {
+        self.overspeed_point = percent;
         self
     }
 
-    /// Set acceleration limit
-    pub fn with_accel_limit(mut self, ms: u16) -> Self {
-        self.accel_limit = ms;
+    /// Set position deviation excessive threshold
+    pub fn with_position_deviation_threshold(mut self, pulses: u32) -> Self {
+        self.position_deviation_threshold = Some(pulses);
         self
     }
 
-    /// Set timeout
-    pub fn with_timeout(mut self, ms: u16) -> Self {
-        self.timeout = ms;
+    /// Check every threshold against its documented range
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=100).contains(&self.overload_warning) {
+            return Err(DsyrsError::InvalidParameter(
+                "Overload warning value must be 1-100 %".into(),
+            ));
+        }
+        if !(10..=300).contains(&self.motor_overload_factor) {
+            return Err(DsyrsError::InvalidParameter(
+                "Motor overload factor must be 10-300 %".into(),
+            ));
+        }
+        if !(50..=120).contains(&self.overspeed_point) {
+            return Err(DsyrsError::InvalidParameter(
+                "Overspeed point must be 50-120 %".into(),
+            ));
+        }
+        if let Some(pulses) = self.position_deviation_threshold {
+            if !(1..=1 << 30).contains(&pulses) {
+                return Err(DsyrsError::InvalidParameter(
+                    "Position deviation threshold must be 1-1073741824 pulses".into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Torque control configuration (P06.02-P06.16) in physical units
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TorqueConfig {
+    /// Torque command selection (P06.02)
+    pub selection: TorqueCmdSelect,
+    /// Torque command filter time (P06.04, ms)
+    pub filter_ms: f32,
+    /// Torque command keyboard setting (P06.05, % of rated)
+    pub command: f32,
+    /// Torque limit source (P06.06)
+    pub limit_source: TorqueLimitSource,
+    /// Forward internal torque limit (P06.08, % of rated)
+    pub forward_internal_limit: f32,
+    /// Backward internal torque limit (P06.09, % of rated)
+    pub backward_internal_limit: f32,
+    /// Forward external torque limit (P06.10, % of rated)
+    pub forward_external_limit: f32,
+    /// Backward external torque limit (P06.11, % of rated)
+    pub backward_external_limit: f32,
+    /// Speed limit source (P06.13)
+    pub speed_limit_source: TorqueSpeedLimitSource,
+    /// Positive speed limit in torque mode (P06.15, rpm)
+    pub positive_speed_limit: u16,
+    /// Negative speed limit in torque mode (P06.16, rpm)
+    pub negative_speed_limit: u16,
+}
+
+/// Torque limits (P06.06, P06.08-P06.11), in 0.1% of rated torque
+///
+/// The internal pair applies with [`TorqueLimitSource::Internal`], the
+/// external pair with [`TorqueLimitSource::External`] while the P_CL/N_CL
+/// inputs (FunIN.15/FunIN.16) are active. Written with `apply_torque_limits()`.
+///
+/// # Example
+/// ```
+/// use dsyrs::{TorqueLimits, TorqueLimitSource};
+///
+/// let limits = TorqueLimits::new(TorqueLimitSource::External)
+///     .with_internal(3000, 3000)
+///     .with_external(800, 800);
+/// assert!(limits.validate().is_ok());
+/// assert!(limits.with_external(5001, 800).validate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TorqueLimits {
+    /// Torque limit source (P06.06)
+    pub source: TorqueLimitSource,
+    /// Forward internal torque limit (P06.08, 0-5000)
+    pub forward_internal: u16,
+    /// Backward internal torque limit (P06.09, 0-5000)
+    pub backward_internal: u16,
+    /// Forward external torque limit (P06.10, 0-5000)
+    pub forward_external: u16,
+    /// Backward external torque limit (P06.11, 0-5000)
+    pub backward_external: u16,
+}
+
+impl Default for TorqueLimits {
+    fn default() -> Self {
+        Self {
+            source: TorqueLimitSource::Internal,
+            forward_internal: 3000,
+            backward_internal: 3000,
+            forward_external: 3000,
+            backward_external: 3000,
+        }
+    }
+}
+
+impl TorqueLimits {
+    /// Create torque limits with the given source and default 300% limits
+    pub fn new(source: TorqueLimitSource) -> Self {
+        Self {
+            source,
+            ..Default::default()
+        }
+    }
+
+    /// Set the internal limits (P06.08/P06.09)
+    pub fn with_internal(mut self, forward: u16, backward: u16) -> Self {
+        self.forward_internal = forward;
+        self.backward_internal = backward;
         self
     }
 
-    /// Set home offset
-    pub fn with_offset(mut self, offset: i32) -> Self {
-        self.offset = offset;
+    /// Set the external limits (P06.10/P06.11)
+    pub fn with_external(mut self, forward: u16, backward: u16) -> Self {
+        self.forward_external = forward;
+        self.backward_external = backward;
         self
     }
+
+    /// Check every limit against the documented 0-5000 range
+    pub fn validate(&self) -> Result<()> {
+        let limits = [
+            self.forward_internal,
+            self.backward_internal,
+            self.forward_external,
+            self.backward_external,
+        ];
+        if let Some(limit) = limits.iter().find(|&&limit| limit > 5000) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Torque limit {} must be 0-5000 (0.1% of rated)",
+                limit
+            )));
+        }
+        Ok(())
+    }
 }
 
-/// Jog configuration
-#[derive(Debug, Clone)]
-pub struct JogConfig {
-    /// Jog speed (rpm)
-    pub speed: u16,
-    /// Acceleration time (ms)
-    pub accel_time: u16,
-    /// Deceleration time (ms)
-    pub decel_time: u16,
+/// Speed ceiling in torque control (P06.13, P06.15-P06.16), in rpm
+///
+/// In torque mode the motor accelerates until the load balances the torque
+/// command; with no load it runs away. The drive caps the speed at these
+/// limits instead. Written with `apply_torque_mode_config()`.
+///
+/// # Example
+/// ```
+/// use dsyrs::{TorqueModeConfig, TorqueSpeedLimitSource};
+///
+/// let config = TorqueModeConfig::new(1500, 500);
+/// assert_eq!(config.speed_limit_source, TorqueSpeedLimitSource::Internal);
+/// assert!(config.validate().is_ok());
+/// assert!(TorqueModeConfig::new(9000, 0).validate().is_ok());
+/// assert!(TorqueModeConfig::new(9001, 500).validate().is_err());
+/// assert!(TorqueModeConfig::new(1500, 9001).validate().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TorqueModeConfig {
+    /// Speed limit source (P06.13)
+    pub speed_limit_source: TorqueSpeedLimitSource,
+    /// Positive speed limit (P06.15, 0-9000 rpm)
+    pub positive_speed_limit: u16,
+    /// Negative speed limit (P06.16, 0-9000 rpm)
+    pub negative_speed_limit: u16,
 }
 
-impl Default for JogConfig {
+impl Default for TorqueModeConfig {
     fn default() -> Self {
         Self {
-            speed: 200,
-            accel_time: 50,
-            decel_time: 50,
+            speed_limit_source: TorqueSpeedLimitSource::Internal,
+            positive_speed_limit: 3000,
+            negative_speed_limit: 3000,
         }
     }
 }
 
-impl JogConfig {
-    /// Set jog speed
-    pub fn with_speed(mut self, rpm: u16) -> Self {
-        self.speed = rpm;
-        self
+impl TorqueModeConfig {
+    /// Create a configuration with internal speed limits
+    pub fn new(positive_speed_limit: u16, negative_speed_limit: u16) -> Self {
+        Self {
+            speed_limit_source: TorqueSpeedLimitSource::Internal,
+            positive_speed_limit,
+            negative_speed_limit,
+        }
     }
 
-    /// Set acceleration time
-    pub fn with_accel(mut self, ms: u16) -> Self {
-        self.accel_time = ms;
+    /// Set the speed limit source (P06.13)
+    pub fn with_source(mut self, source: TorqueSpeedLimitSource) -> Self {
+        self.speed_limit_source = source;
         self
     }
 
-    /// Set deceleration time
-    pub fn with_decel(mut self, ms: u16) -> Self {
-        self.decel_time = ms;
-        self
+    /// Check both limits against the documented 0-9000 rpm range
+    pub fn validate(&self) -> Result<()> {
+        Self::check_speed_limit(self.positive_speed_limit)?;
+        Self::check_speed_limit(self.negative_speed_limit)
+    }
+
+    /// Check a torque mode speed limit (P06.15-P06.16) against its 0-9000 rpm range
+    pub(crate) fn check_speed_limit(rpm: u16) -> Result<()> {
+        if rpm > 9000 {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Torque mode speed limit {} must be 0-9000 rpm",
+                rpm
+            )));
+        }
+        Ok(())
     }
 }
 
 /// Servo status information
+///
+/// Fields keep the register units. Signedness follows the manual's ranges:
+/// speed (±9000 rpm), torque (±5000) and position (±2^30) are two's
+/// complement; load rate, current, bus voltage and electrical angle are
+/// unsigned, so a word with the top bit set is a large value, not a negative one.
+///
+/// # Example
+/// ```
+/// use dsyrs::ServoStatus;
+///
+/// // Every word with only the top bit set
+/// let mut regs = [0x8000u16; ServoStatus::BLOCK_LEN];
+/// regs[8] = 0x0000;
+/// let status = ServoStatus::from_registers(&regs)?;
+/// assert_eq!(status.speed, i16::MIN);
+/// assert_eq!(status.torque, i16::MIN);
+/// assert_eq!(status.position, i32::MIN);
+/// assert_eq!(status.load_rate, 0x8000);
+/// assert_eq!(status.current, 0x8000);
+/// assert_eq!(status.bus_voltage, 0x8000);
+/// assert_eq!(status.electrical_angle, 0x8000);
+/// assert!(status.torque_percent() < 0.0);
+/// assert!(status.current_amps() > 327.0);
+/// assert!(status.bus_voltage_volts() > 3276.0);
+/// assert!(status.load_rate_percent() > 3276.0);
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
 #[derive(Debug, Clone)]
 pub struct ServoStatus {
     /// Current servo state
     pub state: ServoState,
-    /// Motor speed feedback (rpm)
+    /// Motor speed feedback (rpm, signed, ±9000)
     pub speed: i16,
-    /// Average load rate (0.1%)
+    /// Average load rate (0.1%, unsigned, 0-3000)
     pub load_rate: u16,
-    /// Internal torque (0.1% of rated)
+    /// Internal torque (0.1% of rated, signed, ±5000)
     pub torque: i16,
-    /// Phase current RMS (0.01 A)
+    /// Phase current RMS (0.01 A, unsigned)
     pub current: u16,
-    /// DC bus voltage (0.1 V)
+    /// DC bus voltage (0.1 V, unsigned)
     pub bus_voltage: u16,
-    /// Absolute position
+    /// Absolute position (command units, signed 32-bit, ±2^30)
     pub position: i32,
-    /// Electrical angle (0.1°)
+    /// Electrical angle (0.1°, unsigned, 0-3600)
     pub electrical_angle: u16,
 }
 
+impl ServoStatus {
+    /// Number of registers in the P18.00-P18.09 status block
+    pub const BLOCK_LEN: usize = 10;
+
+    /// Parse the P18.00-P18.09 status block read in one transaction
+    ///
+    /// The absolute position spans P18.07 (high word) and P18.08 (low word), see
+    /// `from_registers_with_order()` for drives using the other word order;
+    /// P18.03 (speed command) is not part of `ServoStatus` and is skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::{ServoState, ServoStatus};
+    ///
+    /// let regs = [
+    ///     0x0001,                // P18.00 running
+    ///     (-1500i16) as u16,     // P18.01 speed
+    ///     325,                   // P18.02 load rate
+    ///     (-1500i16) as u16,     // P18.03 speed command (skipped)
+    ///     (-420i16) as u16,      // P18.04 torque
+    ///     512,                   // P18.05 current
+    ///     480,                   // P18.06 bus voltage
+    ///     0xFFFE, 0x7960,        // P18.07-08 position = -100000
+    ///     1800,                  // P18.09 electrical angle
+    /// ];
+    /// let status = ServoStatus::from_registers(&regs)?;
+    /// assert_eq!(status.state, ServoState::Running);
+    /// assert_eq!(status.speed, -1500);
+    /// assert_eq!(status.load_rate, 325);
+    /// assert_eq!(status.torque, -420);
+    /// assert_eq!(status.current, 512);
+    /// assert_eq!(status.bus_voltage, 480);
+    /// assert_eq!(status.position, -100000);
+    /// assert_eq!(status.electrical_angle, 1800);
+    ///
+    /// assert!(ServoStatus::from_registers(&regs[..9]).is_err());
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn from_registers(regs: &[u16]) -> Result<Self> {
+        Self::from_registers_with_order(regs, WordOrder::HighFirst)
+    }
+
+    /// Parse the P18.00-P18.09 status block with the given 32-bit word order
+    pub fn from_registers_with_order(regs: &[u16], order: WordOrder) -> Result<Self> {
+        if regs.len() < Self::BLOCK_LEN {
+            return Err(DsyrsError::OperationFailed(format!(
+                "Status block too short: expected {} registers, got {}",
+                Self::BLOCK_LEN,
+                regs.len()
+            )));
+        }
+        Ok(Self {
+            state: ServoState::from(regs[0]),
+            speed: regs[1] as i16,
+            load_rate: regs[2],
+            torque: regs[4] as i16,
+            current: regs[5],
+            bus_voltage: regs[6],
+            position: order.join([regs[7], regs[8]]) as i32,
+            electrical_angle: regs[9],
+        })
+    }
+
+    /// Average load rate (%)
+    pub fn load_rate_percent(&self) -> f32 {
+        self.load_rate as f32 * 0.1
+    }
+
+    /// Internal torque (% of rated)
+    pub fn torque_percent(&self) -> f32 {
+        TorquePercent::from_raw(self.torque as u16).0
+    }
+
+    /// Phase current RMS (A)
+    pub fn current_amps(&self) -> f32 {
+        Amps::from_raw(self.current).0
+    }
+
+    /// DC bus voltage (V)
+    pub fn bus_voltage_volts(&self) -> f32 {
+        Volts::from_raw(self.bus_voltage).0
+    }
+
+    /// Electrical angle (degrees)
+    pub fn electrical_angle_degrees(&self) -> f32 {
+        self.electrical_angle as f32 * 0.1
+    }
+}
+
+/// One-line summary in engineering units
+///
+/// # Example
+/// ```
+/// use dsyrs::{ServoState, ServoStatus};
+///
+/// let status = ServoStatus {
+///     state: ServoState::Running,
+///     speed: -1500,
+///     load_rate: 325,
+///     torque: -420,
+///     current: 512,
+///     bus_voltage: 480,
+///     position: -100000,
+///     electrical_angle: 1800,
+/// };
+/// assert_eq!(status.current_amps(), 5.12);
+/// assert_eq!(
+///     status.to_string(),
+///     "Running, -1500 rpm, load 32.5 %, torque -42.0 %, 5.12 A, 48.0 V, \
+///      position -100000, angle 180.0°"
+/// );
+/// ```
+impl std::fmt::Display for ServoStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?}, {}, load {:.1} %, torque {}, {}, {}, position {}, angle {:.1}°",
+            self.state,
+            Rpm(self.speed),
+            self.load_rate_percent(),
+            TorquePercent(self.torque_percent()),
+            Amps(self.current_amps()),
+            Volts(self.bus_voltage_volts()),
+            self.position,
+            self.electrical_angle_degrees()
+        )
+    }
+}
+
+/// Pre-flight checklist returned by `ready_for_motion()`
+#[derive(Debug, Clone)]
+pub struct ReadinessReport {
+    /// Servo state read from P18.00
+    pub state: ServoState,
+    /// Active fault or alarm, if any
+    pub fault: Option<FaultCode>,
+    /// Servo is enabled (running state)
+    pub servo_enabled: bool,
+    /// Control mode configured on the drive (P00.00)
+    pub control_mode: ControlMode,
+    /// Drive control mode matches the client `ServoConfig`
+    pub control_mode_matches: bool,
+    /// Command source problem for host control, if any
+    pub command_routing_issue: Option<String>,
+    /// No overtravel warning (AL.950) in P18.00
+    ///
+    /// The limit inputs themselves cannot be read over Modbus; the drive
+    /// raises this warning while either limit is active.
+    pub overtravel_clear: bool,
+}
+
+impl ReadinessReport {
+    /// Check if every verifiable check passed
+    pub fn is_ready(&self) -> bool {
+        self.problems().is_empty()
+    }
+
+    /// Describe every failed check
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(fault) = self.fault {
+            problems.push(format!("Active fault {}", fault));
+        }
+        if !self.servo_enabled {
+            problems.push(format!("Servo not enabled (state {:?})", self.state));
+        }
+        if !self.control_mode_matches {
+            problems.push(format!(
+                "Drive control mode is {:?}, not the configured mode",
+                self.control_mode
+            ));
+        }
+        if let Some(issue) = &self.command_routing_issue {
+            problems.push(issue.clone());
+        }
+        if !self.overtravel_clear {
+            problems.push("Overtravel limit reached (AL.950)".into());
+        }
+        problems
+    }
+}
+
 /// Gain parameters for tuning
+///
+/// Gain set 1 is always written by `apply_gain_params()`; the gain set 2
+/// values only when present. Gain set 2 takes effect through gain switching
+/// (P07.10/P07.11), e.g. stiff gains while moving and soft gains at rest.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GainParams {
     /// Position loop gain (0.1 Hz)
     pub position_gain: u16,
@@ -1052,6 +4603,12 @@ pub struct GainParams {
     pub speed_integral: u16,
     /// Speed detection filter (0.01 ms)
     pub speed_filter: u16,
+    /// Position loop gain 2 (P07.05, 0.1 Hz), left unchanged if None
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position_gain_2: Option<u16>,
+    /// Speed loop gain 2 (P07.06, 0.1 Hz), left unchanged if None
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub speed_gain_2: Option<u16>,
 }
 
 impl Default for GainParams {
@@ -1061,12 +4618,103 @@ impl Default for GainParams {
             speed_gain: 180,      // 18.0 Hz
             speed_integral: 3100, // 31.0 ms
             speed_filter: 20,     // 0.2 ms
+            position_gain_2: None,
+            speed_gain_2: None,
+        }
+    }
+}
+
+impl GainParams {
+    /// Set the gain set 2 loop gains (P07.05/P07.06, 0.1 Hz)
+    pub fn with_gain_set_2(mut self, position_gain: u16, speed_gain: u16) -> Self {
+        self.position_gain_2 = Some(position_gain);
+        self.speed_gain_2 = Some(speed_gain);
+        self
+    }
+}
+
+/// Notch filter parameters (P08.02-P08.04 for the 1st notch)
+///
+/// Written with `set_notch_filter()`, read back with `read_notch_filter()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotchFilter {
+    /// Notch frequency (10-4000 Hz, 4000 = disabled)
+    pub frequency: u16,
+    /// Notch width (0-8)
+    pub width: u16,
+    /// Notch depth (0-100)
+    pub depth: u16,
+}
+
+impl Default for NotchFilter {
+    fn default() -> Self {
+        Self {
+            frequency: 4000,
+            width: 8,
+            depth: 50,
+        }
+    }
+}
+
+impl NotchFilter {
+    /// Create a notch filter at the given frequency with default width and depth
+    pub fn new(frequency: u16) -> Self {
+        Self {
+            frequency,
+            ..Default::default()
+        }
+    }
+
+    /// Set notch width
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set notch depth
+    pub fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Check every value against its documented range
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::NotchFilter;
+    ///
+    /// assert!(NotchFilter::new(10).with_width(0).with_depth(0).validate().is_ok());
+    /// assert!(NotchFilter::new(4000).with_width(8).with_depth(100).validate().is_ok());
+    ///
+    /// assert!(NotchFilter::new(9).validate().is_err());
+    /// assert!(NotchFilter::new(4001).validate().is_err());
+    /// assert!(NotchFilter::new(500).with_width(9).validate().is_err());
+    /// assert!(NotchFilter::new(500).with_depth(101).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        if !(10..=4000).contains(&self.frequency) {
+            return Err(DsyrsError::InvalidParameter(
+                "Notch frequency must be 10-4000 Hz".into(),
+            ));
+        }
+        if self.width > 8 {
+            return Err(DsyrsError::InvalidParameter(
+                "Notch width must be 0-8".into(),
+            ));
         }
+        if self.depth > 100 {
+            return Err(DsyrsError::InvalidParameter(
+                "Notch depth must be 0-100".into(),
+            ));
+        }
+        Ok(())
     }
 }
 
 /// Communication configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommConfig {
     /// Slave address (0-247, 0=broadcast)
     pub address: u8,
@@ -1088,3 +4736,565 @@ impl Default for CommConfig {
         }
     }
 }
+
+/// Electronic gear selection, switched by the ElectronicGearSelection input (FunIN.23)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gear {
+    /// Gear 1 (P04.07/P04.09), input inactive
+    #[default]
+    Gear1,
+    /// Gear 2 (P04.11/P04.13), input active
+    Gear2,
+}
+
+/// Electronic gear ratio (P04.07/P04.09 for gear 1, P04.11/P04.13 for gear 2)
+///
+/// Encoder increments = command units × numerator / denominator.
+///
+/// # Example
+/// ```
+/// use dsyrs::GearRatio;
+///
+/// // 17-bit encoder (131072 inc/rev) on a 5 mm lead screw, 1 µm per unit:
+/// // one motor revolution is 5000 units
+/// let ratio = GearRatio::for_resolution(131072, 5000)?;
+/// assert_eq!((ratio.numerator, ratio.denominator), (16384, 625));
+///
+/// // 23-bit encoder on a 10 mm lead screw, 0.1 µm per unit
+/// let ratio = GearRatio::for_resolution(8_388_608, 100_000)?;
+/// assert_eq!((ratio.numerator, ratio.denominator), (262144, 3125));
+///
+/// // Units per revolution equal to the encoder resolution gives 1:1
+/// assert_eq!(GearRatio::for_resolution(10000, 10000)?, GearRatio::new(1, 1)?);
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GearRatio {
+    /// Gear numerator (1-1073741824)
+    pub numerator: u32,
+    /// Gear denominator (1-1073741824)
+    pub denominator: u32,
+}
+
+impl GearRatio {
+    /// Maximum value for the numerator and denominator (2^30)
+    pub const MAX_TERM: u32 = 1 << 30;
+
+    /// Create a gear ratio, reduced to lowest terms
+    ///
+    /// Both terms are divided by their greatest common divisor, and must not
+    /// exceed [`MAX_TERM`](Self::MAX_TERM) once reduced.
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::GearRatio;
+    ///
+    /// assert_eq!(GearRatio::new(10000, 5000)?, GearRatio { numerator: 2, denominator: 1 });
+    /// assert_eq!(GearRatio::new(131072, 10000)?, GearRatio { numerator: 8192, denominator: 625 });
+    /// assert_eq!(GearRatio::new(7, 3)?, GearRatio { numerator: 7, denominator: 3 });
+    /// assert!(GearRatio::new(1 << 31, 3).is_err());
+    /// assert!(GearRatio::new(1, 0).is_err());
+    /// # Ok::<(), dsyrs::DsyrsError>(())
+    /// ```
+    pub fn new(numerator: u32, denominator: u32) -> Result<Self> {
+        if numerator == 0 || denominator == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "Gear ratio terms must be non-zero".into(),
+            ));
+        }
+        let divisor = gcd(numerator, denominator);
+        let ratio = Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        };
+        if ratio.numerator > Self::MAX_TERM || ratio.denominator > Self::MAX_TERM {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Gear ratio {}/{} exceeds 1073741824",
+                ratio.numerator, ratio.denominator
+            )));
+        }
+        Ok(ratio)
+    }
+
+    /// Compute the gear ratio giving `units_per_rev` command units per motor revolution
+    ///
+    /// Assumes `encoder_resolution` is the encoder increments per revolution (P01.20)
+    /// and one command unit per pulse or per communication position unit.
+    pub fn for_resolution(encoder_resolution: u32, units_per_rev: u32) -> Result<Self> {
+        Self::new(encoder_resolution, units_per_rev)
+    }
+
+    /// Check if the ratio is in lowest terms
+    pub fn is_reduced(&self) -> bool {
+        gcd(self.numerator, self.denominator) == 1
+    }
+}
+
+/// Greatest common divisor (Euclid)
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Multi-turn absolute position, as encoder turns plus an offset within the turn
+///
+/// Computed from the absolute position (P18.07, command units), electronic
+/// gear 1 (P04.07/P04.09) and the encoder resolution (P01.20): the position
+/// is converted to encoder increments (units × numerator / denominator) and
+/// split into whole turns and the remaining increments.
+///
+/// Turns count from the origin set by homing. With the rotary absolute
+/// system (P00.06 = 2) the drive wraps the position within one revolution of
+/// the load, so `turns` rolls back to 0 after each load revolution instead of
+/// growing; with the linear absolute system (P00.06 = 1) it follows the
+/// position up to its ±2³⁰ unit limit.
+///
+/// # Example
+/// ```
+/// use dsyrs::{GearRatio, MultiTurnPosition};
+///
+/// // 17-bit encoder, 1:1 gear: 2.5 turns
+/// let gear = GearRatio::new(1, 1)?;
+/// let pos = MultiTurnPosition::from_position(327_680, gear, 131_072)?;
+/// assert_eq!(pos, MultiTurnPosition { turns: 2, single_turn: 65_536 });
+///
+/// // Negative positions floor towards minus infinity
+/// let pos = MultiTurnPosition::from_position(-1, gear, 131_072)?;
+/// assert_eq!(pos, MultiTurnPosition { turns: -1, single_turn: 131_071 });
+///
+/// // Default gear: 10000 units per revolution
+/// let gear = GearRatio { numerator: 131_072, denominator: 10_000 };
+/// let pos = MultiTurnPosition::from_position(25_000, gear, 131_072)?;
+/// assert_eq!(pos, MultiTurnPosition { turns: 2, single_turn: 65_536 });
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiTurnPosition {
+    /// Whole encoder turns from the origin
+    pub turns: i32,
+    /// Position within the turn (encoder increments, 0 to resolution - 1)
+    pub single_turn: u32,
+}
+
+impl MultiTurnPosition {
+    /// Split an absolute position in command units into turns and single-turn offset
+    pub fn from_position(position: i32, gear: GearRatio, encoder_resolution: u32) -> Result<Self> {
+        if gear.denominator == 0 || encoder_resolution == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "Gear denominator and encoder resolution must be non-zero".into(),
+            ));
+        }
+        let increments =
+            (position as i128 * gear.numerator as i128).div_euclid(gear.denominator as i128);
+        let resolution = encoder_resolution as i128;
+        let turns = i32::try_from(increments.div_euclid(resolution)).map_err(|_| {
+            DsyrsError::InvalidParameter(format!(
+                "Turn count out of range for position {}",
+                position
+            ))
+        })?;
+        Ok(Self {
+            turns,
+            single_turn: increments.rem_euclid(resolution) as u32,
+        })
+    }
+}
+
+/// Conversion between position pulses and user units (mm, degrees, ...)
+///
+/// Positions (P18.07, segment displacements) are in command units, the
+/// "pulses" of the electronic gear: one pulse is `gear_num / gear_denom`
+/// encoder increments, and one motor revolution is `encoder_resolution`
+/// increments (P01.20). `units_per_rev` gives the user units travelled per
+/// motor revolution, e.g. the lead of a ball screw in mm or 360 for degrees.
+///
+/// `user_to_pulses()` rounds to the nearest pulse, halves away from zero, and
+/// saturates at the `i32` range (NaN gives 0).
+///
+/// # Example
+/// ```
+/// use dsyrs::UnitConverter;
+///
+/// // 17-bit encoder, default gear (10000 pulses per revolution), 5 mm lead
+/// let conv = UnitConverter::new(131_072, 131_072, 10_000, 5.0)?;
+/// assert_eq!(conv.pulses_to_user(10_000), 5.0);
+/// assert_eq!(conv.pulses_to_user(-2_500), -1.25);
+/// assert_eq!(conv.user_to_pulses(2.5), 5_000);
+///
+/// // 1 pulse is 0.5 µm: 1.25 µm rounds to 3 pulses, -1.25 µm to -3
+/// assert_eq!(conv.user_to_pulses(0.00125), 3);
+/// assert_eq!(conv.user_to_pulses(-0.00125), -3);
+///
+/// // Rotary table in degrees, 1:1 gear on a 23-bit encoder
+/// let conv = UnitConverter::new(8_388_608, 1, 1, 360.0)?;
+/// assert_eq!(conv.user_to_pulses(90.0), 2_097_152);
+///
+/// assert!(UnitConverter::new(131_072, 1, 0, 5.0).is_err());
+/// assert!(UnitConverter::new(131_072, 1, 1, 0.0).is_err());
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitConverter {
+    /// Encoder increments per motor revolution (P01.20)
+    pub encoder_resolution: u32,
+    /// Electronic gear numerator (P04.07)
+    pub gear_num: u32,
+    /// Electronic gear denominator (P04.09)
+    pub gear_denom: u32,
+    /// User units per motor revolution
+    pub units_per_rev: f64,
+}
+
+impl UnitConverter {
+    /// Create a converter, rejecting zero terms and a non-positive `units_per_rev`
+    pub fn new(
+        encoder_resolution: u32,
+        gear_num: u32,
+        gear_denom: u32,
+        units_per_rev: f64,
+    ) -> Result<Self> {
+        let converter = Self {
+            encoder_resolution,
+            gear_num,
+            gear_denom,
+            units_per_rev,
+        };
+        converter.validate()?;
+        Ok(converter)
+    }
+
+    /// Check the terms of a converter built field by field
+    pub fn validate(&self) -> Result<()> {
+        if self.encoder_resolution == 0 || self.gear_num == 0 || self.gear_denom == 0 {
+            return Err(DsyrsError::InvalidParameter(
+                "Encoder resolution and gear terms must be non-zero".into(),
+            ));
+        }
+        if !(self.units_per_rev.is_finite() && self.units_per_rev > 0.0) {
+            return Err(DsyrsError::InvalidParameter(format!(
+                "Units per revolution must be positive, got {}",
+                self.units_per_rev
+            )));
+        }
+        Ok(())
+    }
+
+    /// User units per pulse
+    fn units_per_pulse(&self) -> f64 {
+        self.units_per_rev * self.gear_num as f64
+            / (self.gear_denom as f64 * self.encoder_resolution as f64)
+    }
+
+    /// Convert a position in pulses to user units
+    pub fn pulses_to_user(&self, pulses: i32) -> f64 {
+        pulses as f64 * self.units_per_pulse()
+    }
+
+    /// Convert a position in user units to pulses, rounded to the nearest pulse
+    pub fn user_to_pulses(&self, value: f64) -> i32 {
+        (value / self.units_per_pulse()).round() as i32
+    }
+}
+
+/// Firmware and product identification (P12.11-P12.14)
+///
+/// Versions are stored as decimal X.YY numbers, e.g. 123 for version 1.23,
+/// and displayed that way.
+///
+/// # Example
+/// ```
+/// use dsyrs::FirmwareInfo;
+///
+/// let info = FirmwareInfo::from_registers(&[0, 123, 105, 3])?;
+/// assert_eq!(info.software, 123);
+/// assert_eq!(info.software_version(), (1, 23));
+/// assert_eq!(info.to_string(), "1.23 (FPGA 1.05, product 3)");
+/// assert!(info.is_at_least(1, 20));
+/// assert!(info.is_at_least(1, 23));
+/// assert!(!info.is_at_least(1, 24));
+/// assert!(!info.is_at_least(2, 0));
+///
+/// let info = FirmwareInfo::from_registers(&[7, 200, 100, 3])?;
+/// assert_eq!(info.to_string(), "2.00 (FPGA 1.00, product 3, non-standard 7)");
+///
+/// assert!(FirmwareInfo::from_registers(&[0, 123, 105]).is_err());
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FirmwareInfo {
+    /// Software version (P12.12, X.YY)
+    pub software: u16,
+    /// FPGA version (P12.13, X.YY)
+    pub fpga: u16,
+    /// Non-standard (customer-specific) version (P12.11), 0 for standard firmware
+    pub nonstandard: u16,
+    /// Product series code (P12.14)
+    pub product_code: u16,
+}
+
+impl FirmwareInfo {
+    /// Number of registers in the P12.11-P12.14 block
+    pub const BLOCK_LEN: usize = 4;
+
+    /// Parse the P12.11-P12.14 block read in one transaction
+    pub fn from_registers(regs: &[u16]) -> Result<Self> {
+        if regs.len() < Self::BLOCK_LEN {
+            return Err(DsyrsError::OperationFailed(format!(
+                "Version block too short: expected {} registers, got {}",
+                Self::BLOCK_LEN,
+                regs.len()
+            )));
+        }
+        Ok(Self {
+            nonstandard: regs[0],
+            software: regs[1],
+            fpga: regs[2],
+            product_code: regs[3],
+        })
+    }
+
+    /// Software version as (major, minor)
+    pub fn software_version(&self) -> (u16, u16) {
+        (self.software / 100, self.software % 100)
+    }
+
+    /// FPGA version as (major, minor)
+    pub fn fpga_version(&self) -> (u16, u16) {
+        (self.fpga / 100, self.fpga % 100)
+    }
+
+    /// Whether the software version is `major.minor` or later
+    pub fn is_at_least(&self, major: u16, minor: u16) -> bool {
+        self.software_version() >= (major, minor)
+    }
+}
+
+impl std::fmt::Display for FirmwareInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (major, minor) = self.software_version();
+        let (fpga_major, fpga_minor) = self.fpga_version();
+        write!(
+            f,
+            "{}.{:02} (FPGA {}.{:02}, product {}",
+            major, minor, fpga_major, fpga_minor, self.product_code
+        )?;
+        if self.nonstandard != 0 {
+            write!(f, ", non-standard {}", self.nonstandard)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Forced levels of the DI terminals (DI1-DI9), as held in P11.11
+///
+/// Bit 0 is DI1, bit 8 is DI9. A set bit is a high level; the function
+/// assigned to the terminal is active when the level matches its logic in
+/// P02. Returned by `read_forced_di()`; the wired levels cannot be read.
+///
+/// # Example
+/// ```
+/// use dsyrs::DiStates;
+///
+/// let states = DiStates::from(0b1_0000_0101);
+/// assert_eq!(states.raw, 0x0105);
+/// assert!(states.input(1) && !states.input(2) && states.input(3));
+/// assert!(states.input(9));
+/// assert!(!states.input(10));
+/// assert_eq!(states.inputs[8], true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiStates {
+    /// Raw word, bit 0 = DI1
+    pub raw: u16,
+    /// Level of each terminal, index 0 = DI1
+    pub inputs: [bool; 9],
+}
+
+impl DiStates {
+    /// Level of a terminal (1-9), `false` for any other number
+    pub fn input(&self, terminal: u8) -> bool {
+        (1..=9).contains(&terminal) && self.inputs[terminal as usize - 1]
+    }
+}
+
+impl From<u16> for DiStates {
+    fn from(raw: u16) -> Self {
+        Self {
+            raw,
+            inputs: std::array::from_fn(|i| raw & (1 << i) != 0),
+        }
+    }
+}
+
+/// Forced levels of the DO terminals (DO1-DO5), as held in P11.12
+///
+/// Bit 0 is DO1, bit 4 is DO5. Returned by `read_forced_do()`.
+///
+/// # Example
+/// ```
+/// use dsyrs::DoStates;
+///
+/// let states = DoStates::from(0b1_0010);
+/// assert!(!states.output(1) && states.output(2) && states.output(5));
+/// assert!(!states.output(6));
+/// assert_eq!(states.outputs, [false, true, false, false, true]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoStates {
+    /// Raw word, bit 0 = DO1
+    pub raw: u16,
+    /// Level of each terminal, index 0 = DO1
+    pub outputs: [bool; 5],
+}
+
+impl DoStates {
+    /// Level of a terminal (1-5), `false` for any other number
+    pub fn output(&self, terminal: u8) -> bool {
+        (1..=5).contains(&terminal) && self.outputs[terminal as usize - 1]
+    }
+}
+
+impl From<u16> for DoStates {
+    fn from(raw: u16) -> Self {
+        Self {
+            raw,
+            outputs: std::array::from_fn(|i| raw & (1 << i) != 0),
+        }
+    }
+}
+
+/// Communication counters accumulated by a client, see `stats()`
+///
+/// Every request attempt sent by the low-level read/write methods counts in
+/// `requests`, retries included. Failed attempts are counted by kind:
+/// transport failures and timeouts (no or garbled response, CRC errors) as
+/// `io_errors`, malformed Modbus responses as `protocol_errors`, and
+/// exception answers from the drive as `exceptions`.
+///
+/// # Example
+/// ```
+/// use dsyrs::mock::MockTransport;
+/// use dsyrs::{registers, DsyrsSyncClient, ExceptionCode, RetryPolicy, ServoConfig};
+/// use std::time::Duration;
+///
+/// let mock = MockTransport::new();
+/// let config = ServoConfig::new(1).with_retry_policy(RetryPolicy::new(2, Duration::ZERO));
+/// let mut servo = DsyrsSyncClient::new(mock.clone(), config);
+///
+/// servo.get_speed()?;
+/// mock.fail_next(1);
+/// servo.get_speed()?;
+/// mock.set_exception(registers::P18_BUS_VOLTAGE, ExceptionCode::IllegalDataAddress);
+/// assert!(servo.get_bus_voltage().is_err());
+///
+/// let stats = servo.stats();
+/// assert_eq!(stats.requests, 4);
+/// assert_eq!(stats.retries, 1);
+/// assert_eq!(stats.io_errors, 1);
+/// assert_eq!(stats.exceptions, 1);
+/// assert_eq!(stats.errors(), 2);
+///
+/// servo.reset_stats();
+/// assert_eq!(*servo.stats(), Default::default());
+/// # Ok::<(), dsyrs::DsyrsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CommStats {
+    /// Request attempts sent, retries included
+    pub requests: u64,
+    /// Attempts repeated under the retry policy
+    pub retries: u64,
+    /// Transport failures and timeouts
+    pub io_errors: u64,
+    /// Malformed responses
+    pub protocol_errors: u64,
+    /// Modbus exception responses
+    pub exceptions: u64,
+}
+
+impl CommStats {
+    /// Total failed attempts
+    pub fn errors(&self) -> u64 {
+        self.io_errors + self.protocol_errors + self.exceptions
+    }
+
+    /// Count a failed attempt by kind
+    pub(crate) fn record_error(&mut self, err: &DsyrsError) {
+        match err {
+            DsyrsError::Modbus(_)
+            | DsyrsError::Timeout
+            | DsyrsError::ModbusProtocol(tokio_modbus::Error::Transport(_)) => self.io_errors += 1,
+            DsyrsError::ModbusProtocol(_) => self.protocol_errors += 1,
+            DsyrsError::ModbusException(_) => self.exceptions += 1,
+            _ => {}
+        }
+    }
+
+    /// Unwrap a drive answer, counting an exception response
+    pub(crate) fn check<R>(
+        &mut self,
+        response: std::result::Result<R, ExceptionCode>,
+    ) -> Result<R> {
+        response.map_err(|code| {
+            self.exceptions += 1;
+            code.into()
+        })
+    }
+}
+
+/// Bus round-trip latency statistics from `measure_latency()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// Number of samples
+    pub samples: usize,
+    /// Fastest round trip
+    pub min: Duration,
+    /// Mean round trip
+    pub mean: Duration,
+    /// Slowest round trip
+    pub max: Duration,
+    /// 99th percentile round trip
+    pub p99: Duration,
+}
+
+impl LatencyStats {
+    /// Compute statistics from round-trip samples, `None` if there are none
+    ///
+    /// # Example
+    /// ```
+    /// use dsyrs::LatencyStats;
+    /// use std::time::Duration;
+    ///
+    /// let samples: Vec<Duration> = (1..=100).map(Duration::from_millis).collect();
+    /// let stats = LatencyStats::from_samples(samples).unwrap();
+    /// assert_eq!(stats.min, Duration::from_millis(1));
+    /// assert_eq!(stats.max, Duration::from_millis(100));
+    /// assert_eq!(stats.p99, Duration::from_millis(99));
+    /// assert_eq!(stats.mean, Duration::from_micros(50500));
+    /// ```
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let n = samples.len();
+        let total: Duration = samples.iter().sum();
+        // Nearest-rank percentile
+        let p99_rank = (n * 99).div_ceil(100);
+        Some(Self {
+            samples: n,
+            min: samples[0],
+            mean: total / n as u32,
+            max: samples[n - 1],
+            p99: samples[p99_rank - 1],
+        })
+    }
+}
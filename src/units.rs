@@ -0,0 +1,176 @@
+//! Physical-unit newtypes for DSY-RS status values
+//!
+//! The plain getters return raw integers or scaled `f32` values whose unit is
+//! only given in the documentation. These newtypes carry the unit in the type,
+//! so an rpm value cannot be passed where a torque percentage is expected:
+//! - [`Rpm`]: speed, 1 rpm per count (signed)
+//! - [`Amps`]: current, 0.01 A per count
+//! - [`TorquePercent`]: torque, 0.1% of rated per count (signed)
+//! - [`Volts`]: voltage, 0.1 V per count
+//!
+//! Each type converts from and to the raw register value with `from_raw()` and
+//! `to_raw()`, and the `*_typed()` getters on both clients return them.
+//!
+//! # Example
+//! ```
+//! use dsyrs::units::{Amps, Rpm, TorquePercent, Volts};
+//!
+//! assert_eq!(Rpm::from_raw(0xFF38), Rpm(-200));
+//! assert_eq!(Amps::from_raw(250), Amps(2.5));
+//! assert_eq!(TorquePercent::from_raw(0xFFF6), TorquePercent(-1.0));
+//! assert_eq!(Volts::from_raw(240), Volts(24.0));
+//!
+//! assert_eq!(Amps(2.5).to_raw(), 250);
+//! assert_eq!(TorquePercent(-1.0).to_raw(), 0xFFF6);
+//! assert_eq!(Volts(24.0).to_raw(), 240);
+//!
+//! assert_eq!(Amps(2.5).to_string(), "2.50 A");
+//! assert_eq!(TorquePercent(-1.0).to_string(), "-1.0 %");
+//! ```
+
+use std::fmt;
+
+/// Motor speed in rpm (1 rpm per register count, signed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Rpm(pub i16);
+
+impl Rpm {
+    /// Convert a raw register value
+    pub fn from_raw(raw: u16) -> Self {
+        Rpm(raw as i16)
+    }
+
+    /// Convert to the raw register value
+    pub fn to_raw(self) -> u16 {
+        self.0 as u16
+    }
+}
+
+impl From<i16> for Rpm {
+    fn from(rpm: i16) -> Self {
+        Rpm(rpm)
+    }
+}
+
+impl From<Rpm> for i16 {
+    fn from(rpm: Rpm) -> Self {
+        rpm.0
+    }
+}
+
+impl fmt::Display for Rpm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rpm", self.0)
+    }
+}
+
+/// Current in amperes (0.01 A per register count)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Amps(pub f32);
+
+impl Amps {
+    /// Amperes per register count
+    pub const SCALE: f32 = 0.01;
+
+    /// Convert a raw register value
+    pub fn from_raw(raw: u16) -> Self {
+        Amps(raw as f32 * Self::SCALE)
+    }
+
+    /// Convert to the raw register value (rounded, saturating at 0 and 655.35 A)
+    pub fn to_raw(self) -> u16 {
+        (self.0 / Self::SCALE).round() as u16
+    }
+}
+
+impl From<f32> for Amps {
+    fn from(amps: f32) -> Self {
+        Amps(amps)
+    }
+}
+
+impl From<Amps> for f32 {
+    fn from(amps: Amps) -> Self {
+        amps.0
+    }
+}
+
+impl fmt::Display for Amps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} A", self.0)
+    }
+}
+
+/// Torque as a percentage of rated torque (0.1% per register count, signed)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct TorquePercent(pub f32);
+
+impl TorquePercent {
+    /// Percent per register count
+    pub const SCALE: f32 = 0.1;
+
+    /// Convert a raw register value
+    pub fn from_raw(raw: u16) -> Self {
+        TorquePercent(raw as i16 as f32 * Self::SCALE)
+    }
+
+    /// Convert to the raw register value (rounded, saturating at the i16 range)
+    pub fn to_raw(self) -> u16 {
+        (self.0 / Self::SCALE).round() as i16 as u16
+    }
+}
+
+impl From<f32> for TorquePercent {
+    fn from(percent: f32) -> Self {
+        TorquePercent(percent)
+    }
+}
+
+impl From<TorquePercent> for f32 {
+    fn from(percent: TorquePercent) -> Self {
+        percent.0
+    }
+}
+
+impl fmt::Display for TorquePercent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} %", self.0)
+    }
+}
+
+/// Voltage in volts (0.1 V per register count)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Volts(pub f32);
+
+impl Volts {
+    /// Volts per register count
+    pub const SCALE: f32 = 0.1;
+
+    /// Convert a raw register value
+    pub fn from_raw(raw: u16) -> Self {
+        Volts(raw as f32 * Self::SCALE)
+    }
+
+    /// Convert to the raw register value (rounded, saturating at 0 and 6553.5 V)
+    pub fn to_raw(self) -> u16 {
+        (self.0 / Self::SCALE).round() as u16
+    }
+}
+
+impl From<f32> for Volts {
+    fn from(volts: f32) -> Self {
+        Volts(volts)
+    }
+}
+
+impl From<Volts> for f32 {
+    fn from(volts: Volts) -> Self {
+        volts.0
+    }
+}
+
+impl fmt::Display for Volts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} V", self.0)
+    }
+}
@@ -0,0 +1,1431 @@
+//! Client behavior against the in-memory `MockTransport` (`test-util` feature)
+
+use dsyrs::mock::{simulated_client, MockTransport};
+use dsyrs::model::ServoConfigModel;
+use dsyrs::{
+    registers, BrakeConfig, BrakingResistorConfig, ControlMode, DiFunction, DiLogic, DoFunction,
+    DoLogic, DsyrsClient, DsyrsError, DsyrsSyncClient, EncoderReset, EncoderType, EnergyResistor,
+    ExceptionCode, FaultCode, Gear, GearRatio, HomingConfig, InertiaIdMode, JogDirection,
+    MotionCommand, MotionProgram, MultiSegOperationMode, MultiSegPositionMode, NotchFilter,
+    OvertravelStopMode, ParameterSet, RetryPolicy, SegmentConfig, ServoConfig, ServoFault,
+    ServoState, TorqueModeConfig, WarningCode, WordOrder,
+};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn init_writes_configuration() {
+    let config = ServoConfig::new(1)
+        .with_control_mode(ControlMode::Speed)
+        .with_max_speed(2000);
+    let (mut servo, mock) = simulated_client(config);
+    servo.init().await.unwrap();
+
+    assert_eq!(
+        mock.writes(),
+        vec![
+            (registers::P00_CONTROL_MODE, 1),
+            (registers::P00_DIRECTION, 0),
+            (registers::P00_MAX_SPEED, 2000),
+        ]
+    );
+    assert_eq!(mock.slave(), Some(1));
+}
+
+#[tokio::test]
+async fn scripted_reads_fall_back_to_register_map() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [0, 500, 1000]);
+
+    for expected in [0, 500, 1000] {
+        assert_eq!(servo.get_speed().await.unwrap(), expected);
+    }
+    assert_eq!(servo.get_speed().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn fault_and_reset() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    assert_eq!(servo.get_servo_state().await.unwrap(), ServoState::Ready);
+
+    // Error state in the low nibble, fault code above it
+    mock.set_register(registers::P18_SERVO_STATUS, (0x410 << 4) | 2);
+    assert_eq!(
+        servo.get_fault_code().await.unwrap(),
+        Some(FaultCode(0x410))
+    );
+    assert_eq!(servo.get_servo_state().await.unwrap(), ServoState::Error);
+
+    servo.reset_fault().await.unwrap();
+    assert_eq!(mock.writes().last(), Some(&(registers::P11_FAULT_RESET, 1)));
+    mock.set_register(registers::P18_SERVO_STATUS, 0);
+    assert_eq!(servo.get_fault_code().await.unwrap(), None);
+    assert_eq!(servo.get_servo_state().await.unwrap(), ServoState::Ready);
+}
+
+#[tokio::test]
+async fn warnings_are_not_faults() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // Encoder battery low (AL.730): Alarm state
+    mock.set_register(registers::P18_SERVO_STATUS, (0x730 << 4) | 3);
+    assert_eq!(servo.get_fault_code().await.unwrap(), None);
+    assert_eq!(
+        servo.get_warning_code().await.unwrap(),
+        Some(WarningCode::EncoderBatteryLow)
+    );
+
+    mock.set_register(registers::P18_SERVO_STATUS, (0x601 << 4) | 2);
+    let fault = servo.get_fault_code().await.unwrap().unwrap();
+    assert_eq!(ServoFault::from(fault), ServoFault::HomingTimeout(0x601));
+    assert_eq!(servo.get_warning_code().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn homing_wait_completes_on_home_position() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [300, 120, 0]);
+    mock.script_reads(registers::P18_ABSOLUTE_POSITION + 1, [900, 40, 0]);
+    servo
+        .wait_for_homing_complete(Duration::from_millis(1), Duration::from_millis(500))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn homing_wait_needs_homing_to_have_started() {
+    // At rest on the default home offset, homing never triggered
+    let (mut servo, _mock) = simulated_client(ServoConfig::new(1));
+    let result = servo
+        .wait_for_homing_complete(Duration::from_millis(1), Duration::from_millis(50))
+        .await;
+    assert!(matches!(result, Err(DsyrsError::Timeout)));
+}
+
+#[tokio::test]
+async fn homing_wait_completes_once_the_trigger_clears() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // Started with P16.08 = 3, which the drive clears while the motor stays still
+    mock.script_reads(registers::P16_HOMING_ENABLE_MODE, [3, 3]);
+    servo
+        .wait_for_homing_complete(Duration::from_millis(1), Duration::from_millis(500))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn homing_wait_ignores_unrelated_warnings() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // Encoder battery low (AL.730) while homing runs
+    mock.set_register(registers::P18_SERVO_STATUS, (0x730 << 4) | 3);
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [300, 0]);
+    servo
+        .wait_for_homing_complete(Duration::from_millis(1), Duration::from_millis(500))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn homing_wait_aborts_on_overtravel() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 300);
+    mock.script_reads(registers::P18_SERVO_STATUS, [1, 1, (0x950 << 4) | 3]);
+    let result = servo
+        .wait_for_homing_complete(Duration::from_millis(1), Duration::from_millis(500))
+        .await;
+    assert!(matches!(result, Err(DsyrsError::OperationFailed(_))));
+}
+
+#[tokio::test]
+async fn run_homing_starts_and_waits() {
+    let timeout = Duration::from_millis(500);
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let config = HomingConfig::default().with_timeout(200);
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [300, 0]);
+    servo.run_homing(&config, timeout).await.unwrap();
+    assert_eq!(mock.register(registers::P16_HOMING_ENABLE_MODE), 3);
+
+    // The home switch is never found: the drive's homing timeout (Er.601) ends it
+    mock.set_register(registers::P18_SERVO_STATUS, (0x601 << 4) | 2);
+    let result = servo.run_homing(&config, timeout).await;
+    assert!(matches!(result, Err(DsyrsError::Timeout)));
+}
+
+#[tokio::test]
+async fn run_homing_cancels_on_host_timeout() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let config = HomingConfig::default().with_timeout(20);
+    // Still searching for the home switch when the host gives up
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 300);
+    let result = servo.run_homing(&config, Duration::from_millis(50)).await;
+    assert!(matches!(result, Err(DsyrsError::Timeout)));
+    assert_eq!(mock.register(registers::P16_HOMING_ENABLE_MODE), 0);
+    assert_eq!(
+        mock.writes().last(),
+        Some(&(registers::P16_HOMING_ENABLE_MODE, 0))
+    );
+}
+
+#[tokio::test]
+async fn run_homing_needs_motion() {
+    // P16.08 stays set and the motor never turns: not reported as homed
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let config = HomingConfig::default().with_timeout(20);
+    let result = servo.run_homing(&config, Duration::from_millis(50)).await;
+    assert!(matches!(result, Err(DsyrsError::Timeout)));
+    assert_eq!(mock.register(registers::P16_HOMING_ENABLE_MODE), 0);
+}
+
+#[tokio::test]
+async fn read_params_coalesces_requests() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P05_ACCEL_TIME, 100);
+    mock.set_register(registers::P05_DECEL_TIME, 200);
+    mock.set_register(registers::P00_MAX_SPEED, 3000);
+
+    // Unsorted input, values come back in the requested order
+    let values = servo
+        .read_params(&[
+            registers::P05_DECEL_TIME,
+            registers::P00_MAX_SPEED,
+            registers::P05_ACCEL_TIME,
+        ])
+        .await
+        .unwrap();
+    assert_eq!(values, vec![200, 3000, 100]);
+    assert_eq!(mock.reads().len(), 2);
+}
+
+#[tokio::test]
+async fn parameter_set_refuses_link_parameters() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let mut set: ParameterSet = "P00.04=15\nP10.02=6".parse().unwrap();
+    assert!(matches!(
+        servo.apply_parameter_set(&set).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+    assert!(mock.writes().is_empty());
+
+    set.remove(registers::P10_MODBUS_BAUDRATE);
+    servo.apply_parameter_set(&set).await.unwrap();
+    assert_eq!(mock.writes(), vec![(registers::P00_RIGIDITY, 15)]);
+}
+
+#[tokio::test]
+async fn factory_reset_checks_the_restored_comm_settings() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(5));
+    mock.set_register(registers::P10_COMM_ADDRESS, 5);
+    mock.set_register(registers::P10_RS232_BAUDRATE, 2);
+    // The reset lands after the address was written back
+    mock.script_reads(registers::P10_COMM_ADDRESS, [5, 1]);
+    assert!(matches!(
+        servo.factory_reset_preserving_comm(true).await,
+        Err(DsyrsError::OperationFailed(_))
+    ));
+    assert!(mock
+        .writes()
+        .iter()
+        .all(|&(addr, _)| addr != registers::P10_WRITE_EEPROM));
+}
+
+#[tokio::test]
+async fn dump_and_restore_parameters() {
+    let (mut source, source_mock) = simulated_client(ServoConfig::new(1));
+    source_mock.set_register(registers::P00_MAX_SPEED, 3000);
+    // 32-bit segment 1 displacement = -100000 (high word, low word)
+    source_mock.set_registers(registers::P13_SEG1_DISPLACEMENT, &[0xFFFE, 0x7960]);
+    // Absolute encoder origin of the source motor
+    source_mock.set_registers(registers::P16_ENCODER_ORIGIN, &[2, 0x1000]);
+    source_mock.set_register(registers::P16_ENCODER_TURNS, 7);
+    let backup = source.dump_parameters().await.unwrap();
+
+    let (mut target, target_mock) = simulated_client(ServoConfig::new(2));
+    target.restore_parameters(&backup).await.unwrap();
+
+    let origin = [
+        registers::P16_ENCODER_ORIGIN,
+        registers::P16_ENCODER_ORIGIN + 1,
+        registers::P16_ENCODER_TURNS,
+    ];
+    let restored = target.dump_parameters().await.unwrap();
+    for (addr, value) in &backup {
+        let expected = if origin.contains(addr) { 0 } else { *value };
+        assert_eq!(restored[addr], expected);
+    }
+    assert!(target_mock
+        .writes()
+        .iter()
+        .all(|&(addr, _)| addr >> 8 != 1 && addr >> 8 != 18 && !origin.contains(&addr)));
+
+    // Restoring onto the same motor keeps its origin
+    target
+        .restore_parameters_with_origin(&backup)
+        .await
+        .unwrap();
+    assert_eq!(target.dump_parameters().await.unwrap(), backup);
+}
+
+#[tokio::test]
+async fn retry_policy() {
+    let config =
+        ServoConfig::new(1).with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1)));
+    let (mut servo, mock) = simulated_client(config);
+    mock.set_register(registers::P00_MAX_SPEED, 3000);
+
+    // Two timeouts, then the third attempt succeeds
+    mock.fail_next(2);
+    assert_eq!(
+        servo.read_register(registers::P00_MAX_SPEED).await.unwrap(),
+        3000
+    );
+
+    // A permanent failure gives up after max_retries (1 attempt + 3 retries)
+    mock.fail_next(4);
+    match servo.write_register(registers::P00_MAX_SPEED, 2000).await {
+        Err(DsyrsError::RetriesExhausted { retries, .. }) => assert_eq!(retries, 3),
+        other => panic!("expected exhausted retries, got {:?}", other),
+    }
+    assert!(mock.writes().is_empty());
+
+    // An exception is the drive's answer: reported at once, never retried
+    mock.set_exception(
+        registers::P08_NOTCH1_FREQUENCY,
+        ExceptionCode::IllegalDataAddress,
+    );
+    let err = servo
+        .write_register(registers::P08_NOTCH1_FREQUENCY, 850)
+        .await
+        .unwrap_err();
+    assert!(err.is_illegal_address() && !err.is_transient());
+}
+
+#[tokio::test]
+async fn request_timeouts_are_retried() {
+    let config = ServoConfig::new(1)
+        .with_timeout(Duration::from_millis(20))
+        .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1)));
+    let (mut servo, mock) = simulated_client(config);
+    mock.set_register(registers::P00_MAX_SPEED, 3000);
+
+    // One unanswered request, as the sync client would see a timed-out read
+    mock.stall_next(1);
+    assert_eq!(
+        servo.read_register(registers::P00_MAX_SPEED).await.unwrap(),
+        3000
+    );
+    assert_eq!(servo.stats().retries, 1);
+}
+
+#[tokio::test]
+async fn latency_samples_use_the_retry_policy() {
+    let config =
+        ServoConfig::new(1).with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1)));
+    let (mut servo, mock) = simulated_client(config);
+    mock.fail_next(1);
+    servo.measure_latency(3).await.unwrap();
+    assert_eq!(servo.stats().requests, 4);
+    assert_eq!(servo.stats().retries, 1);
+}
+
+#[test]
+fn sync_client_shares_the_mock() {
+    let mock = MockTransport::new();
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 1500);
+    let mut servo = DsyrsSyncClient::new(mock.clone(), ServoConfig::new(3));
+    servo.init().unwrap();
+    assert_eq!(servo.get_speed().unwrap(), 1500);
+    assert_eq!(mock.slave(), Some(3));
+    assert_eq!(mock.writes().len(), 3);
+}
+
+#[tokio::test]
+async fn enable_and_disable_through_forced_input() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // Factory I/O: DI1 = ServoEnable, low active; forced DI value all high
+    mock.set_register(registers::P02_DI1_FUNCTION, 1);
+    mock.set_register(registers::P11_FORCED_DI_VALUE, 0x01FF);
+
+    servo.enable().await.unwrap();
+    assert_eq!(mock.register(registers::P11_FORCED_DI_VALUE), 0x01FE);
+    assert_eq!(mock.register(registers::P11_FORCED_DIDO), 1);
+    let levels = servo.read_forced_di().await.unwrap();
+    assert!(!levels.input(1) && levels.input(2));
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+    assert!(servo.is_enabled().await.unwrap());
+
+    servo.disable().await.unwrap();
+    assert_eq!(mock.register(registers::P11_FORCED_DI_VALUE), 0x01FF);
+    assert_eq!(mock.register(registers::P11_FORCED_DIDO), 0);
+    // Without forcing, the terminal levels cannot be read back
+    assert!(servo.read_forced_di().await.is_err());
+}
+
+#[tokio::test]
+async fn emergency_stop_blocks_enabling_but_not_disabling() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P02_DI1_FUNCTION, 1);
+    servo.enable().await.unwrap();
+    servo.emergency_stop().await.unwrap();
+    assert!(servo.is_emergency_stop_latched());
+    mock.clear_history();
+
+    assert!(matches!(
+        servo.enable().await,
+        Err(DsyrsError::EmergencyStopActive)
+    ));
+    assert!(matches!(
+        servo.force_di_bit(2, true).await,
+        Err(DsyrsError::EmergencyStopActive)
+    ));
+    assert!(matches!(
+        servo.use_multi_speed().await,
+        Err(DsyrsError::EmergencyStopActive)
+    ));
+    assert!(matches!(
+        servo.set_speed_command(500).await,
+        Err(DsyrsError::EmergencyStopActive)
+    ));
+    assert!(matches!(
+        servo.set_torque_command(100).await,
+        Err(DsyrsError::EmergencyStopActive)
+    ));
+    assert!(mock.writes().is_empty());
+
+    servo.disable().await.unwrap();
+    assert_eq!(mock.register(registers::P11_FORCED_DIDO), 0);
+
+    servo.clear_emergency_stop().await.unwrap();
+    assert!(!servo.is_emergency_stop_latched());
+    servo.set_speed_command(500).await.unwrap();
+}
+
+#[tokio::test]
+async fn gear_ratios_1_and_2() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // Reduced to 8192/625 before writing
+    servo.set_gear_ratio(131072, 10000).await.unwrap();
+    servo.set_gear_ratio_2(0x0002_0000, 625).await.unwrap();
+
+    // Each 32-bit term is written high word first, like write_u32()
+    assert_eq!(
+        mock.writes(),
+        vec![
+            (registers::P04_GEAR1_NUMERATOR, 0x0000),
+            (registers::P04_GEAR1_NUMERATOR + 1, 8192),
+            (registers::P04_GEAR1_DENOMINATOR, 0x0000),
+            (registers::P04_GEAR1_DENOMINATOR + 1, 625),
+            (registers::P04_GEAR2_NUMERATOR, 0x0002),
+            (registers::P04_GEAR2_NUMERATOR + 1, 0x0000),
+            (registers::P04_GEAR2_DENOMINATOR, 0x0000),
+            (registers::P04_GEAR2_DENOMINATOR + 1, 625),
+        ]
+    );
+    let gear2 = servo.read_gear_ratio_2().await.unwrap();
+    assert_eq!((gear2.numerator, gear2.denominator), (0x0002_0000, 625));
+
+    // The raw variant writes the terms as given
+    servo
+        .set_gear_ratio_raw(Gear::Gear1, 10000, 5000)
+        .await
+        .unwrap();
+    assert_eq!(servo.get_gear_ratio().await.unwrap(), (10000, 5000));
+
+    for result in [
+        servo.set_gear_ratio(1, 0).await,
+        servo.set_gear_ratio_2(1, 0).await,
+        servo.set_gear_ratio_raw(Gear::Gear2, 1, 0).await,
+        servo.set_gear_ratio_raw(Gear::Gear2, 0, 1).await,
+        servo
+            .set_gear_ratio_raw(Gear::Gear1, GearRatio::MAX_TERM + 1, 1)
+            .await,
+    ] {
+        assert!(matches!(result, Err(DsyrsError::InvalidParameter(_))));
+    }
+    assert_eq!(servo.get_gear_ratio().await.unwrap(), (10000, 5000));
+}
+
+#[tokio::test]
+async fn word_order_of_32_bit_parameters() {
+    for (order, words) in [
+        (WordOrder::HighFirst, [0x1234, 0x5678]),
+        (WordOrder::LowFirst, [0x5678, 0x1234]),
+    ] {
+        let (mut servo, mock) = simulated_client(ServoConfig::new(1).with_word_order(order));
+
+        servo
+            .write_u32(registers::P04_GEAR1_NUMERATOR, 0x12345678)
+            .await
+            .unwrap();
+        assert_eq!(mock.register(registers::P04_GEAR1_NUMERATOR), words[0]);
+        assert_eq!(mock.register(registers::P04_GEAR1_NUMERATOR + 1), words[1]);
+        assert_eq!(
+            servo
+                .read_u32(registers::P04_GEAR1_NUMERATOR)
+                .await
+                .unwrap(),
+            0x12345678
+        );
+
+        // Position -100000 = 0xFFFE7960, stored in the configured order
+        let position = order.split(-100000i32 as u32);
+        mock.set_registers(registers::P18_ABSOLUTE_POSITION, &position);
+        assert_eq!(servo.get_position().await.unwrap(), -100000);
+        assert_eq!(servo.get_status_fast().await.unwrap().position, -100000);
+    }
+}
+
+#[tokio::test]
+async fn jog_releases_the_input() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // DI2 = ForwardJog (FunIN.17), high active; forcing off
+    mock.set_register(registers::P02_DI2_FUNCTION, 17);
+    mock.set_register(registers::P02_DI2_LOGIC, 1);
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+
+    servo
+        .jog(JogDirection::Forward, Duration::from_millis(30))
+        .await
+        .unwrap();
+    assert!(mock
+        .writes()
+        .contains(&(registers::P11_FORCED_DI_VALUE, 0b10)));
+    assert_eq!(mock.register(registers::P11_FORCED_DI_VALUE), 0);
+    assert_eq!(mock.register(registers::P11_FORCED_DIDO), 0);
+
+    // The servo faults mid-jog: the input is still released
+    mock.script_reads(registers::P18_SERVO_STATUS, [1, (0x410 << 4) | 2]);
+    let result = servo
+        .jog(JogDirection::Forward, Duration::from_secs(5))
+        .await;
+    assert!(matches!(result, Err(DsyrsError::OperationFailed(_))));
+    assert_eq!(mock.register(registers::P11_FORCED_DI_VALUE), 0);
+    assert_eq!(mock.register(registers::P11_FORCED_DIDO), 0);
+}
+
+#[tokio::test]
+async fn notch_filter_and_range_checks() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let filter = NotchFilter::new(850).with_width(4).with_depth(30);
+    servo.set_notch_filter(&filter).await.unwrap();
+    assert_eq!(
+        mock.writes(),
+        vec![
+            (registers::P08_NOTCH1_FREQUENCY, 850),
+            (registers::P08_NOTCH1_WIDTH, 4),
+            (registers::P08_NOTCH1_DEPTH, 30),
+        ]
+    );
+    assert_eq!(servo.read_notch_filter(1).await.unwrap(), filter);
+
+    // Out-of-range values are rejected before anything is written
+    mock.clear_history();
+    for bad in [
+        NotchFilter::new(9),
+        NotchFilter::new(4001),
+        NotchFilter::new(850).with_width(9),
+        NotchFilter::new(850).with_depth(101),
+    ] {
+        assert!(servo.set_notch_filter(&bad).await.is_err());
+    }
+    assert!(servo.set_adaptive_filter_mode(6).await.is_err());
+    assert!(mock.writes().is_empty());
+
+    servo.set_adaptive_filter_mode(5).await.unwrap();
+    servo.disable_notch_filter().await.unwrap();
+    assert_eq!(mock.register(registers::P08_ADAPTIVE_FILTER_MODE), 5);
+    assert_eq!(servo.get_adaptive_filter_mode().await.unwrap(), 5);
+    assert_eq!(mock.register(registers::P08_NOTCH1_DEPTH), 0);
+}
+
+#[tokio::test]
+async fn inertia_identification() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // Disabled: refused before anything is written
+    let result = servo
+        .identify_inertia(InertiaIdMode::OfflineJog, Duration::from_secs(1))
+        .await;
+    assert!(matches!(result, Err(DsyrsError::ServoNotReady)));
+    assert!(mock.writes().is_empty());
+
+    // Enabled: P11.03 stays set for two polls, then the drive clears it
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+    mock.script_reads(registers::P11_INERTIA_RECOGNITION, [1, 1, 0]);
+    mock.set_register(registers::P00_INERTIA_RATIO, 350);
+    let ratio = servo
+        .identify_inertia(InertiaIdMode::OfflineJog, Duration::from_secs(1))
+        .await
+        .unwrap();
+    assert_eq!(ratio, 350);
+    assert_eq!(mock.register(registers::P08_INERTIA_ID_MODE), 1);
+}
+
+#[tokio::test]
+async fn relative_and_absolute_moves() {
+    let timeout = Duration::from_millis(500);
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P04_POSITIONING_RANGE, 10);
+
+    // Starts at 1000, still moving on the first poll, then settles at 1495
+    mock.script_reads(registers::P18_ABSOLUTE_POSITION + 1, [1000, 1300]);
+    mock.set_register(registers::P18_ABSOLUTE_POSITION + 1, 1495);
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [200, 0]);
+    servo.move_relative(500, 300, timeout).await.unwrap();
+    assert_eq!(mock.register(registers::P13_SEG1_DISPLACEMENT + 1), 500);
+    assert_eq!(mock.register(registers::P13_SEG1_SPEED), 300);
+    assert_eq!(mock.register(registers::P13_POSITION_MODE), 0);
+    assert_eq!(mock.register(registers::P04_POSITION_CMD_SOURCE), 4);
+
+    mock.set_register(registers::P18_ABSOLUTE_POSITION + 1, 0);
+    mock.clear_history();
+    servo.move_absolute(0, 300, timeout).await.unwrap();
+    assert_eq!(mock.register(registers::P13_POSITION_MODE), 1);
+    // FunIN.29 is unassigned: its state (P02.10 bit 12) gets a new rising edge
+    let trigger: Vec<_> = mock
+        .writes()
+        .into_iter()
+        .filter(|&(addr, _)| addr == registers::P02_FUNINH_STATE)
+        .collect();
+    assert_eq!(
+        trigger,
+        vec![
+            (registers::P02_FUNINH_STATE, 0),
+            (registers::P02_FUNINH_STATE, 1 << 12)
+        ]
+    );
+
+    // Speed mode: refused before the segment is programmed
+    mock.set_register(registers::P00_CONTROL_MODE, 1);
+    mock.clear_history();
+    assert!(servo.move_absolute(0, 300, timeout).await.is_err());
+    assert!(mock.writes().is_empty());
+}
+
+#[tokio::test]
+async fn position_command_pulses_a_forced_start_input() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    // FunIN.29 on DI2 (high active), DI forcing already on
+    mock.set_register(registers::P02_DI2_FUNCTION, 29);
+    mock.set_register(registers::P02_DI2_LOGIC, 1);
+    mock.set_register(registers::P11_FORCED_DIDO, 1);
+    servo
+        .command(MotionCommand::PositionRelative(100))
+        .await
+        .unwrap();
+    let forced: Vec<_> = mock
+        .writes()
+        .into_iter()
+        .filter(|&(addr, _)| addr == registers::P11_FORCED_DI_VALUE)
+        .collect();
+    assert_eq!(
+        forced,
+        vec![
+            (registers::P11_FORCED_DI_VALUE, 0),
+            (registers::P11_FORCED_DI_VALUE, 0b10)
+        ]
+    );
+
+    // Without DI forcing the wired input is left in charge
+    mock.set_register(registers::P11_FORCED_DIDO, 0);
+    mock.clear_history();
+    servo
+        .command(MotionCommand::PositionRelative(100))
+        .await
+        .unwrap();
+    assert!(mock
+        .writes()
+        .iter()
+        .all(|&(addr, _)| addr != registers::P11_FORCED_DI_VALUE
+            && addr != registers::P11_FORCED_DIDO));
+}
+
+#[tokio::test]
+async fn in_position_wait_checks_the_target() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P04_POSITIONING_RANGE, 10);
+
+    // Idle at the start reads as finished, but the target is not reached
+    assert!(servo.is_motion_finished().await.unwrap());
+    assert!(matches!(
+        servo
+            .wait_for_in_position(500, Duration::from_millis(50))
+            .await,
+        Err(DsyrsError::Timeout)
+    ));
+
+    mock.script_reads(registers::P18_ABSOLUTE_POSITION + 1, [0, 200]);
+    mock.set_register(registers::P18_ABSOLUTE_POSITION + 1, 495);
+    servo
+        .wait_for_in_position(500, Duration::from_millis(500))
+        .await
+        .unwrap();
+
+    // Within range but still commanded to turn
+    mock.set_register(registers::P18_SPEED_COMMAND, 300);
+    assert!(matches!(
+        servo
+            .wait_for_in_position(500, Duration::from_millis(50))
+            .await,
+        Err(DsyrsError::Timeout)
+    ));
+}
+
+#[tokio::test]
+async fn model_program_is_downloaded() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let program = MotionProgram::builder(1, 2)
+        .with_segment(
+            SegmentConfig::new(1)
+                .unwrap()
+                .with_displacement(70_000)
+                .with_speed(1500),
+        )
+        .with_segment(SegmentConfig::new(2).unwrap().with_displacement(-2))
+        .build()
+        .unwrap();
+    let model = ServoConfigModel::new(ServoConfig::new(1).with_max_speed(3000))
+        .with_multi_segment(program.clone());
+    servo.apply_model(&model).await.unwrap();
+    assert_eq!(mock.register(registers::P13_END_SEGMENT), 2);
+    assert_eq!(mock.register(registers::P13_SEG1_SPEED), 1500);
+    assert_eq!(mock.register(registers::P13_SEG2_DISPLACEMENT + 1), 0xFFFE);
+
+    // Segment speeds above 9000 rpm are refused before anything is written
+    mock.clear_history();
+    let mut fast = program;
+    fast.segments[0].speed = 9001;
+    assert!(servo.download_program(&fast).await.is_err());
+    assert!(mock.writes().is_empty());
+}
+
+#[tokio::test]
+async fn speed_and_torque_command_limits() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1).with_max_speed(3000));
+    servo.set_speed_command(1500).await.unwrap();
+    servo.set_speed_command(-3000).await.unwrap();
+    assert_eq!(
+        mock.register(registers::P05_SPEED_COMMAND),
+        (-3000i16) as u16
+    );
+    assert!(matches!(
+        servo.set_speed_command(3001).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+
+    // A configured maximum above the drive limit is capped at 9000 rpm
+    let (mut servo, _) = simulated_client(ServoConfig::new(1).with_max_speed(10000));
+    servo.set_speed_command(9000).await.unwrap();
+    assert!(servo.set_speed_command(-9001).await.is_err());
+
+    servo.set_torque_command(-3000).await.unwrap();
+    servo.set_torque_command(3000).await.unwrap();
+    assert!(matches!(
+        servo.set_torque_command(3001).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+}
+
+#[tokio::test]
+async fn broadcast_writes_skip_the_response() {
+    // The mock, like a real drive, never answers slave 0
+    let mock = MockTransport::new();
+    let mut all = DsyrsClient::broadcast(mock.clone());
+    assert_eq!(mock.slave(), Some(0));
+
+    let started = std::time::Instant::now();
+    all.set_speed_command(0).await.unwrap();
+    assert_eq!(mock.register(registers::P05_SPEED_COMMAND), 0);
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    // Reads cannot be broadcast
+    assert!(all.get_speed().await.is_err());
+
+    let mut all = DsyrsSyncClient::broadcast(mock.clone());
+    all.set_speed_command(250).unwrap();
+    assert_eq!(mock.register(registers::P05_SPEED_COMMAND), 250);
+}
+
+#[tokio::test]
+async fn register_bits_preserve_other_bits() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P11_FORCED_DI_VALUE, 0x01F0);
+    mock.set_register(registers::P11_FORCED_DO_VALUE, 0x0011);
+
+    servo.force_di_bit(2, true).await.unwrap();
+    servo.force_di_bit(9, false).await.unwrap();
+    assert_eq!(mock.register(registers::P11_FORCED_DI_VALUE), 0x00F2);
+
+    servo.force_do_bit(1, false).await.unwrap();
+    assert_eq!(mock.register(registers::P11_FORCED_DO_VALUE), 0x0010);
+    assert!(servo.force_do_bit(6, true).await.is_err());
+
+    assert!(servo
+        .toggle_register_bit(registers::P02_FUNINL_STATE, 15)
+        .await
+        .unwrap());
+    assert!(!servo
+        .toggle_register_bit(registers::P02_FUNINL_STATE, 15)
+        .await
+        .unwrap());
+    assert!(servo
+        .set_register_bit(registers::P02_FUNINL_STATE, 16, true)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn fixed_length_interrupt() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo.enable_fixed_length(true).await.unwrap();
+    servo
+        .configure_fixed_length_1(0x0002_0005, 1000)
+        .await
+        .unwrap();
+    servo.configure_fixed_length_2(-2, 500).await.unwrap();
+    assert_eq!(mock.register(registers::P16_FIXED_LENGTH_ENABLE), 1);
+    // 32-bit displacements, high word first
+    assert_eq!(mock.register(registers::P16_FIXED_LENGTH1_DISP), 0x0002);
+    assert_eq!(mock.register(registers::P16_FIXED_LENGTH1_DISP + 1), 0x0005);
+    assert_eq!(mock.register(registers::P16_FIXED_LENGTH2_DISP), 0xFFFF);
+    assert_eq!(mock.register(registers::P16_FIXED_LENGTH2_DISP + 1), 0xFFFE);
+    assert!(servo.configure_fixed_length_1(1 << 30, 1000).await.is_err());
+    assert!(servo
+        .configure_fixed_length_2(-(1 << 30), 500)
+        .await
+        .is_err());
+
+    // DI3 = InterruptFixedLengthReset (FunIN.36), high active; forcing off
+    mock.set_register(registers::P02_DI3_FUNCTION, 36);
+    mock.set_register(registers::P02_DI3_LOGIC, 1);
+    mock.clear_history();
+    servo.reset_fixed_length().await.unwrap();
+    let forced: Vec<u16> = mock
+        .writes()
+        .into_iter()
+        .filter(|&(addr, _)| addr == registers::P11_FORCED_DI_VALUE)
+        .map(|(_, value)| value)
+        .collect();
+    assert_eq!(forced, vec![0b100, 0]);
+    assert_eq!(mock.register(registers::P11_FORCED_DIDO), 0);
+}
+
+#[tokio::test]
+async fn status_stream_reports_failed_reads() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+    // One failed read is reported and sampling carries on
+    mock.fail_next(1);
+    let samples: Vec<_> = servo
+        .status_stream(Duration::from_millis(10))
+        .take(10)
+        .collect()
+        .await;
+    assert_eq!(samples.len(), 10);
+    assert!(samples[0].is_err());
+    for status in samples.into_iter().skip(1) {
+        assert_eq!(status.unwrap().state, ServoState::Running);
+    }
+}
+
+#[tokio::test]
+async fn readiness_reports_overtravel_from_the_status_word() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+    assert!(servo.ready_for_motion().await.unwrap().overtravel_clear);
+
+    // Overtravel warning (AL.950) in Alarm state
+    mock.set_register(registers::P18_SERVO_STATUS, (0x950 << 4) | 3);
+    let report = servo.ready_for_motion().await.unwrap();
+    assert!(!report.overtravel_clear);
+    assert!(report
+        .problems()
+        .iter()
+        .any(|problem| problem.contains("AL.950")));
+}
+
+#[tokio::test]
+async fn command_routing_accepts_the_crate_positioning_source() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P18_SERVO_STATUS, 1);
+    mock.set_register(registers::P04_POSITIONING_RANGE, 10);
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [200, 0]);
+    mock.set_register(registers::P18_ABSOLUTE_POSITION + 1, 500);
+    servo
+        .move_absolute(500, 300, Duration::from_millis(500))
+        .await
+        .unwrap();
+    let report = servo.ready_for_motion().await.unwrap();
+    assert_eq!(report.command_routing_issue, None);
+
+    // Pulse input: host moves would be ignored
+    mock.set_register(registers::P04_POSITION_CMD_SOURCE, 0);
+    assert!(servo.validate_command_routing().await.is_err());
+}
+
+#[tokio::test]
+async fn command_routing_in_speed_mode() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P00_CONTROL_MODE, 1);
+    servo.validate_command_routing().await.unwrap();
+
+    // Auxiliary B from the digit value is accepted too
+    mock.set_register(registers::P05_SPEED_CMD_SELECT, 2);
+    servo.validate_command_routing().await.unwrap();
+
+    // A/B switching, or an undocumented selection
+    for select in [3, 1] {
+        mock.set_register(registers::P05_SPEED_CMD_SELECT, select);
+        assert!(matches!(
+            servo.validate_command_routing().await,
+            Err(DsyrsError::OperationFailed(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn verified_writes_catch_ignored_values() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo
+        .write_register_verified(registers::P00_MAX_SPEED, 3000)
+        .await
+        .unwrap();
+
+    // The drive ignores the write: the read-back still holds position mode
+    mock.script_reads(registers::P00_CONTROL_MODE, [0]);
+    assert!(matches!(
+        servo.set_control_mode(ControlMode::Speed).await,
+        Err(DsyrsError::OperationFailed(_))
+    ));
+}
+
+#[tokio::test]
+async fn short_replies_are_protocol_errors() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.short_read_next(3);
+    assert!(matches!(
+        servo.get_speed().await,
+        Err(DsyrsError::OperationFailed(_))
+    ));
+    assert!(matches!(
+        servo.get_position().await,
+        Err(DsyrsError::OperationFailed(_))
+    ));
+    assert!(matches!(
+        servo.get_status_fast().await,
+        Err(DsyrsError::OperationFailed(_))
+    ));
+    assert_eq!(servo.stats().protocol_errors, 3);
+    assert_eq!(servo.get_speed().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn displacements_are_limited_to_30_bits() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let addr = registers::P16_FIXED_LENGTH2_DISP;
+
+    servo.write_i30(addr, (1 << 30) - 1).await.unwrap();
+    assert_eq!(servo.read_i32(addr).await.unwrap(), (1 << 30) - 1);
+    servo.write_i30(addr, -(1 << 30) + 1).await.unwrap();
+    assert_eq!(servo.read_i32(addr).await.unwrap(), -(1 << 30) + 1);
+    assert_eq!(mock.register(addr), 0xC000);
+    assert_eq!(mock.register(addr + 1), 0x0001);
+
+    assert!(servo.write_i30(addr, 1 << 30).await.is_err());
+    assert!(servo.write_i30(addr, -(1 << 30)).await.is_err());
+    assert_eq!(servo.read_i32(addr).await.unwrap(), -(1 << 30) + 1);
+}
+
+#[tokio::test]
+async fn brake_config_round_trip() {
+    let (mut servo, _mock) = simulated_client(ServoConfig::new(1));
+    let brake = BrakeConfig::default()
+        .with_overtravel_stop_mode(OvertravelStopMode::DecelThenFreewheel)
+        .with_off_delay(300);
+    servo.apply_brake_config(&brake).await.unwrap();
+    assert_eq!(servo.read_brake_config().await.unwrap(), brake);
+    assert!(servo.set_brake_speed_threshold(1001).await.is_err());
+}
+
+#[tokio::test]
+async fn braking_resistor_ratings_are_written_first() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P18_BUS_VOLTAGE, 3100);
+    let config = BrakingResistorConfig::new(EnergyResistor::ExternalNatural, 380)
+        .with_external_resistor(200, 50, 5000);
+    servo.apply_braking_resistor_config(&config).await.unwrap();
+    assert_eq!(servo.read_braking_resistor_config().await.unwrap(), config);
+
+    let writes = mock.writes();
+    let ratings = writes
+        .iter()
+        .position(|w| w.0 == registers::P00_EXT_RESISTOR_POWER);
+    let selection = writes
+        .iter()
+        .position(|w| w.0 == registers::P00_ENERGY_RESISTOR);
+    assert!(ratings < selection);
+
+    // An external resistor without ratings is refused before anything is written
+    mock.clear_history();
+    let unrated = BrakingResistorConfig::new(EnergyResistor::ExternalForced, 380);
+    assert!(matches!(
+        servo.apply_braking_resistor_config(&unrated).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+    assert!(mock.writes().is_empty());
+
+    assert!(servo.set_external_resistor(200, 0, 5000).await.is_err());
+    assert!(servo.set_external_resistor(0, 50, 5000).await.is_err());
+    assert!(servo.set_brake_start_voltage(1001).await.is_err());
+}
+
+#[tokio::test]
+async fn io_functions_read_back() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let inputs = [
+        (DiFunction::ServoEnable, DiLogic::HighActive),
+        (DiFunction::ForwardOvertravel, DiLogic::LowActive),
+        (DiFunction::HomeSwitchSignal, DiLogic::RisingEdge),
+    ];
+    for (input, (function, logic)) in (1..=3).zip(inputs) {
+        servo.set_di_function(input, function).await.unwrap();
+        servo.set_di_logic(input, logic).await.unwrap();
+        assert_eq!(servo.get_di_function(input).await.unwrap(), function);
+        assert_eq!(servo.get_di_logic(input).await.unwrap(), logic);
+    }
+    for output in 1..=2 {
+        servo
+            .set_do_function(output, DoFunction::ZeroSpeedSignal)
+            .await
+            .unwrap();
+        servo
+            .set_do_logic(output, DoLogic::NormallyClosed)
+            .await
+            .unwrap();
+        assert_eq!(
+            servo.get_do_function(output).await.unwrap(),
+            DoFunction::ZeroSpeedSignal
+        );
+        assert_eq!(
+            servo.get_do_logic(output).await.unwrap(),
+            DoLogic::NormallyClosed
+        );
+    }
+
+    mock.set_register(registers::P02_DI1_FUNCTION, 45);
+    assert_eq!(
+        servo.get_di_function(1).await.unwrap(),
+        DiFunction::Unknown(45)
+    );
+
+    assert!(matches!(
+        servo.get_di_function(4).await,
+        Err(DsyrsError::InvalidDigitalInput(4))
+    ));
+    assert!(matches!(
+        servo.get_di_logic(0).await,
+        Err(DsyrsError::InvalidDigitalInput(0))
+    ));
+    assert!(matches!(
+        servo.get_do_function(3).await,
+        Err(DsyrsError::InvalidDigitalOutput(3))
+    ));
+    assert!(matches!(
+        servo.get_do_logic(3).await,
+        Err(DsyrsError::InvalidDigitalOutput(3))
+    ));
+}
+
+#[tokio::test]
+async fn verified_gear_ratio_is_reduced() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let ratio = servo.verify_gear_ratio(131072, 10000).await.unwrap();
+    assert_eq!((ratio.numerator, ratio.denominator), (8192, 625));
+    assert_eq!(servo.get_gear_ratio().await.unwrap(), (8192, 625));
+
+    // 32-bit values, high word first
+    mock.set_registers(registers::P04_GEAR1_NUMERATOR, &[0x0002, 0x0000]);
+    assert_eq!(servo.get_gear_ratio().await.unwrap(), (131072, 625));
+
+    // The drive keeps another numerator than the one written
+    mock.script_reads(registers::P04_GEAR1_NUMERATOR + 1, [5]);
+    assert!(matches!(
+        servo.verify_gear_ratio(2, 1).await,
+        Err(DsyrsError::OperationFailed(_))
+    ));
+}
+
+#[tokio::test]
+async fn zero_speed_uses_the_threshold() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo.set_zero_speed_threshold(20).await.unwrap();
+
+    mock.set_register(registers::P18_SPEED_FEEDBACK, (-20i16) as u16);
+    assert!(servo.is_at_zero_speed().await.unwrap());
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 21);
+    assert!(!servo.is_at_zero_speed().await.unwrap());
+
+    assert!(servo.set_zero_speed_threshold(6001).await.is_err());
+    assert!(servo.set_zero_speed_clamp(6001).await.is_err());
+}
+
+#[tokio::test]
+async fn acceleration_profile() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let timeout = Duration::from_secs(1);
+
+    // Position mode is refused before anything is commanded
+    assert!(matches!(
+        servo.measure_acceleration(1000, timeout).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+
+    servo.set_control_mode(ControlMode::Speed).await.unwrap();
+    mock.set_register(registers::P05_SPEED_UNIFORM_WIDTH, 10);
+    mock.script_reads(
+        registers::P18_SPEED_FEEDBACK,
+        [0, 400, 800, 995, 1060, 1020],
+    );
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 1000);
+    let profile = servo.measure_acceleration(1000, timeout).await.unwrap();
+    assert_eq!(mock.register(registers::P05_SPEED_COMMAND), 1000);
+    let speeds: Vec<i16> = profile.samples[..4].iter().map(|s| s.1).collect();
+    assert_eq!(speeds, [0, 400, 800, 995]);
+    assert_eq!(profile.time_to_target, profile.samples[3].0);
+    assert_eq!(profile.overshoot_rpm, 60);
+
+    // Never reaches the target
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 0);
+    let result = servo
+        .measure_acceleration(-500, Duration::from_millis(100))
+        .await;
+    assert!(matches!(result, Err(DsyrsError::Timeout)));
+}
+
+#[tokio::test]
+async fn speed_wait() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_register(registers::P05_SPEED_UNIFORM_WIDTH, 10);
+
+    // Ramping up, within P05.17 of the target on the fourth sample
+    mock.script_reads(registers::P18_SPEED_FEEDBACK, [0, 300, 600]);
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 990);
+    servo
+        .wait_for_speed(1000, 0, Duration::from_secs(1))
+        .await
+        .unwrap();
+
+    // Outside an explicit tolerance
+    let result = servo
+        .wait_for_speed(1000, 5, Duration::from_millis(50))
+        .await;
+    assert!(matches!(result, Err(DsyrsError::Timeout)));
+
+    // Fault Er.410 while waiting
+    mock.script_reads(registers::P18_SERVO_STATUS, [0x0001, 0x4102]);
+    mock.set_register(registers::P18_SPEED_FEEDBACK, 0);
+    let result = servo.wait_for_speed(-500, 0, Duration::from_secs(1)).await;
+    assert!(matches!(result, Err(DsyrsError::OperationFailed(_))));
+}
+
+#[tokio::test]
+async fn torque_mode_speed_ceiling() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo
+        .apply_torque_mode_config(&TorqueModeConfig::new(1500, 500))
+        .await
+        .unwrap();
+    assert_eq!(mock.register(registers::P06_SPEED_LIMIT_SOURCE), 0);
+    assert_eq!(mock.register(registers::P06_POSITIVE_SPEED_LIMIT), 1500);
+    assert_eq!(mock.register(registers::P06_NEGATIVE_SPEED_LIMIT), 500);
+
+    let written = mock.writes().len();
+    assert!(servo
+        .apply_torque_mode_config(&TorqueModeConfig::new(9001, 500))
+        .await
+        .is_err());
+    assert!(servo.set_positive_speed_limit(9001).await.is_err());
+    assert!(servo.set_negative_speed_limit(9001).await.is_err());
+    assert_eq!(mock.writes().len(), written);
+
+    servo.set_negative_speed_limit(9000).await.unwrap();
+    assert_eq!(
+        servo
+            .read_torque_config()
+            .await
+            .unwrap()
+            .negative_speed_limit,
+        9000
+    );
+}
+
+#[tokio::test]
+async fn gain_switch_mode_range() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo.set_gain_switch_mode(0).await.unwrap();
+    servo.set_gain_switch_mode(13).await.unwrap();
+    assert_eq!(mock.register(registers::P07_GAIN_SWITCH_MODE), 13);
+    assert!(servo.set_gain_switch_mode(12).await.is_err());
+    assert!(servo.set_gain_switch_mode(14).await.is_err());
+}
+
+#[tokio::test]
+async fn default_display_code_range() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo.set_default_display(100).await.unwrap();
+    assert_eq!(mock.register(registers::P12_DEFAULT_DISPLAY), 100);
+    assert!(servo.set_default_display(101).await.is_err());
+    mock.set_register(registers::P12_DEFAULT_DISPLAY, 1);
+    assert_eq!(servo.get_default_display().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn factory_reset_keeps_the_comm_settings() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(5));
+    mock.set_register(registers::P10_COMM_ADDRESS, 5);
+    mock.set_register(registers::P10_MODBUS_BAUDRATE, 3);
+    mock.set_register(registers::P10_MODBUS_FORMAT, 1);
+    mock.set_register(registers::P10_RS485_ADDRESS_SOURCE, 0);
+    mock.set_register(registers::P10_RS232_BAUDRATE, 2);
+
+    // Nothing is written without confirmation
+    assert!(matches!(
+        servo.factory_reset_preserving_comm(false).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+    assert!(mock.writes().is_empty());
+
+    servo.factory_reset_preserving_comm(true).await.unwrap();
+    assert_eq!(
+        mock.writes(),
+        [
+            (registers::P11_SYSTEM_INIT, 1),
+            (registers::P10_COMM_ADDRESS, 5),
+            (registers::P10_MODBUS_BAUDRATE, 3),
+            (registers::P10_MODBUS_FORMAT, 1),
+            (registers::P10_RS485_ADDRESS_SOURCE, 0),
+            (registers::P10_RS232_BAUDRATE, 2),
+            (registers::P10_WRITE_EEPROM, 1),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn encoder_reset_checks_the_turns() {
+    let config = ServoConfig::new(1).with_encoder_type(EncoderType::Bit17Absolute);
+    let (mut servo, mock) = simulated_client(config);
+    mock.set_registers(registers::P01_ENCODER_RESOLUTION, &[0x0002, 0x0000]);
+    servo.set_gear_ratio(1, 1).await.unwrap();
+    let timeout = Duration::from_millis(100);
+
+    servo
+        .reset_encoder_and_verify(EncoderReset::ResetMultiTurn, true, timeout)
+        .await
+        .unwrap();
+    assert_eq!(mock.register(registers::P11_ENCODER_RESET), 2);
+
+    // Still 3 turns away from the origin
+    mock.set_registers(registers::P18_ABSOLUTE_POSITION, &[0x0006, 0x0000]);
+    let result = servo
+        .reset_encoder_and_verify(EncoderReset::ResetMultiTurn, false, timeout)
+        .await;
+    assert!(matches!(result, Err(DsyrsError::OperationFailed(_))));
+
+    // The drive asks for a power cycle (AL.941)
+    mock.set_register(registers::P18_SERVO_STATUS, 0x9413);
+    let result = servo
+        .reset_encoder_and_verify(EncoderReset::ClearWarnings, false, timeout)
+        .await;
+    assert!(matches!(result, Err(DsyrsError::OperationFailed(_))));
+}
+
+#[tokio::test]
+async fn forced_outputs_are_restored() {
+    let (mut servo, _mock) = simulated_client(ServoConfig::new(1));
+    let mut forced = servo.force_outputs(0b0_0101).await.unwrap();
+    assert!(forced.read_forced_do().await.unwrap().output(3));
+    forced.force_do_bit(3, false).await.unwrap();
+    assert!(!forced.read_forced_do().await.unwrap().output(3));
+    forced.release().await.unwrap();
+    assert_eq!(
+        servo
+            .read_register(registers::P11_FORCED_DIDO)
+            .await
+            .unwrap(),
+        0
+    );
+
+    // Dropped without release(): restored before the next request
+    drop(servo.force_outputs(0b1_0000).await.unwrap());
+    assert_eq!(
+        servo
+            .read_register(registers::P11_FORCED_DIDO)
+            .await
+            .unwrap(),
+        0
+    );
+
+    assert!(servo.force_outputs(0x20).await.is_err());
+}
+
+#[tokio::test]
+async fn program_download_coalesces_segments() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let program = MotionProgram::builder(2, 5)
+        .with_operation_mode(MultiSegOperationMode::Single)
+        .with_position_mode(MultiSegPositionMode::Absolute)
+        .with_segment(
+            SegmentConfig::new(3)
+                .unwrap()
+                .with_displacement(-2)
+                .with_speed(800),
+        )
+        .with_segment(
+            SegmentConfig::new(2)
+                .unwrap()
+                .with_displacement(70_000)
+                .with_speed(1500),
+        )
+        .with_segment(
+            SegmentConfig::new(5)
+                .unwrap()
+                .with_accel_decel(50)
+                .with_wait_time(10),
+        )
+        .build()
+        .unwrap();
+    servo.download_program(&program).await.unwrap();
+
+    assert_eq!(mock.register(registers::P13_OPERATION_MODE), 0);
+    assert_eq!(mock.register(registers::P13_START_SEGMENT), 2);
+    assert_eq!(mock.register(registers::P13_END_SEGMENT), 5);
+    assert_eq!(mock.register(registers::P13_POSITION_MODE), 1);
+    assert_eq!(mock.register(registers::P13_SEG2_DISPLACEMENT), 0x0001);
+    assert_eq!(mock.register(registers::P13_SEG2_DISPLACEMENT + 1), 0x1170);
+    assert_eq!(mock.register(registers::P13_SEG2_SPEED), 1500);
+    assert_eq!(mock.register(registers::P13_SEG3_DISPLACEMENT), 0xFFFF);
+    assert_eq!(mock.register(registers::P13_SEG3_DISPLACEMENT + 1), 0xFFFE);
+    assert_eq!(mock.register(registers::P13_SEG3_SPEED), 800);
+    assert_eq!(mock.register(registers::P13_SEG5_ACCEL_DECEL), 50);
+    assert_eq!(mock.register(registers::P13_SEG5_WAIT_TIME), 10);
+    // P13.00-02, P13.05, segments 2-3 and segment 5
+    assert_eq!(servo.stats().requests, 4);
+
+    // A hand-built program with start after end writes nothing
+    mock.clear_history();
+    let mut program = program;
+    program.start = 6;
+    assert!(matches!(
+        servo.download_program(&program).await,
+        Err(DsyrsError::InvalidParameter(_))
+    ));
+    assert!(mock.writes().is_empty());
+}
+
+#[tokio::test]
+async fn segments_read_back() {
+    let (mut servo, _mock) = simulated_client(ServoConfig::new(1));
+    let segment = SegmentConfig::new(3)
+        .unwrap()
+        .with_displacement(-123_456)
+        .with_speed(1500)
+        .with_accel_decel(80)
+        .with_wait_time(20);
+    servo.configure_segment(&segment).await.unwrap();
+
+    let read = servo.read_segment(3).await.unwrap();
+    assert_eq!(read.displacement, -123_456);
+    assert_eq!(read.speed, 1500);
+    assert_eq!(read.accel_decel_time, 80);
+    assert_eq!(read.wait_time, 20);
+    assert_eq!(servo.read_all_segments(1, 3).await.unwrap().len(), 3);
+    assert!(servo.read_segment(17).await.is_err());
+}
+
+#[tokio::test]
+async fn commands_follow_the_control_mode() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    let commands = [
+        MotionCommand::Speed(500),
+        MotionCommand::Torque(-100),
+        MotionCommand::PositionRelative(1000),
+        MotionCommand::PositionAbsolute(-2000),
+    ];
+    for mode in [
+        ControlMode::Position,
+        ControlMode::Speed,
+        ControlMode::Torque,
+    ] {
+        mock.set_register(registers::P00_CONTROL_MODE, mode.into());
+        for command in commands {
+            let result = servo.command(command).await;
+            if command.control_mode() == mode {
+                result.unwrap();
+            } else {
+                assert!(matches!(result, Err(DsyrsError::InvalidParameter(_))));
+            }
+        }
+    }
+    assert_eq!(mock.register(registers::P05_SPEED_COMMAND), 500);
+    assert_eq!(
+        mock.register(registers::P06_TORQUE_COMMAND),
+        (-100i16) as u16
+    );
+    assert_eq!(servo.read_segment(1).await.unwrap().displacement, -2000);
+}
+
+#[tokio::test]
+async fn diff_against_a_dump() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    servo.set_max_speed(3000).await.unwrap();
+    let mut baseline = servo.dump_parameters().await.unwrap();
+    // Read-only registers in the baseline are ignored
+    baseline.insert(registers::P18_SPEED_FEEDBACK, 1234);
+    assert!(servo.diff_parameters(&baseline).await.unwrap().is_empty());
+
+    // The maximum speed drifted
+    mock.set_register(registers::P00_MAX_SPEED, 2500);
+    assert_eq!(
+        servo.diff_parameters(&baseline).await.unwrap(),
+        vec![(registers::P00_MAX_SPEED, 3000, 2500)]
+    );
+}
+
+#[tokio::test]
+async fn positions_in_user_units() {
+    let gear = GearRatio::new(131_072, 10_000).unwrap();
+    let config = ServoConfig::new(1).with_mechanical(5.0, gear, 131_072);
+    let (mut servo, mock) = simulated_client(config);
+    mock.set_registers(registers::P18_ABSOLUTE_POSITION, &[0x0000, 25_000]);
+    assert_eq!(servo.get_position_user().await.unwrap(), 12.5);
+
+    // No mechanics configured
+    let mut servo = DsyrsClient::new(mock, ServoConfig::new(1));
+    assert!(servo.get_position_user().await.is_err());
+}
+
+#[tokio::test]
+async fn status_getters_decode_signedness() {
+    let (mut servo, mock) = simulated_client(ServoConfig::new(1));
+    mock.set_registers(registers::P18_SPEED_FEEDBACK, &[0x8000; 9]);
+    mock.set_register(registers::P18_ABSOLUTE_POSITION + 1, 0x0000);
+
+    assert_eq!(servo.get_speed().await.unwrap(), -32768);
+    assert_eq!(servo.get_speed_command().await.unwrap(), -32768);
+    assert_eq!(servo.get_torque().await.unwrap(), -3276.8);
+    assert_eq!(servo.get_position().await.unwrap(), i32::MIN);
+    assert_eq!(servo.get_load_rate().await.unwrap(), 3276.8);
+    assert_eq!(servo.get_current().await.unwrap(), 327.68);
+    assert_eq!(servo.get_bus_voltage().await.unwrap(), 3276.8);
+    assert_eq!(servo.get_electrical_angle().await.unwrap(), 3276.8);
+
+    for status in [
+        servo.get_status().await.unwrap(),
+        servo.get_status_fast().await.unwrap(),
+    ] {
+        assert_eq!((status.speed, status.torque), (i16::MIN, i16::MIN));
+        assert_eq!(status.position, i32::MIN);
+        assert_eq!(
+            (status.load_rate, status.current, status.bus_voltage),
+            (0x8000, 0x8000, 0x8000)
+        );
+        assert_eq!(status.electrical_angle, 0x8000);
+    }
+}